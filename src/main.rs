@@ -45,8 +45,11 @@ fn main() -> Result<(), eframe::Error> {
     };
     let mut pre_options = config::read_config();
 
+    // Add modifiers that didn't exist when the config was written.
+    rename::mods::ModsOrder::append_missing(&mut pre_options.modifier_order.0);
+
     // Prevert modifier_order from having extra elements.
-    if pre_options.modifier_order.0.len() != 11 {
+    if pre_options.modifier_order.0.len() != rename::mods::ModsOrder::iterate_over_oneness().count() {
         pre_options.modifier_order = rename::app::ModifierOrder::default();
     }
 
@@ -1,9 +1,10 @@
 use super::mods::{Modifiers, ModsOrder};
 use super::presets::Presets;
 use super::util::{dir, threads::{ThreadState, ModifierThreadError, ModifierThreadStorage, 
-    ThreadFunction, ThreadStorage, Endianness, thread, SaveType}, processing::file_processing::process};
+    ThreadFunction, ThreadStorage, Endianness, thread, SaveType}, processing::file_processing::{process, Batch, selection_items, folder_listing, ListedItem}};
 use super::gui::main_sub::file_browser::{FileBrowser, MapFolder};
 use super::gui::main_sub::file_selector::FileSelection;
+use super::gui::main_sub::file_modifications::TesterCache;
 use super::app;
 use super::debug::DebugStats;
 
@@ -52,6 +53,7 @@ pub struct WindowMain {
 
     pub file_selector: FileSelection,
     pub file_selected_total: u32,
+    pub tester_cache: TesterCache,
 
     pub modifiers_reorder_enabled: bool,
     pub modifiers_dnd_enabled: bool,
@@ -134,6 +136,7 @@ impl Default for WindowMain {
                 ..Default::default()
             },
             file_selected_total: 0,
+            tester_cache: TesterCache::default(),
 
             modifiers_reorder_enabled: false,
             modifiers_dnd_enabled: true,
@@ -146,6 +149,7 @@ impl Default for WindowMain {
                 modifier_order: sync::Arc::new(sync::Mutex::new(None)),
                 eddited_files: sync::Arc::new(sync::Mutex::new(None)),
                 raw_files: sync::Arc::new(sync::Mutex::new(None)),
+                listings: sync::Arc::new(sync::Mutex::new(None)),
                 errors: sync::Arc::new(sync::Mutex::new(None)),
                 state: sync::Arc::new(sync::Mutex::new(ThreadState::None)),
                 thread_calc_time: sync::Arc::new(sync::Mutex::new(0))
@@ -250,8 +254,8 @@ impl WindowMain {
         }
     }
 
-    pub fn create_selected_vec(&mut self) -> Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> {
-        let mut selected: Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> = vec![];
+    pub fn create_selected_vec(&mut self) -> Vec<(String, Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> {
+        let mut selected: Vec<(String, Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> = vec![];
         let mut selected_folder_paths: Vec<(String, usize, usize)> = vec![];
         let mut selected_file_paths: Vec<(String, usize, usize)> = vec![];
         let mut selected_total: u32 = 0;
@@ -279,7 +283,7 @@ impl WindowMain {
                     }
                 };
             };
-            selected.insert(index, (fold_selected, file_selected))
+            selected.insert(index, (folder.path.clone(), fold_selected, file_selected))
         };
         self.file_selected_total = selected_total;
         self.file_selector.selected_folder_paths = selected_folder_paths;
@@ -287,6 +291,11 @@ impl WindowMain {
         return selected;
     }
 
+    /// Everything in each folder, in the same order as `create_selected_vec`.
+    pub fn create_listings(&self) -> Vec<Vec<ListedItem>> {
+        self.file_selector.folders.iter().map(folder_listing).collect()
+    }
+
     pub fn fill_selected_renamed(&mut self, renamed: Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)>,  errors: Vec<(Vec<ModifierThreadError>, Vec<ModifierThreadError>)>) {
         //Check and leave if something changed.
         if renamed.len() != self.file_selector.folders.len() {
@@ -312,12 +321,14 @@ impl WindowMain {
             for (_, fold) in folders.list_folders.iter_mut().enumerate() {
                 fold.error = String::new();
                 fold.errored = false;
+                fold.warning = String::new();
             }
         };
         for(_, folders) in self.file_selector.folders.iter_mut().enumerate() {
             for (_, file) in folders.list_files.iter_mut().enumerate() {
                 file.error = String::new();
                 file.errored = false;
+                file.warning = String::new();
             }
         };
         for (index, errors) in errors.iter().enumerate() {
@@ -349,6 +360,65 @@ impl WindowMain {
                             self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
                                 String::from(format!("File name is invalid, system reserved file name! - {}", err.1));
                         }
+                    },
+                    ModifierThreadError::InvalidTemplate(invalidtemplate) => {
+                        for err in invalidtemplate {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
+                                String::from(format!("Template error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::NormalizationDiffers(differs) => {
+                        for err in differs {
+                            self.file_selector.folders[index].list_folders[*err].warning = 
+                                String::from("Name isn't in the selected normalization form.");
+                        }
+                    },
+                    ModifierThreadError::NormalizationCollision(collisions) => {
+                        for err in collisions {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
+                                String::from(format!("Collides with {} once normalized!", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidNumber(invalidnumber) => {
+                        for err in invalidnumber {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
+                                String::from(format!("Number error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidRegex(invalidregex) => {
+                        for err in invalidregex {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
+                                String::from(format!("Regex error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidMapping(invalidmapping) => {
+                        for err in invalidmapping {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].error = 
+                                String::from(format!("Mapping error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::Unmapped(unmapped) => {
+                        for err in unmapped {
+                            self.file_selector.folders[index].list_folders[*err].warning = 
+                                String::from("Not in the mapping, keeps its name.");
+                        }
+                    },
+                    ModifierThreadError::ListTooShort(unlisted) => {
+                        for err in unlisted {
+                            self.file_selector.folders[index].list_folders[*err].warning = 
+                                String::from("No name left in the list, keeps its name.");
+                        }
+                    },
+                    ModifierThreadError::SequenceGaps(gaps) => {
+                        for err in gaps {
+                            self.file_selector.folders[index].list_folders[err.0.to_owned()].warning = 
+                                format!("Sequence is missing frames {}", err.1);
+                        }
                     }
                 };
             };
@@ -380,6 +450,65 @@ impl WindowMain {
                             self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
                                 String::from(format!("File name is invalid, system reserved file name! - {}", err.1));
                         }
+                    },
+                    ModifierThreadError::InvalidTemplate(invalidtemplate) => {
+                        for err in invalidtemplate {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
+                                String::from(format!("Template error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::NormalizationDiffers(differs) => {
+                        for err in differs {
+                            self.file_selector.folders[index].list_files[*err].warning = 
+                                String::from("Name isn't in the selected normalization form.");
+                        }
+                    },
+                    ModifierThreadError::NormalizationCollision(collisions) => {
+                        for err in collisions {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
+                                String::from(format!("Collides with {} once normalized!", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidNumber(invalidnumber) => {
+                        for err in invalidnumber {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
+                                String::from(format!("Number error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidRegex(invalidregex) => {
+                        for err in invalidregex {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
+                                String::from(format!("Regex error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::InvalidMapping(invalidmapping) => {
+                        for err in invalidmapping {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].errored = true;
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].error = 
+                                String::from(format!("Mapping error! - {}", err.1));
+                        }
+                    },
+                    ModifierThreadError::Unmapped(unmapped) => {
+                        for err in unmapped {
+                            self.file_selector.folders[index].list_files[*err].warning = 
+                                String::from("Not in the mapping, keeps its name.");
+                        }
+                    },
+                    ModifierThreadError::ListTooShort(unlisted) => {
+                        for err in unlisted {
+                            self.file_selector.folders[index].list_files[*err].warning = 
+                                String::from("No name left in the list, keeps its name.");
+                        }
+                    },
+                    ModifierThreadError::SequenceGaps(gaps) => {
+                        for err in gaps {
+                            self.file_selector.folders[index].list_files[err.0.to_owned()].warning = 
+                                format!("Sequence is missing frames {}", err.1);
+                        }
                     }
                 };
             };
@@ -413,9 +542,15 @@ impl WindowMain {
         let proto_files = self.create_selected_vec();
         let mut completed_edits: Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> = vec![];
         let mut completed_errors: Vec<(Vec<ModifierThreadError>, Vec<ModifierThreadError>)> = vec![];
-        for (index, (folders, files)) in proto_files.iter().enumerate() {
-            let folders_edits = process(index, &mut self.modifiers, folders.to_owned(), self.options.modifier_order.0.clone(), true);
-            let files_edits = process(index, &mut self.modifiers, files.to_owned(), self.options.modifier_order.0.clone(), false);
+        let mut offset: usize = 0;
+        let selection = selection_items(&proto_files);
+        let listings = self.create_listings();
+        for (index, (path, folders, files)) in proto_files.iter().enumerate() {
+            let batch = |offset: usize| Batch { folder_index: index, offset, selection: &selection, listing: &listings[index] };
+            let folders_edits = process(batch(offset), path.to_owned(), &mut self.modifiers, folders.to_owned(), self.options.modifier_order.0.clone(), true);
+            offset += folders.len();
+            let files_edits = process(batch(offset), path.to_owned(), &mut self.modifiers, files.to_owned(), self.options.modifier_order.0.clone(), false);
+            offset += files.len();
             completed_edits.push((folders_edits.0, files_edits.0));
             completed_errors.push((folders_edits.1, files_edits.1));
         };
@@ -584,11 +719,23 @@ impl Default for Options {
             windows_context_menu_installed: false,
 
             modifier_order: ModifierOrder {0: vec![
+                ModsOrder::Mapping,
+                ModsOrder::NameList,
+                ModsOrder::Normalize,
+                ModsOrder::Template,
                 ModsOrder::Case,
                 ModsOrder::Name,
                 ModsOrder::Regex,
+                ModsOrder::Segment,
+                ModsOrder::Renumber,
+                ModsOrder::Sequence,
+                ModsOrder::Random,
+                ModsOrder::Truncate,
+                ModsOrder::Seperators,
                 ModsOrder::Remove,
+                ModsOrder::Transliterate,
                 ModsOrder::MoveCopy,
+                ModsOrder::Dictionary,
                 ModsOrder::Replace,
                 ModsOrder::Add,
                 ModsOrder::Date,
@@ -612,6 +759,7 @@ impl Default for Options {
             file_browser_selected: false,
             file_selection: OptionsFileSelection {
                 stripped_column: true,
+                collapse_sequences: true,
                 ..Default::default()
             },
             file_selection_selected: false,
@@ -664,7 +812,13 @@ pub struct OptionsFileSelection {
     #[serde(default)]
     pub list_folders: bool,
     #[serde(default)]
-    pub always_show_extra_row: bool
+    pub always_show_extra_row: bool,
+    #[serde(default)]
+    pub sort: dir::SortMode,
+    #[serde(default)]
+    pub sort_descending: bool,
+    #[serde(default)]
+    pub collapse_sequences: bool
 }
 
 #[derive(Default, Clone, Serialize, Deserialize)]
@@ -725,11 +879,23 @@ pub struct ModifierOrder(pub Vec<ModsOrder>);
 impl Default for ModifierOrder {
     fn default() -> Self {
         Self { 0: vec![
+            ModsOrder::Mapping,
+            ModsOrder::NameList,
+            ModsOrder::Normalize,
+            ModsOrder::Template,
             ModsOrder::Case,
             ModsOrder::Name,
             ModsOrder::Regex,
+            ModsOrder::Segment,
+            ModsOrder::Renumber,
+            ModsOrder::Sequence,
+            ModsOrder::Random,
+            ModsOrder::Truncate,
+            ModsOrder::Seperators,
             ModsOrder::Remove,
+            ModsOrder::Transliterate,
             ModsOrder::MoveCopy,
+            ModsOrder::Dictionary,
             ModsOrder::Replace,
             ModsOrder::Add,
             ModsOrder::Date,
@@ -1,6 +1,9 @@
 use super::super::app::WindowMain;
 use super::super::presets::Preset;
+use super::super::mods::{Modifiers, ModMapping, ModsOrder};
 use super::super::util::{config, dir};
+use super::super::util::threads::{thread, ModifierThreadError, SaveType, ThreadFunction};
+use super::super::util::processing::file_processing;
 
 use std::fs;
 
@@ -10,17 +13,20 @@ pub fn parse_arguments(app: &mut WindowMain, args: Vec<String>) -> CliResult {
         let mut operator: CliArgType = CliArgType::None;
         let mut verified_path: Option<String> = None;
         let mut verified_preset: Option<Preset> = None;
+        let mut verified_file: Option<String> = None;
         for arg in args {
             match arg.to_ascii_lowercase().as_str() {
                 "-h" | "--help" => {
-                    println!("{}", format!("{}{}{}{}{}{}{}{}",
+                    println!("{}", format!("{}{}{}{}{}{}{}{}{}{}",
                         "Kita Rename Utility v",
                         env!("CARGO_PKG_VERSION").to_string(),
                         " usage:\n",
                         "-h | --help         : Help\n",
                         "-o <path>           : Opens Kita (gui) to a path\n",
                         "-op <path> <preset> : Opens Kita (gui) to a path with a preset pre-selected\n",
-                        "-ap <path> <preset> : Applies a preset to a path (cli) (upcoming feature)\n\n",
+                        "-ap <path> <preset> : Applies a preset to a path (cli) (upcoming feature)\n",
+                        "-m <path> <mapping> : Renames a path's items from a CSV / TSV / JSON old -> new mapping file (cli)\n",
+                        "-u                  : Undoes the last rename made with -m (cli)\n\n",
                         "Submit a bug report here:  https://github.com/Auvrae/Kita/issues"
                     ));
                     std::process::exit(0);
@@ -34,6 +40,12 @@ pub fn parse_arguments(app: &mut WindowMain, args: Vec<String>) -> CliResult {
                 "-o" => { // Open to Path
                     operator = CliArgType::Operator(CliOperator::OpenPath)
                 },
+                "-m" => { // Rename from a Mapping file
+                    operator = CliArgType::Operator(CliOperator::ApplyMapping)
+                },
+                "-u" => { // Undo the last Mapping rename
+                    operator = CliArgType::Operator(CliOperator::Undo)
+                },
                 _ => {
                     check_for_path.push(arg);
                 }
@@ -44,6 +56,10 @@ pub fn parse_arguments(app: &mut WindowMain, args: Vec<String>) -> CliResult {
             if test.is_some() {
                 verified_path = Some(test.unwrap());
             };
+            let file = verify_file(argument.clone());
+            if file.is_some() {
+                verified_file = Some(file.unwrap());
+            };
             let preset = verfiy_preset(app, argument);
             if preset.is_some() {
                 verified_preset = Some(preset.unwrap());
@@ -76,6 +92,24 @@ pub fn parse_arguments(app: &mut WindowMain, args: Vec<String>) -> CliResult {
                     ))
                 }
             },
+            CliArgType::Operator(CliOperator::ApplyMapping) => {
+                if verified_path.is_none() {
+                    return CliResult::Error(String::from("No valid path given."));
+                };
+                if verified_file.is_none() {
+                    return CliResult::Error(String::from("No valid mapping file given."));
+                };
+                match apply_mapping(app, verified_path.unwrap(), verified_file.unwrap()) {
+                    Ok(()) => return CliResult::Stop,
+                    Err(error) => return CliResult::Error(error)
+                }
+            },
+            CliArgType::Operator(CliOperator::Undo) => {
+                match undo_last_edit(app) {
+                    Ok(()) => return CliResult::Stop,
+                    Err(error) => return CliResult::Error(error)
+                }
+            },
             CliArgType::Operator(CliOperator::OpenPath) => {
                 if verified_path.is_some() {
                     let path = verified_path.unwrap();
@@ -199,6 +233,130 @@ fn apply_preset(app: &mut WindowMain, path: String, preset: Preset) -> Result<()
     Err(String::from("Work in Progress"))
 }
 
+/// Renames the items in `path` that the mapping file lists, through the same validation and saving as the GUI.
+fn apply_mapping(app: &mut WindowMain, path: String, mapping_path: String) -> Result<(), String> {
+    let table = fs::read_to_string(&mapping_path).map_err(|err| format!("Couldn't read {}: {}", mapping_path, err))?;
+    let pairs = file_processing::parse_mapping(&table)?;
+    let folder = dir::get_folder(path.to_owned(), false).map_err(|err| err.to_string())?;
+
+    // Every item goes in, so new names that collide with an unmapped item are caught as duplicates.
+    let mut modifiers = Modifiers {
+        mapping: ModMapping { table: table, file_path: mapping_path, ..Default::default() },
+        mapping_enabled: true,
+        ..Default::default()
+    };
+    let order: Vec<ModsOrder> = vec![ModsOrder::Mapping];
+    let folders: Vec<(String, usize, Option<String>)> = folder.list_folders.iter().enumerate().map(|(index, item)| (item.name.to_owned(), index, None)).collect();
+    let files: Vec<(String, usize, Option<String>)> = folder.list_files.iter().enumerate().map(|(index, item)| (item.name.to_owned(), index, None)).collect();
+    let folders_total = folders.len();
+    let selection = file_processing::selection_items(&vec![(path.to_owned(), folders.clone(), files.clone())]);
+    let listing: Vec<file_processing::ListedItem> = file_processing::folder_listing(&folder).into_iter()
+        .map(|item| file_processing::ListedItem { selected: true, ..item })
+        .collect();
+    let batch = |offset: usize| file_processing::Batch { folder_index: 0, offset, selection: &selection, listing: &listing };
+    let (folders_renamed, folders_errors) = file_processing::process(batch(0), path.to_owned(), &mut modifiers, folders, order.clone(), true);
+    let (files_renamed, files_errors) = file_processing::process(batch(folders_total), path.to_owned(), &mut modifiers, files, order, false);
+
+    // Report
+    for (old, _) in pairs.iter() {
+        if !folder.list_files.iter().chain(folder.list_folders.iter()).any(|item| item.name == *old) {
+            println!("Unmatched row: {}", old);
+        };
+    }
+    let mut problems: Vec<String> = vec![];
+    for (errors, items) in [(&folders_errors, &folder.list_folders), (&files_errors, &folder.list_files)] {
+        for error in errors {
+            match error {
+                ModifierThreadError::Unmapped(unmapped) => {
+                    for index in unmapped {
+                        println!("Unmapped: {}", items[*index].name);
+                    }
+                },
+                ModifierThreadError::NormalizationDiffers(_) | ModifierThreadError::ListTooShort(_) | ModifierThreadError::SequenceGaps(_) => {},
+                _ => problems.extend(describe_error(error, items))
+            }
+        }
+    }
+    if !problems.is_empty() {
+        return Err(format!("nothing was renamed.\n{}", problems.join("\n")));
+    };
+
+    let mut edit: dir::Edit = dir::Edit {
+        tag: String::new(),
+        items: vec![],
+        edits: 1
+    };
+    for (renamed, items) in [(&folders_renamed, &folder.list_folders), (&files_renamed, &folder.list_files)] {
+        for (name_edited, index, _) in renamed {
+            let item = &items[*index];
+            if item.name == *name_edited { continue };
+            edit.items.push(dir::EdittedItem {
+                name_original: item.name.to_owned(),
+                name_edited: name_edited.to_owned(),
+                path_original: item.path.to_owned(),
+                path_edited: format!("{}/{}", item.path_plain, name_edited)
+            });
+        }
+    }
+    if edit.items.is_empty() {
+        println!("Nothing to rename.");
+        return Ok(());
+    };
+    edit.tag = format!("{} files.", edit.items.len());
+    app.edits.undo = Some(edit.clone());
+    thread(app, ThreadFunction::SaveUndoRedo(edit.clone(), SaveType::Save, app.options.saving.io_operation_waittime)).join().unwrap();
+    println!("Renamed {} items.", edit.items.len());
+    match config::write_last_edit(&edit) {
+        Ok(()) => println!("Undo with -u."),
+        Err(error) => println!("Couldn't save the undo information: {}", error)
+    };
+    Ok(())
+}
+
+fn undo_last_edit(app: &mut WindowMain) -> Result<(), String> {
+    let edit = match config::read_last_edit() {
+        Some(edit) => edit,
+        None => return Err(String::from("There's no rename to undo."))
+    };
+    thread(app, ThreadFunction::SaveUndoRedo(edit.clone(), SaveType::Undo, app.options.saving.io_operation_waittime)).join().unwrap();
+    config::remove_last_edit()?;
+    println!("Undid {}", edit.tag);
+    Ok(())
+}
+
+/// The same messages the selector shows, one line per item.
+fn describe_error(error: &ModifierThreadError, items: &Vec<dir::FolderItem>) -> Vec<String> {
+    match error {
+        ModifierThreadError::DuplicateFileName(duplicates) => duplicates.iter()
+            .map(|index| format!("{}: Duplicate name!", items[*index].name)).collect(),
+        ModifierThreadError::LengthLimitFileName(file_length) => file_length.iter()
+            .map(|err| format!("{}: File length {} which is greater then the limit [255]", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidChar(invalidchars) => invalidchars.iter()
+            .map(|err| format!("{}: Character {} invalid for names on this operating system!", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidFileName(invalidname) => invalidname.iter()
+            .map(|err| format!("{}: File name is invalid, system reserved file name! - {}", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidTemplate(invalidtemplate) => invalidtemplate.iter()
+            .map(|err| format!("{}: Template error! - {}", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::NormalizationCollision(collisions) => collisions.iter()
+            .map(|err| format!("{}: Collides with {} once normalized!", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidNumber(invalidnumber) => invalidnumber.iter()
+            .map(|err| format!("{}: Number error! - {}", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidRegex(invalidregex) => invalidregex.iter()
+            .map(|err| format!("{}: Regex error! - {}", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::InvalidMapping(invalidmapping) => invalidmapping.iter()
+            .map(|err| format!("{}: Mapping error! - {}", items[err.0].name, err.1)).collect(),
+        ModifierThreadError::NormalizationDiffers(_) | ModifierThreadError::Unmapped(_) | ModifierThreadError::ListTooShort(_) 
+            | ModifierThreadError::SequenceGaps(_) => vec![]
+    }
+}
+
+fn verify_file(path: String) -> Option<String> {
+    match fs::metadata(&path) {
+        Ok(metadata) if metadata.is_file() => Some(path),
+        _ => None
+    }
+}
+
 fn verify_path(mut path: String) -> Option<String> {
     path = path.replacen("\\", "/", 254).to_string();
     if path.len() >= 3 && path.ends_with("/") {
@@ -240,5 +398,7 @@ enum CliArgType {
 enum CliOperator {
     OpenPath,
     OpenPreset,
-    ApplyPreset
+    ApplyPreset,
+    ApplyMapping,
+    Undo
 }
\ No newline at end of file
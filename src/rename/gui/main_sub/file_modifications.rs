@@ -1,14 +1,20 @@
 use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 use std::borrow::BorrowMut;
 use std::time::Instant;
 
 use super::super::super::util::threads;
+use super::super::super::util::random;
 use super::super::super::app::{WindowMain, DndDropLocation};
 use super::super::super::debug::DebugStatType;
+use super::file_selector::SelectedType;
+use super::super::super::util::processing::file_processing;
 use super::super::super::mods::{Modifiers, ModsOrder, ModAdd, ModCase, ModExtension, ModDate, 
-    ModHashing, ModMoveCopy, ModName, ModNumber, ModRegex, ModRemove, ModReplace, CaseMode, CaseExecptMode,
-    DateFormatMode, DateMode, DateSeperator, ExtensionMode, HashSeperator, MoveCopyFromMode, MoveCopyToMode, NameMode, NumberMode, 
-    NumberTypeMode, RemoveCropMode};
+    ModHashing, ModMoveCopy, ModName, ModNumber, ModRegex, ModRemove, ModReplace, ModTemplate, ModTransliterate, ModNormalize, ModDictionary, ModMapping, ModNameList, ModSegment, ModRenumber, ModSequence, ModRandom, ModTruncate, ModSeperators, CaseMode, CaseExecptMode, TitleLanguage,
+    DateFormatMode, DateMode, DateSeperator, ExtensionMode, HashSeperator, MoveCopyFromMode, MoveCopyToMode, MoveCopyUnit, InsertAnchor, AnchorMode, AnchorFallback, NameMode, NumberMode, 
+    NumberScope, NumberTypeMode, RandomMode, RandomKind, TruncateUnit, RemoveBracketsMode, RemoveCropMode, RemoveCropOccurrence, TransliterateMode, NormalizeMode, RegexOccurrence, RegexScope, ReplaceOccurrence, RenumberOccurrence, DictionaryOrder};
 use super::super::super::super::config;
 
 pub fn modifications(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
@@ -210,7 +216,7 @@ pub fn fill_modification_container(gui: &mut WindowMain, modorder: ModsOrder, ui
                     for (mod_index, _) in modadd.iter().enumerate() {
                         ui.add_enabled_ui(modreadd_enabled, |ui| {
                             let mut add_raw = gui.modifiers.add.remove(mod_index);
-                            let add = fill_modadd(gui, ui, &mut add_raw);
+                            let add = fill_modadd(gui, ui, &mut add_raw, mod_index);
                             gui.modifiers.add.insert(mod_index, add.0);
                             gui.modifications_total += add.1;
                             if (modadd.len() != 1) && (mod_index != modadd.len() - 1) { ui.separator(); }; // Add Seperators in between the sections.
@@ -668,252 +674,1606 @@ pub fn fill_modification_container(gui: &mut WindowMain, modorder: ModsOrder, ui
                     }
                 });
             });
+        },
+        ModsOrder::Template => {
+            let mut modtemplate = gui.modifiers.template.clone();
+            let modtemplate_enabled = gui.modifiers.template_enabled;
+            let mut modtemplate_sections: u8 = modtemplate.len().to_owned() as u8;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Template");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.template_enabled, "");
+    
+                            ui.separator();
+    
+                            if ui.small_button("➕").clicked() {
+                                if modtemplate_sections < gui.options.file_modifiers.sub_modifier_maximum {
+                                    gui.modifiers.template.push(ModTemplate::default());
+                                    modtemplate.push(ModTemplate::default());
+                                    modtemplate_sections += 1;
+                                }
+                            };
+    
+                            ui.separator();
+    
+                            if ui.small_button("➖").clicked() {
+                                if modtemplate_sections > 1 {
+                                    gui.modifiers.template.pop().unwrap();
+                                    modtemplate.pop().unwrap();
+                                    modtemplate_sections -= 1;
+                                }
+                            };
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    for (mod_index, _) in modtemplate.iter().enumerate() {
+                        ui.add_enabled_ui(modtemplate_enabled, |ui| {
+                            let mut template_raw = gui.modifiers.template.remove(mod_index);
+                            let template = fill_modtemplate(gui, ui, &mut template_raw, mod_index);
+                            gui.modifiers.template.insert(mod_index, template.0);
+                            gui.modifications_total += template.1;
+                            if (modtemplate.len() != 1) && (mod_index != modtemplate.len() - 1) { ui.separator(); }; // Add Seperators in between the sections.
+                        });
+                    }
+                });
+            });
+        },
+        ModsOrder::Segment => {
+            let mut modsegment = gui.modifiers.segment.clone();
+            let modsegment_enabled = gui.modifiers.segment_enabled;
+            let mut modsegment_sections: u8 = modsegment.len().to_owned() as u8;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Segment");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.segment_enabled, "");
+    
+                            ui.separator();
+    
+                            if ui.small_button("➕").clicked() {
+                                if modsegment_sections < gui.options.file_modifiers.sub_modifier_maximum {
+                                    gui.modifiers.segment.push(ModSegment::default());
+                                    modsegment.push(ModSegment::default());
+                                    modsegment_sections += 1;
+                                }
+                            };
+    
+                            ui.separator();
+    
+                            if ui.small_button("➖").clicked() {
+                                if modsegment_sections > 1 {
+                                    gui.modifiers.segment.pop().unwrap();
+                                    modsegment.pop().unwrap();
+                                    modsegment_sections -= 1;
+                                }
+                            };
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    for (mod_index, _) in modsegment.iter().enumerate() {
+                        ui.add_enabled_ui(modsegment_enabled, |ui| {
+                            let mut segment_raw = gui.modifiers.segment.remove(mod_index);
+                            let segment = fill_modsegment(gui, ui, &mut segment_raw, mod_index);
+                            gui.modifiers.segment.insert(mod_index, segment.0);
+                            gui.modifications_total += segment.1;
+                            if (modsegment.len() != 1) && (mod_index != modsegment.len() - 1) { ui.separator(); }; // Add Seperators in between the sections.
+                        });
+                    }
+                });
+            });
+        },
+        ModsOrder::Transliterate => {
+            let mut modtransliterate = gui.modifiers.transliterate.clone();
+            let modtransliterate_enabled = gui.modifiers.transliterate_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Transliterate");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.transliterate_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modtransliterate_enabled, |ui| {
+                        gui.modifications_total += fill_modtransliterate(gui, ui, &mut modtransliterate);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.transliterate = modtransliterate;
+        },
+        ModsOrder::Normalize => {
+            let mut modnormalize = gui.modifiers.normalize.clone();
+            let modnormalize_enabled = gui.modifiers.normalize_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Normalize");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.normalize_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modnormalize_enabled, |ui| {
+                        gui.modifications_total += fill_modnormalize(gui, ui, &mut modnormalize);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.normalize = modnormalize;
+        },
+        ModsOrder::Dictionary => {
+            let mut moddictionary = gui.modifiers.dictionary.clone();
+            let moddictionary_enabled = gui.modifiers.dictionary_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Dictionary");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.dictionary_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(moddictionary_enabled, |ui| {
+                        gui.modifications_total += fill_moddictionary(gui, ui, &mut moddictionary);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.dictionary = moddictionary;
+        },
+        ModsOrder::Renumber => {
+            let mut modrenumber = gui.modifiers.renumber.clone();
+            let modrenumber_enabled = gui.modifiers.renumber_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Renumber");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.renumber_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modrenumber_enabled, |ui| {
+                        gui.modifications_total += fill_modrenumber(gui, ui, &mut modrenumber);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.renumber = modrenumber;
+        },
+        ModsOrder::Sequence => {
+            let mut modsequence = gui.modifiers.sequence.clone();
+            let modsequence_enabled = gui.modifiers.sequence_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Sequence");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.sequence_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modsequence_enabled, |ui| {
+                        gui.modifications_total += fill_modsequence(gui, ui, &mut modsequence);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.sequence = modsequence;
+        },
+        ModsOrder::Random => {
+            let mut modrandom = gui.modifiers.random.clone();
+            let modrandom_enabled = gui.modifiers.random_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Random");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.random_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modrandom_enabled, |ui| {
+                        gui.modifications_total += fill_modrandom(gui, ui, &mut modrandom);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.random = modrandom;
+        },
+        ModsOrder::Truncate => {
+            let mut modtruncate = gui.modifiers.truncate.clone();
+            let modtruncate_enabled = gui.modifiers.truncate_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Truncate");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.truncate_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modtruncate_enabled, |ui| {
+                        gui.modifications_total += fill_modtruncate(gui, ui, &mut modtruncate);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.truncate = modtruncate;
+        },
+        ModsOrder::Seperators => {
+            let mut modseperators = gui.modifiers.seperators.clone();
+            let modseperators_enabled = gui.modifiers.seperators_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Seperators");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.seperators_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modseperators_enabled, |ui| {
+                        gui.modifications_total += fill_modseperators(ui, &mut modseperators);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.seperators = modseperators;
+        },
+        ModsOrder::Mapping => {
+            let mut modmapping = gui.modifiers.mapping.clone();
+            let modmapping_enabled = gui.modifiers.mapping_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Mapping");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.mapping_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modmapping_enabled, |ui| {
+                        gui.modifications_total += fill_modmapping(gui, ui, &mut modmapping);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.mapping = modmapping;
+        },
+        ModsOrder::NameList => {
+            let mut modnamelist = gui.modifiers.name_list.clone();
+            let modnamelist_enabled = gui.modifiers.name_list_enabled;
+            ui.group(|ui| {
+                // Title Bar
+                ui.horizontal(|ui| {
+                    ui.label("Names From List");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::TOP), |ui| {
+                        ui.add_enabled_ui(!headers_only, |ui| {
+                            ui.checkbox(&mut gui.modifiers.name_list_enabled, "");
+                        });
+                    });
+                });
+                if headers_only { return }; // Rearranging causes headers to collapse.
+                // Body
+                ui.vertical(|ui| {
+                    ui.separator();
+                    ui.add_enabled_ui(modnamelist_enabled, |ui| {
+                        gui.modifications_total += fill_modnamelist(gui, ui, &mut modnamelist);
+                    });
+                });
+            });
+            // Refill Modifiers
+            gui.modifiers.name_list = modnamelist;
+        }
+    };
+}
+
+/// Anchor row for the modifiers that insert at a position.
+fn fill_insert_anchor(ui: &mut egui::Ui, anchor: &mut InsertAnchor, id: String) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {
+        ui.label("Anchor");
+        egui::ComboBox::new(format!("{}-anchor", id), "")
+        .selected_text(anchor.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "Position").clicked() {
+                anchor.mode_name = String::from("Position");
+                anchor.mode = AnchorMode::Position;
+            }
+            if ui.selectable_label(false, "Before First").clicked() {
+                anchor.mode_name = String::from("Before First");
+                anchor.mode = AnchorMode::BeforeFirst;
+            }
+            if ui.selectable_label(false, "After First").clicked() {
+                anchor.mode_name = String::from("After First");
+                anchor.mode = AnchorMode::AfterFirst;
+            }
+            if ui.selectable_label(false, "Before Last").clicked() {
+                anchor.mode_name = String::from("Before Last");
+                anchor.mode = AnchorMode::BeforeLast;
+            }
+            if ui.selectable_label(false, "After Last").clicked() {
+                anchor.mode_name = String::from("After Last");
+                anchor.mode = AnchorMode::AfterLast;
+            }
+        });
+        ui.add_enabled_ui(anchor.mode != AnchorMode::Position, |ui| {
+            ui.label("Else");
+            egui::ComboBox::new(format!("{}-anchor_fallback", id), "")
+            .selected_text(anchor.fallback_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "Skip").clicked() {
+                    anchor.fallback_name = String::from("Skip");
+                    anchor.fallback = AnchorFallback::Skip;
+                }
+                if ui.selectable_label(false, "Position").clicked() {
+                    anchor.fallback_name = String::from("Position");
+                    anchor.fallback = AnchorFallback::Position;
+                }
+                if ui.selectable_label(false, "Start").clicked() {
+                    anchor.fallback_name = String::from("Start");
+                    anchor.fallback = AnchorFallback::Start;
+                }
+                if ui.selectable_label(false, "End").clicked() {
+                    anchor.fallback_name = String::from("End");
+                    anchor.fallback = AnchorFallback::End;
+                }
+            });
+            ui.label("Regex");
+            ui.checkbox(&mut anchor.regex, "");
+        });
+    });
+    if anchor.mode != AnchorMode::Position {
+        ui.horizontal(|ui| {
+            ui.label("Match");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut anchor.pattern)
+                .char_limit(255)
+            );
+        });
+        if anchor.regex {
+            if let Err(error) = regex::Regex::new(&anchor.pattern) {
+                ui.colored_label(egui::Color32::RED, error.to_string());
+            };
+        };
+    };
+    // Fill modifications
+    {
+        if anchor.mode != AnchorMode::Position { modifications += 1 };
+        if anchor.pattern.chars().count() >= 1 { modifications += 1 };
+        if anchor.regex == true { modifications += 1 };
+        if anchor.fallback != AnchorFallback::Skip { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modadd(gui: &mut WindowMain, ui: &mut egui::Ui, add: &mut ModAdd, index: usize) -> (ModAdd, u32) {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {
+        ui.label("Prefix");
+        ui.add_sized(
+            egui::vec2(ui.available_width(), ui.available_height()), 
+            egui::widgets::text_edit::TextEdit::singleline(&mut add.prefix)
+        );
+    });
+    ui.horizontal(|ui| {
+        ui.label("Insert");
+        ui.add_sized(
+            egui::vec2(ui.available_width() - 145.0, ui.available_height()), 
+            egui::widgets::text_edit::TextEdit::singleline(&mut add.insert)
+        );
+        ui.label("at");
+        let drag = ui.add_enabled(true, 
+            egui::DragValue::new(&mut add.insert_at)
+            .range(-255..=255)
+            .speed(0.05)
+        );
+
+        if drag.hovered() {
+            gui.modifiers.drag_box_hovered = true;
+        };
+
+        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+            add.insert_at += 1;
+        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+            if add.insert_at >= 1 {
+                add.insert_at -= 1;
+            }
+        };
+        if ui.small_button("➖").clicked() {
+            add.insert_at -= 1;
+        };
+
+        ui.separator();
+
+        if ui.small_button("➕").clicked() {
+            add.insert_at += 1;
+        };
+    });
+    modifications += fill_insert_anchor(ui, &mut add.anchor, format!("add-{}", index));
+    ui.horizontal(|ui| {
+        ui.label("Suffix");
+        ui.add_sized(
+            egui::vec2(ui.available_width(), ui.available_height()), 
+            egui::widgets::text_edit::TextEdit::singleline(&mut add.suffix)
+        );
+    });
+    // Fill modifications
+    {
+        if add.insert_at != 0 { modifications += 1 };
+        if add.prefix.chars().count() >= 1 { modifications += 1 };
+        if add.suffix.chars().count() >= 1 { modifications += 1 };
+        if add.seperator_enabled == true { modifications += 1 };
+    }
+    return (add.to_owned(), modifications);
+}
+
+fn fill_modcase(gui: &mut WindowMain, ui: &mut egui::Ui, case: &mut ModCase, index: usize) -> (ModCase, u32) {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| { 
+        ui.horizontal(|ui| {
+            ui.label("Mode");
+            egui::ComboBox::new(format!("case-{}", index), "")
+                .selected_text(case.mode_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Same").clicked() {
+                        case.mode_name = String::from("Same");
+                        case.mode = CaseMode::Same;
+                    }
+                    if ui.selectable_label(false, "Upper").clicked() {
+                        case.mode_name = String::from("Upper");
+                        case.mode = CaseMode::Upper;
+                    }
+                    if ui.selectable_label(false, "Lower").clicked() {
+                        case.mode_name = String::from("Lower");
+                        case.mode = CaseMode::Lower;
+                    }
+                    if ui.selectable_label(false, "Title").clicked() {
+                        case.mode_name = String::from("Title");
+                        case.mode = CaseMode::Title;
+                    }
+                    if ui.selectable_label(false, "UpperFirst").clicked() {
+                        case.mode_name = String::from("UpperFirst");
+                        case.mode = CaseMode::UpperFirst;
+                    }
+                    if ui.selectable_label(false, "camelCase").clicked() {
+                        case.mode_name = String::from("camelCase");
+                        case.mode = CaseMode::Camel;
+                    }
+                    if ui.selectable_label(false, "PascalCase").clicked() {
+                        case.mode_name = String::from("PascalCase");
+                        case.mode = CaseMode::Pascal;
+                    }
+                    if ui.selectable_label(false, "snake_case").clicked() {
+                        case.mode_name = String::from("snake_case");
+                        case.mode = CaseMode::Snake;
+                    }
+                    if ui.selectable_label(false, "kebab-case").clicked() {
+                        case.mode_name = String::from("kebab-case");
+                        case.mode = CaseMode::Kebab;
+                    }
+                    if ui.selectable_label(false, "CONSTANT_CASE").clicked() {
+                        case.mode_name = String::from("CONSTANT_CASE");
+                        case.mode = CaseMode::Constant;
+                    }
+                    if ui.selectable_label(false, "Sentence case").clicked() {
+                        case.mode_name = String::from("Sentence case");
+                        case.mode = CaseMode::Sentence;
+                    }
+                });
+
+                ui.separator();
+                ui.add_enabled_ui(case.except_enabled, |ui| {
+                    ui.label("Except");
+                    egui::ComboBox::new(format!("case-except-{}", index), "")
+                        .selected_text(case.except_mode_name.to_owned())
+                        .show_ui(ui, |ui| {
+                            if ui.selectable_label(false, "None").clicked() {
+                                case.except_mode_name = String::from("None");
+                                case.except_mode = CaseExecptMode::None;
+                            }
+                            if ui.selectable_label(false, "FromTo").clicked() {
+                                case.except_mode_name = String::from("FromTo");
+                                case.except_mode = CaseExecptMode::FromTo;
+                            }
+                            if ui.selectable_label(false, "Match").clicked() {
+                                case.except_mode_name = String::from("Match");
+                                case.except_mode = CaseExecptMode::Match;
+                            }
+                    });
+                });
+            });
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(case.widgets_enabled, |ui| {
+                match case.except_mode {
+                    CaseExecptMode::FromTo => {
+                        ui.label("From");
+                        let drag = ui.add_enabled(true, 
+                            egui::DragValue::new(&mut case.except_from)
+                            .range(0..=255)
+                            .speed(0.05)
+                            
+                        );
+
+                        if drag.hovered() {
+                            gui.modifiers.drag_box_hovered = true;
+                        };
+                
+                        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                            case.except_from += 1;
+                        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                            if case.except_from >= 1 {
+                                case.except_from -= 1;
+                            };
+                        };
+                        if ui.small_button("➖").clicked() {
+                            if case.except_from >= 1 {
+                                case.except_from -= 1;
+                            };
+                        };
+                
+                        ui.separator();
+                
+                        if ui.small_button("➕").clicked() {
+                            case.except_from += 1;
+                        };
+
+                        ui.label("To");
+                        let drag = ui.add_enabled(true, 
+                            egui::DragValue::new(&mut case.except_to)
+                            .range(case.except_from..=255)
+                            .speed(0.05)
+                        );
+
+                        if drag.hovered() {
+                            gui.modifiers.drag_box_hovered = true;
+                        };
+                
+                        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                            case.except_to += 1;
+                        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                            if case.except_to >= 1 {
+                                case.except_to -= 1;
+                            };
+                        };
+                        if ui.small_button("➖").clicked() {
+                            if case.except_to >= 1 {
+                                case.except_to -= 1;
+                            };
+                        };
+                
+                        ui.separator();
+                
+                        if ui.small_button("➕").clicked() {
+                            case.except_to += 1;
+                        };
+                    },
+                    CaseExecptMode::Match => {
+                        ui.label("Match");
+                        ui.add_sized(
+                            egui::vec2(ui.available_width(), ui.available_height()), 
+                            egui::text_edit::TextEdit::singleline(&mut case.except)
+                        );
+                    },
+                    _ => {}
+                }
+            });
+        });
+        if case.mode == CaseMode::Title {
+            ui.horizontal(|ui| {
+                ui.label("Language");
+                egui::ComboBox::new(format!("case-language-{}", index), "")
+                    .selected_text(case.title_language_name.to_owned())
+                    .show_ui(ui, |ui| {
+                            if ui.selectable_label(false, "None").clicked() {
+                                case.title_language_name = String::from("None");
+                                case.title_language = TitleLanguage::None;
+                            }
+                            if ui.selectable_label(false, "English").clicked() {
+                                case.title_language_name = String::from("English");
+                                case.title_language = TitleLanguage::English;
+                            }
+                            if ui.selectable_label(false, "French").clicked() {
+                                case.title_language_name = String::from("French");
+                                case.title_language = TitleLanguage::French;
+                            }
+                            if ui.selectable_label(false, "German").clicked() {
+                                case.title_language_name = String::from("German");
+                                case.title_language = TitleLanguage::German;
+                            }
+                            if ui.selectable_label(false, "Spanish").clicked() {
+                                case.title_language_name = String::from("Spanish");
+                                case.title_language = TitleLanguage::Spanish;
+                            }
+                            if ui.selectable_label(false, "Italian").clicked() {
+                                case.title_language_name = String::from("Italian");
+                                case.title_language = TitleLanguage::Italian;
+                            }
+                            if ui.selectable_label(false, "Portuguese").clicked() {
+                                case.title_language_name = String::from("Portuguese");
+                                case.title_language = TitleLanguage::Portuguese;
+                            }
+                            if ui.selectable_label(false, "Dutch").clicked() {
+                                case.title_language_name = String::from("Dutch");
+                                case.title_language = TitleLanguage::Dutch;
+                            }
+                    });
+                ui.label("Small Words");
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut case.small_words)
+                ).on_hover_text("Extra words kept lowercase (besides the first and last word), seperated by spaces or commas.");
+            });
+        };
+        if case.mode != CaseMode::Same {
+            ui.horizontal(|ui| {
+                ui.label("Protected");
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut case.protected_words)
+                ).on_hover_text("Words left exactly as written, like NASA, USB or iPhone. Seperated by spaces or commas.");
+            });
+        };
+    });
+    match case.except_mode {
+        CaseExecptMode::None => {
+            case.widgets_enabled = false;
+        },
+        _ => { case.widgets_enabled = true }
+    }
+    match case.mode {
+        CaseMode::Upper | CaseMode::Lower => {
+            case.except_enabled = true;
+        },
+        _ => {
+            case.except_enabled = false;
+        }
+    };
+    // Fill modifications
+    {
+        if case.except_mode != CaseExecptMode::None { modifications += 1 };
+        if case.except.chars().count() >= 1 { modifications += 1 };
+        if case.except_from >= 1 { modifications += 1 };
+        if case.except_to >= 1 { modifications += 1 };
+        if case.mode != CaseMode::Same { modifications += 1 };
+        if case.title_language != TitleLanguage::English { modifications += 1 };
+        if case.small_words.chars().count() >= 1 { modifications += 1 };
+        if case.protected_words.chars().count() >= 1 { modifications += 1 };
+    }
+    return (case.to_owned(), modifications);
+}
+
+fn fill_moddate(gui: &mut WindowMain, ui: &mut egui::Ui, date: &mut ModDate, index: usize) -> (ModDate, u32) {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("Mode");
+            egui::ComboBox::new(format!("date-{}", index), "")
+            .selected_text(date.mode_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "None").clicked() {
+                    date.mode_name = String::from("None");
+                    date.mode = DateMode::None;
+                }
+                if ui.selectable_label(false, "Prefix").clicked() {
+                    date.mode_name = String::from("Prefix");
+                    date.mode = DateMode::Prefix;
+                }
+                if ui.selectable_label(false, "Suffix").clicked() {
+                    date.mode_name = String::from("Suffix");
+                    date.mode = DateMode::Suffix;
+                }
+                if ui.selectable_label(false, "Insert").clicked() {
+                    date.mode_name = String::from("Insert");
+                    date.mode = DateMode::Insert;
+                }
+            });
+            ui.add_enabled_ui(date.at_enabled, |ui| {
+                ui.label("at");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut date.at_pos)
+                    .range(-255..=255)
+                    .speed(0.05)
+                );
+
+                if drag.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    date.at_pos += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if date.at_pos >= 1 {
+                        date.at_pos -= 1;
+                    }
+                };
+                if ui.small_button("➖").clicked() {
+                    date.at_pos -= 1;
+                };
+        
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    date.at_pos += 1;
+                };
+            });
+        });
+        if date.at_enabled {
+            modifications += fill_insert_anchor(ui, &mut date.anchor, format!("date-{}", index));
+        };
+        ui.horizontal(|ui| {
+            ui.label("Format");
+            ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                egui::ComboBox::new(format!("date_format-{}", index), "")
+                .selected_text(date.format_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Y").clicked() {
+                        date.format_name = String::from("Y");
+                        date.format = DateFormatMode::Y;
+                    }
+                    if ui.selectable_label(false, "MY").clicked() {
+                        date.format_name = String::from("MY");
+                        date.format = DateFormatMode::MY;
+                    }
+                    if ui.selectable_label(false, "DMY").clicked() {
+                        date.format_name = String::from("DMY");
+                        date.format = DateFormatMode::DMY;
+                    }
+                    if ui.selectable_label(false, "DMYH").clicked() {
+                        date.format_name = String::from("DMYH");
+                        date.format = DateFormatMode::DMYH;
+                    }
+                    if ui.selectable_label(false, "DMYHM").clicked() {
+                        date.format_name = String::from("DMYHM");
+                        date.format = DateFormatMode::DMYHM;
+                    }
+                    if ui.selectable_label(false, "DMYHMS").clicked() {
+                        date.format_name = String::from("DMYHMS");
+                        date.format = DateFormatMode::DMYHMS;
+                    }
+                    if ui.selectable_label(false, "YM").clicked() {
+                        date.format_name = String::from("YM");
+                        date.format = DateFormatMode::YM;
+                    }
+                    if ui.selectable_label(false, "YMD").clicked() {
+                        date.format_name = String::from("YMD");
+                        date.format = DateFormatMode::YMD;
+                    }
+                    if ui.selectable_label(false, "YMDH").clicked() {
+                        date.format_name = String::from("YMDH");
+                        date.format = DateFormatMode::YMDH;
+                    }
+                    if ui.selectable_label(false, "YMDHM").clicked() {
+                        date.format_name = String::from("YMDHM");
+                        date.format = DateFormatMode::YMDHM;
+                    }
+                    if ui.selectable_label(false, "YMDHMS").clicked() {
+                        date.format_name = String::from("YMDHMS");
+                        date.format = DateFormatMode::YMDHMS;
+                    }
+                    if ui.selectable_label(false, "MDY").clicked() {
+                        date.format_name = String::from("MDY");
+                        date.format = DateFormatMode::MDY;
+                    }
+                    if ui.selectable_label(false, "MDYH").clicked() {
+                        date.format_name = String::from("MDYH");
+                        date.format = DateFormatMode::MDYH;
+                    }
+                    if ui.selectable_label(false, "MDYHM").clicked() {
+                        date.format_name = String::from("MDYHM");
+                        date.format = DateFormatMode::MDYHM;
+                    }
+                    if ui.selectable_label(false, "MDYHMS").clicked() {
+                        date.format_name = String::from("MDYHMS");
+                        date.format = DateFormatMode::MDYHMS;
+                    }
+                    if ui.selectable_label(false, "Custom").clicked() {
+                        date.format_name = String::from("Custom");
+                        date.format = DateFormatMode::Custom;
+                    }
+                });
+            });
+            ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                ui.label("Seperator");
+                egui::ComboBox::new(format!("date_seperator-{}", index), "")
+                .selected_text(date.seperator_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "None").clicked() {
+                        date.seperator = DateSeperator::None;
+                        date.seperator_name = String::from("None");
+                    }
+                    if ui.selectable_label(false, "Space  ").clicked() {
+                        date.seperator = DateSeperator::Space;
+                        date.seperator_name = String::from("Space  ");
+                    }
+                    if ui.selectable_label(false, "Asterisk **").clicked() {
+                        date.seperator = DateSeperator::Asterisk;
+                        date.seperator_name = String::from("Asterisk **");
+                    }
+                    if ui.selectable_label(false, "Bracket []").clicked() {
+                        date.seperator = DateSeperator::Bracket;
+                        date.seperator_name = String::from("Bracket []");
+                    }
+                    ui.add_enabled_ui(!cfg!(windows), |ui| {
+                        if ui.selectable_label(false, "Colan ::").clicked() {
+                            date.seperator = DateSeperator::Colan;
+                            date.seperator_name = String::from("Colan :");
+                        }
+                    });
+                    if ui.selectable_label(false, "CurlyBracket {}").clicked() {
+                        date.seperator = DateSeperator::CurlyBracket;
+                        date.seperator_name = String::from("CurlyBracket {}");
+                    }
+                    ui.add_enabled_ui(!cfg!(windows), |ui| {
+                        if ui.selectable_label(false, "Line ||").clicked() {
+                            date.seperator = DateSeperator::Line;
+                            date.seperator_name = String::from("Line ||");
+                        }
+                    });
+                    if ui.selectable_label(false, "Minus --").clicked() {
+                        date.seperator = DateSeperator::Minus;
+                        date.seperator_name = String::from("Minus --");
+                    }
+                    if ui.selectable_label(false, "Parenthesis ()").clicked() {
+                        date.seperator = DateSeperator::Parenthesis;
+                        date.seperator_name = String::from("Parenthesis ()");
+                    }
+                    if ui.selectable_label(false, "Plus ++").clicked() {
+                        date.seperator = DateSeperator::Plus;
+                        date.seperator_name = String::from("Plus ++");
+                    }
+                    if ui.selectable_label(false, "Sign <>").clicked() {
+                        date.seperator = DateSeperator::Sign;
+                        date.seperator_name = String::from("Sign <>");
+                    }
+                    if ui.selectable_label(false, "Underscore __").clicked() {
+                        date.seperator = DateSeperator::Underscore;
+                        date.seperator_name = String::from("Underscore __");
+                    }
+                });
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                ui.label("Seg. Date");
+                egui::ComboBox::new(format!("date_seg_year-{}", index), "")
+                .selected_text(date.segregator_year_name.to_owned())
+                .show_ui(ui, |ui| {
+                    ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                        if ui.selectable_label(false, "None").clicked() {
+                            date.segregator_year = DateSeperator::None;
+                            date.segregator_year_name = String::from("None");
+                        }
+                        if ui.selectable_label(false, "Space ").clicked() {
+                            date.segregator_year = DateSeperator::Space;
+                            date.segregator_year_name = String::from("Space ");
+                        }
+                        ui.add_enabled_ui(!cfg!(windows), |ui| {
+                            if ui.selectable_label(false, "Colan :").clicked() {
+                                date.segregator_year = DateSeperator::Colan;
+                                date.segregator_year_name = String::from("Colan :");
+                            }
+                        });
+                        ui.add_enabled_ui(!cfg!(windows), |ui| {
+                            if ui.selectable_label(false, "Line |").clicked() {
+                                date.segregator_year = DateSeperator::Line;
+                                date.segregator_year_name = String::from("Line |");
+                            }
+                        });
+                        if ui.selectable_label(false, "Minus -").clicked() {
+                            date.segregator_year = DateSeperator::Minus;
+                            date.segregator_year_name = String::from("Minus -");
+                        }
+                        if ui.selectable_label(false, "Plus +").clicked() {
+                            date.segregator_year = DateSeperator::Plus;
+                            date.segregator_year_name = String::from("Plus +");
+                        }
+                        if ui.selectable_label(false, "Underscore _").clicked() {
+                            date.segregator_hour = DateSeperator::Underscore;
+                            date.segregator_year_name = String::from("Underscore _");
+                        }
+                    });
+                });
+                ui.label("Seg. Time");
+                egui::ComboBox::new(format!("date_seg_hour-{}", index), "")
+                .selected_text(date.segregator_hour_name.to_owned())
+                .show_ui(ui, |ui| {
+                    ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                        if ui.selectable_label(false, "None").clicked() {
+                            date.segregator_hour = DateSeperator::None;
+                            date.segregator_hour_name = String::from("None");
+                        }
+                        if ui.selectable_label(false, "Space ").clicked() {
+                            date.segregator_hour = DateSeperator::Space;
+                            date.segregator_hour_name = String::from("Space ");
+                        }
+                        ui.add_enabled_ui(!cfg!(windows), |ui| {
+                            if ui.selectable_label(false, "Colan :").clicked() {
+                                date.segregator_hour = DateSeperator::Colan;
+                                date.segregator_hour_name = String::from("Colan :");
+                            }
+                        });
+                        ui.add_enabled_ui(!cfg!(windows), |ui| {
+                            if ui.selectable_label(false, "Line |").clicked() {
+                                date.segregator_hour = DateSeperator::Line;
+                                date.segregator_hour_name = String::from("Line |");
+                            }
+                        });
+                        if ui.selectable_label(false, "Minus -").clicked() {
+                            date.segregator_hour = DateSeperator::Minus;
+                            date.segregator_hour_name = String::from("Minus -");
+                        }
+                        if ui.selectable_label(false, "Plus +").clicked() {
+                            date.segregator_hour = DateSeperator::Plus;
+                            date.segregator_hour_name = String::from("Plus +");
+                        }
+                        if ui.selectable_label(false, "Underscore _").clicked() {
+                            date.segregator_hour = DateSeperator::Underscore;
+                            date.segregator_hour_name = String::from("Underscore _");
+                        }
+                    });
+                });
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(date.widgets_enabled, |ui| {
+                ui.label("Century").on_hover_text("1999 -> 99");
+                ui.checkbox(&mut date.century, "");
+            });
+            ui.add_enabled_ui(date.custom_enabled, |ui| {
+                ui.label("Custom");
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut date.custom
+                )).on_hover_text(format!(
+                    "Create a custom format using the following"
+                ))
+            });
+        });
+    });
+    match date.mode {
+        DateMode::None => {
+            date.widgets_enabled = false;
+            date.at_enabled = false;
+        },
+        DateMode::Insert => {
+            date.widgets_enabled = true;
+            date.at_enabled = true;
+        }
+        _ => { 
+            date.widgets_enabled = true;
+            date.at_enabled = false;
+        }
+    };
+    match date.format {
+        DateFormatMode::Custom => {
+            date.custom_enabled = true;
+        },
+        _ => {
+            date.custom_enabled = false;
         }
     };
+    // Fill modifications
+    {
+        if date.segregator_hour_enabled == true { modifications += 1 };
+        if date.segregator_year_enabled == true { modifications += 1 };
+        if date.at_pos != 0 { modifications += 1 };
+        if date.custom.chars().count() >= 1 { modifications += 1 };
+        if date.mode != DateMode::None { modifications += 1 };
+    }
+    return (date.to_owned(), modifications);
+}
+
+fn fill_modextension(_gui: &mut WindowMain, ui: &mut egui::Ui, ext: &mut ModExtension) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {ui.label("Mode");
+        egui::ComboBox::new(format!("extension"), "")
+        .selected_text(ext.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "Same").clicked() {
+                ext.mode_name = String::from("Same");
+                ext.mode = ExtensionMode::Same;
+            }
+            if ui.selectable_label(false, "Upper").clicked() {
+                ext.mode_name = String::from("Upper");
+                ext.mode = ExtensionMode::Upper;
+            }
+            if ui.selectable_label(false, "Lower").clicked() {
+                ext.mode_name = String::from("Lower");
+                ext.mode = ExtensionMode::Lower;
+            }
+            if ui.selectable_label(false, "UpperFirst").clicked() {
+                ext.mode_name = String::from("UpperFirst");
+                ext.mode = ExtensionMode::UpperFirst;
+            }
+            if ui.selectable_label(false, "Fixed").clicked() {
+                ext.mode_name = String::from("Fixed");
+                ext.mode = ExtensionMode::Fixed;
+            }
+            if ui.selectable_label(false, "Extra").clicked() {
+                ext.mode_name = String::from("Extra");
+                ext.mode = ExtensionMode::Extra;
+            }
+            if ui.selectable_label(false, "Remove").clicked() {
+                ext.mode_name = String::from("Remove");
+                ext.mode = ExtensionMode::Remove;
+            }
+        });
+    });
+    ui.horizontal(|ui| {
+        ui.add_enabled_ui(ext.widgets_enabled, |ui| {
+            ui.label("Fixed");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut ext.fixed)
+            );
+        });
+    });
+    ui.horizontal(|ui| {
+        ui.add_enabled_ui(ext.widgets_enabled, |ui| {
+            ui.label("Extra");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut ext.extra)
+            );
+        });
+    });
+    match ext.mode {
+        ExtensionMode::Same => {
+            ext.widgets_enabled = false;
+        },
+        _ => { ext.widgets_enabled = true }
+    };
+    // Fill modifications
+    {
+        if ext.extra.chars().count() >= 1 { modifications += 1 };
+        if ext.fixed.chars().count() >= 1 { modifications += 1 };
+        if ext.mode != ExtensionMode::Same { modifications += 1 };
+    }
+    return modifications;
 }
 
-fn fill_modadd(gui: &mut WindowMain, ui: &mut egui::Ui, add: &mut ModAdd) -> (ModAdd, u32) {
+fn fill_modhash(gui: &mut WindowMain, ui: &mut egui::Ui, hash: &mut ModHashing) -> u32 {
     let mut modifications: u32 = 0;
     ui.horizontal(|ui| {
-        ui.label("Prefix");
-        ui.add_sized(
-            egui::vec2(ui.available_width(), ui.available_height()), 
-            egui::widgets::text_edit::TextEdit::singleline(&mut add.prefix)
-        );
+        ui.label("Mode");
+        egui::ComboBox::new(format!("hashmode"), "")
+        .selected_text(hash.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "None").clicked() {
+                hash.mode_name = String::from("None");
+                hash.mode = threads::HashMode::None;
+            }
+            if ui.selectable_label(false, "Prefix").clicked() {
+                hash.mode_name = String::from("Prefix");
+                hash.mode = threads::HashMode::Prefix;
+            }
+            if ui.selectable_label(false, "Suffix").clicked() {
+                hash.mode_name = String::from("Suffix");
+                hash.mode = threads::HashMode::Suffix;
+            }
+            if ui.selectable_label(false, "Insert").clicked() {
+                hash.mode_name = String::from("Insert");
+                hash.mode = threads::HashMode::Insert;
+            }
+            if ui.selectable_label(false, "File").clicked() {
+                hash.mode_name = String::from("File");
+                hash.mode = threads::HashMode::File;
+            }
+        });
+        ui.label("Algorithm");
+        ui.add_enabled_ui(hash.widgets_enabled, |ui| {
+            egui::ComboBox::new(format!("hashalgorithm"), "")
+            .selected_text(hash.algorithm_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "CRC32").clicked() {
+                    hash.algorithm_name = String::from("CRC32");
+                    hash.algorithm = threads::HashType::CRC32;
+                }
+                if ui.selectable_label(false, "MD5").clicked() {
+                    hash.algorithm_name = String::from("MD5");
+                    hash.algorithm = threads::HashType::MD5;
+                }
+                if ui.selectable_label(false, "Sha1").clicked() {
+                    hash.algorithm_name = String::from("Sha1");
+                    hash.algorithm = threads::HashType::Sha1;
+                }
+                if ui.selectable_label(false, "Sha256").clicked() {
+                    hash.algorithm_name = String::from("Sha256");
+                    hash.algorithm = threads::HashType::Sha256;
+                }
+            });
+        });
     });
     ui.horizontal(|ui| {
-        ui.label("Insert");
-        ui.add_sized(
-            egui::vec2(ui.available_width() - 145.0, ui.available_height()), 
-            egui::widgets::text_edit::TextEdit::singleline(&mut add.insert)
-        );
-        ui.label("at");
-        let drag = ui.add_enabled(true, 
-            egui::DragValue::new(&mut add.insert_at)
-            .range(-255..=255)
-            .speed(0.05)
-        );
+        ui.label("Seperator");
+        egui::ComboBox::new(format!("hashaseperator"), "")
+        .selected_text(hash.seperator_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "None").clicked() {
+                hash.seperator_name = String::from("None");
+                hash.seperator = HashSeperator::None;
+            }
+            if ui.selectable_label(false, "Asterisk **").clicked() {
+                hash.seperator_name = String::from("Asterisk **");
+                hash.seperator = HashSeperator::Asterisk;
+            }
+            if ui.selectable_label(false, "Bracket []").clicked() {
+                hash.seperator_name = String::from("Bracket []");
+                hash.seperator = HashSeperator::Bracket;
+            }
+            if ui.selectable_label(false, "Colan ::").clicked() {
+                hash.seperator_name = String::from("Colan ::");
+                hash.seperator = HashSeperator::Colan;
+            }
+            if ui.selectable_label(false, "CurlyBracket {}").clicked() {
+                hash.seperator_name = String::from("CurlyBracket {}");
+                hash.seperator = HashSeperator::CurlyBracket;
+            }
+            if ui.selectable_label(false, "Line ||").clicked() {
+                hash.seperator_name = String::from("Line ||");
+                hash.seperator = HashSeperator::Line;
+            }
+            if ui.selectable_label(false, "Minus --").clicked() {
+                hash.seperator_name = String::from("Minus --");
+                hash.seperator = HashSeperator::Minus;
+            }
+            if ui.selectable_label(false, "Parenthesis ()").clicked() {
+                hash.seperator_name = String::from("Parenthesis ()");
+                hash.seperator = HashSeperator::Parenthesis;
+            }
+            if ui.selectable_label(false, "Plus ++").clicked() {
+                hash.seperator_name = String::from("Plus ++");
+                hash.seperator = HashSeperator::Plus;
+            }
+            if ui.selectable_label(false, "Sign <>").clicked() {
+                hash.seperator_name = String::from("Sign <>");
+                hash.seperator = HashSeperator::Sign;
+            }
+            if ui.selectable_label(false, "Space  ").clicked() {
+                hash.seperator_name = String::from("Space  ");
+                hash.seperator = HashSeperator::Space;
+            }
+            if ui.selectable_label(false, "Underscore __").clicked() {
+                hash.seperator_name = String::from("Underscore __");
+                hash.seperator = HashSeperator::Underscore;
+            }
+        });
+    });
+    if hash.mode == threads::HashMode::Insert {
+        ui.horizontal(|ui| {
+            ui.label("at");
+            let drag = ui.add_enabled(true, 
+                egui::DragValue::new(&mut hash.insert_at)
+                .range(-255..=255)
+                .speed(0.05)
+            );
 
-        if drag.hovered() {
-            gui.modifiers.drag_box_hovered = true;
-        };
+            if drag.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
 
-        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-            add.insert_at += 1;
-        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-            if add.insert_at >= 1 {
-                add.insert_at -= 1;
-            }
-        };
-        if ui.small_button("➖").clicked() {
-            add.insert_at -= 1;
-        };
+            if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                hash.insert_at += 1;
+            } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                hash.insert_at -= 1;
+            };
+            if ui.small_button("➖").clicked() {
+                hash.insert_at -= 1;
+            };
 
-        ui.separator();
+            ui.separator();
 
-        if ui.small_button("➕").clicked() {
-            add.insert_at += 1;
-        };
-    });
-    ui.horizontal(|ui| {
-        ui.label("Suffix");
-        ui.add_sized(
-            egui::vec2(ui.available_width(), ui.available_height()), 
-            egui::widgets::text_edit::TextEdit::singleline(&mut add.suffix)
-        );
-    });
-    // Fill modifications
+            if ui.small_button("➕").clicked() {
+                hash.insert_at += 1;
+            };
+        });
+        modifications += fill_insert_anchor(ui, &mut hash.anchor, String::from("hash"));
+    };
+    match hash.mode {
+        threads::HashMode::None => {
+            hash.widgets_enabled = false;
+        },
+        _ => { hash.widgets_enabled = true }
+    };
+    // Fill modification
     {
-        if add.insert_at != 0 { modifications += 1 };
-        if add.prefix.chars().count() >= 1 { modifications += 1 };
-        if add.suffix.chars().count() >= 1 { modifications += 1 };
-        if add.seperator_enabled == true { modifications += 1 };
+        if hash.mode != threads::HashMode::None { modifications += 1 };
+        if hash.insert_at != 0 { modifications += 1 };
     }
-    return (add.to_owned(), modifications);
+    return modifications;
 }
 
-fn fill_modcase(gui: &mut WindowMain, ui: &mut egui::Ui, case: &mut ModCase, index: usize) -> (ModCase, u32) {
+fn fill_modmovecopy(gui: &mut WindowMain, ui: &mut egui::Ui, movecopy: &mut ModMoveCopy, index: usize) -> (ModMoveCopy, u32) {
     let mut modifications: u32 = 0;
-    ui.vertical(|ui| { 
+    let mut seperator: String = movecopy.seperator.to_string();
+    ui.vertical(|ui| {
         ui.horizontal(|ui| {
             ui.label("Mode");
-            egui::ComboBox::new(format!("case-{}", index), "")
-                .selected_text(case.mode_name.to_owned())
-                .show_ui(ui, |ui| {
-                    if ui.selectable_label(false, "Same").clicked() {
-                        case.mode_name = String::from("Same");
-                        case.mode = CaseMode::Same;
+            egui::ComboBox::new(format!("movecopyfrom-{}", index), "")
+            .selected_text(movecopy.mode_from_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "None").clicked() {
+                    movecopy.mode_from_name = String::from("None");
+                    movecopy.mode_from = MoveCopyFromMode::None;
+                }
+                if ui.selectable_label(false, "Copy First N").clicked() {
+                    movecopy.mode_from_name = String::from("Copy First N");
+                    movecopy.mode_from = MoveCopyFromMode::CopyFirstN;
+                }
+                if ui.selectable_label(false, "Copy Last N").clicked() {
+                    movecopy.mode_from_name = String::from("Copy Last N");
+                    movecopy.mode_from = MoveCopyFromMode::CopyLastN;
+                }
+                if ui.selectable_label(false, "Move First N").clicked() {
+                    movecopy.mode_from_name = String::from("Move First N");
+                    movecopy.mode_from = MoveCopyFromMode::MoveFirstN;
+                }
+                if ui.selectable_label(false, "Move Last N").clicked() {
+                    movecopy.mode_from_name = String::from("Move Last N");
+                    movecopy.mode_from = MoveCopyFromMode::MoveLastN;
+                }
+            });
+            ui.add_enabled_ui(movecopy.widgets_enabled, |ui| {
+                ui.separator();
+                ui.label("N");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut movecopy.letters_count)
+                    .range(0..=255)
+                    .speed(0.05)
+                );
+
+                if drag.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    movecopy.letters_count += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if movecopy.letters_count >= 1 {
+                        movecopy.letters_count -= 1;
                     }
-                    if ui.selectable_label(false, "Upper").clicked() {
-                        case.mode_name = String::from("Upper");
-                        case.mode = CaseMode::Upper;
+                };
+                if ui.small_button("➖").clicked() {
+                    if movecopy.letters_count >= 1 {
+                        movecopy.letters_count -= 1;
                     }
-                    if ui.selectable_label(false, "Lower").clicked() {
-                        case.mode_name = String::from("Lower");
-                        case.mode = CaseMode::Lower;
+                };
+        
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    movecopy.letters_count += 1;
+                };
+            })
+        });
+        ui.add_enabled_ui(movecopy.widgets_enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Unit");
+                egui::ComboBox::new(format!("movecopyunit-{}", index), "")
+                .selected_text(movecopy.unit_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Letters").clicked() {
+                        movecopy.unit_name = String::from("Letters");
+                        movecopy.unit = MoveCopyUnit::Letters;
                     }
-                    if ui.selectable_label(false, "Title").clicked() {
-                        case.mode_name = String::from("Title");
-                        case.mode = CaseMode::Title;
+                    if ui.selectable_label(false, "Words").clicked() {
+                        movecopy.unit_name = String::from("Words");
+                        movecopy.unit = MoveCopyUnit::Words;
                     }
-                    if ui.selectable_label(false, "UpperFirst").clicked() {
-                        case.mode_name = String::from("UpperFirst");
-                        case.mode = CaseMode::UpperFirst;
+                    if ui.selectable_label(false, "Regex").clicked() {
+                        movecopy.unit_name = String::from("Regex");
+                        movecopy.unit = MoveCopyUnit::Regex;
                     }
                 });
-
-                ui.separator();
-                ui.add_enabled_ui(case.except_enabled, |ui| {
-                    ui.label("Except");
-                    egui::ComboBox::new(format!("case-except-{}", index), "")
-                        .selected_text(case.except_mode_name.to_owned())
-                        .show_ui(ui, |ui| {
-                            if ui.selectable_label(false, "None").clicked() {
-                                case.except_mode_name = String::from("None");
-                                case.except_mode = CaseExecptMode::None;
-                            }
-                            if ui.selectable_label(false, "FromTo").clicked() {
-                                case.except_mode_name = String::from("FromTo");
-                                case.except_mode = CaseExecptMode::FromTo;
-                            }
-                            if ui.selectable_label(false, "Match").clicked() {
-                                case.except_mode_name = String::from("Match");
-                                case.except_mode = CaseExecptMode::Match;
-                            }
-                    });
-                });
-            });
-        ui.horizontal(|ui| {
-            ui.add_enabled_ui(case.widgets_enabled, |ui| {
-                match case.except_mode {
-                    CaseExecptMode::FromTo => {
-                        ui.label("From");
-                        let drag = ui.add_enabled(true, 
-                            egui::DragValue::new(&mut case.except_from)
-                            .range(0..=255)
-                            .speed(0.05)
-                            
-                        );
-
-                        if drag.hovered() {
-                            gui.modifiers.drag_box_hovered = true;
-                        };
-                
-                        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                            case.except_from += 1;
-                        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                            if case.except_from >= 1 {
-                                case.except_from -= 1;
-                            };
-                        };
-                        if ui.small_button("➖").clicked() {
-                            if case.except_from >= 1 {
-                                case.except_from -= 1;
-                            };
-                        };
-                
+                match movecopy.unit {
+                    MoveCopyUnit::Letters => {},
+                    MoveCopyUnit::Words => {
                         ui.separator();
-                
-                        if ui.small_button("➕").clicked() {
-                            case.except_from += 1;
-                        };
-
-                        ui.label("To");
-                        let drag = ui.add_enabled(true, 
-                            egui::DragValue::new(&mut case.except_to)
-                            .range(case.except_from..=255)
-                            .speed(0.05)
+                        ui.label("Delimiters");
+                        ui.add_sized(
+                            egui::vec2(ui.available_width(), ui.available_height()), 
+                            egui::text_edit::TextEdit::singleline(&mut movecopy.word_delimiters)
+                            .char_limit(255)
                         );
-
-                        if drag.hovered() {
-                            gui.modifiers.drag_box_hovered = true;
-                        };
-                
-                        if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                            case.except_to += 1;
-                        } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                            if case.except_to >= 1 {
-                                case.except_to -= 1;
-                            };
-                        };
-                        if ui.small_button("➖").clicked() {
-                            if case.except_to >= 1 {
-                                case.except_to -= 1;
-                            };
-                        };
-                
-                        ui.separator();
-                
-                        if ui.small_button("➕").clicked() {
-                            case.except_to += 1;
-                        };
                     },
-                    CaseExecptMode::Match => {
+                    MoveCopyUnit::Regex => {
+                        ui.separator();
                         ui.label("Match");
                         ui.add_sized(
                             egui::vec2(ui.available_width(), ui.available_height()), 
-                            egui::text_edit::TextEdit::singleline(&mut case.except)
+                            egui::text_edit::TextEdit::singleline(&mut movecopy.unit_regex)
+                            .char_limit(255)
                         );
-                    },
-                    _ => {}
-                }
+                    }
+                };
+            });
+        });
+        if movecopy.unit == MoveCopyUnit::Regex {
+            if let Err(error) = regex::Regex::new(&movecopy.unit_regex) {
+                ui.colored_label(egui::Color32::RED, error.to_string());
+            };
+        };
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(movecopy.widgets_enabled, |ui| {
+                ui.label("to");
+                egui::ComboBox::new(format!("movecopyto-{}", index), "")
+                .selected_text(movecopy.mode_to_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "None").clicked() {
+                        movecopy.mode_to_name = String::from("None");
+                        movecopy.mode_to = MoveCopyToMode::None;
+                    }
+                    if ui.selectable_label(false, "To Start").clicked() {
+                        movecopy.mode_to_name = String::from("To Start");
+                        movecopy.mode_to = MoveCopyToMode::ToStart;
+                    }
+                    if ui.selectable_label(false, "To End").clicked() {
+                        movecopy.mode_to_name = String::from("To End");
+                        movecopy.mode_to = MoveCopyToMode::ToEnd;
+                    }
+                    if ui.selectable_label(false, "To Pos").clicked() {
+                        movecopy.mode_to_name = String::from("To Pos");
+                        movecopy.mode_to = MoveCopyToMode::ToPos;
+                    }
+                    if ui.selectable_label(false, "Before Match").clicked() {
+                        movecopy.mode_to_name = String::from("Before Match");
+                        movecopy.mode_to = MoveCopyToMode::BeforeMatch;
+                    }
+                    if ui.selectable_label(false, "After Match").clicked() {
+                        movecopy.mode_to_name = String::from("After Match");
+                        movecopy.mode_to = MoveCopyToMode::AfterMatch;
+                    }
+                });
+            });
+            ui.add_enabled_ui(movecopy.widgets_enabled_two, |ui| {
+                ui.separator();
+                ui.label("pos");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut movecopy.mode_to_pos)
+                    .range(0..=255)
+                    .speed(0.05)
+                );
+
+                if drag.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    movecopy.mode_to_pos += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if movecopy.mode_to_pos >= 1 {
+                        movecopy.mode_to_pos -= 1;
+                    }
+                };
+                if ui.small_button("➖").clicked() {
+                    if movecopy.mode_to_pos >= 1 {
+                        movecopy.mode_to_pos -= 1;
+                    }
+                };
+        
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    movecopy.mode_to_pos += 1;
+                };
+            });
+        });
+        let to_match = movecopy.mode_to == MoveCopyToMode::BeforeMatch || movecopy.mode_to == MoveCopyToMode::AfterMatch;
+        if to_match {
+            ui.horizontal(|ui| {
+                ui.label("Match");
+                ui.add_sized(
+                    egui::vec2(ui.available_width() - 80.0, ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut movecopy.mode_to_match)
+                    .char_limit(255)
+                );
+                ui.label("Regex");
+                ui.checkbox(&mut movecopy.mode_to_regex, "");
+            });
+            if movecopy.mode_to_regex {
+                if let Err(error) = regex::Regex::new(&movecopy.mode_to_match) {
+                    ui.colored_label(egui::Color32::RED, error.to_string());
+                };
+            };
+        };
+        // The seperator also joins words and matches moved to the start or end.
+        let seperator_enabled = movecopy.widgets_enabled_two || to_match || (movecopy.unit != MoveCopyUnit::Letters && movecopy.mode_to != MoveCopyToMode::None);
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(seperator_enabled, |ui| {
+                ui.label("Seperator Char");
+                ui.checkbox(&mut movecopy.seperator_enabled, "");
+                ui.add_enabled_ui(movecopy.seperator_enabled, |ui| {
+                    let response = ui.add_sized(
+                        egui::vec2(20.0, ui.available_height()), 
+                        egui::text_edit::TextEdit::singleline(&mut seperator)
+                        .char_limit(2)
+                        .cursor_at_end(false)
+                    );
+                    if response.changed() {
+                        if seperator.len() >= 1 {
+                            let c = seperator.pop().unwrap();
+                            movecopy.seperator = c;
+                        }
+                    }
+                });
             });
         });
     });
-    match case.except_mode {
-        CaseExecptMode::None => {
-            case.widgets_enabled = false;
+    match movecopy.mode_from {
+        MoveCopyFromMode::None => {
+            movecopy.widgets_enabled = false;
+        },
+        _ => { movecopy.widgets_enabled = true }
+    };
+    match movecopy.mode_to {
+        MoveCopyToMode::None => {
+            movecopy.widgets_enabled_two = false;
+        },
+        MoveCopyToMode::ToPos => {
+            movecopy.widgets_enabled_two = true;
+        }
+        _ => { movecopy.widgets_enabled_two = false }
+    };
+    // Fill modifications
+    {
+        if movecopy.mode_to != MoveCopyToMode::None { modifications += 1 };
+        if movecopy.mode_from != MoveCopyFromMode::None { modifications += 1 };
+        if movecopy.letters_count >= 1 { modifications += 1 };
+        if movecopy.mode_to_pos >= 1 { modifications += 1 };
+        if movecopy.seperator_enabled == true { modifications += 1 };
+        if movecopy.unit != MoveCopyUnit::Letters { modifications += 1 };
+        if movecopy.unit == MoveCopyUnit::Regex && movecopy.unit_regex.chars().count() >= 1 { modifications += 1 };
+        if movecopy.mode_to_match.chars().count() >= 1 { modifications += 1 };
+        if movecopy.mode_to_regex == true { modifications += 1 };
+    }
+    return (movecopy.to_owned(), modifications);
+}
+
+fn fill_modname(_gui: &mut WindowMain, ui: &mut egui::Ui, name: &mut ModName, index: usize) -> (ModName, u32) {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {
+        ui.label("Mode");
+        egui::ComboBox::new(format!("name-{}", index), "")
+        .selected_text(name.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "Keep").clicked() {
+                name.mode_name = String::from("Keep");
+                name.mode = NameMode::Keep
+            }
+            if ui.selectable_label(false, "Remove").clicked() {
+                name.mode_name = String::from("Remove");
+                name.mode = NameMode::Remove
+            }
+            if ui.selectable_label(false, "Fixed").clicked() {
+                name.mode_name = String::from("Fixed");
+                name.mode = NameMode::Fixed
+            }
+            if ui.selectable_label(false, "Reverse").clicked() {
+                name.mode_name = String::from("Reverse");
+                name.mode = NameMode::Reverse
+            }
+        });
+        ui.add_enabled_ui(name.widgets_enabled, |ui| {
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut name.fixed
+            ));
+        });
+    });
+    match name.mode {
+        NameMode::Keep => {
+            name.widgets_enabled = false;
         },
-        _ => { case.widgets_enabled = true }
-    }
-    match case.mode {
-        CaseMode::Title | CaseMode::UpperFirst | CaseMode::Same => {
-            case.except_enabled = false;
+        NameMode::Fixed => {
+            name.widgets_enabled = true;
         },
-        _ => {
-            case.except_enabled = true;
-        }
+        _ => { name.widgets_enabled = false }
     };
     // Fill modifications
     {
-        if case.except_mode != CaseExecptMode::None { modifications += 1 };
-        if case.except.chars().count() >= 1 { modifications += 1 };
-        if case.except_from >= 1 { modifications += 1 };
-        if case.except_to >= 1 { modifications += 1 };
-        if case.mode != CaseMode::Same { modifications += 1 };
+        if name.fixed.chars().count() >= 1 { modifications += 1 };
+        if name.mode != NameMode::Keep { modifications += 1 };
     }
-    return (case.to_owned(), modifications);
+    return (name.to_owned(), modifications);
 }
 
-fn fill_moddate(gui: &mut WindowMain, ui: &mut egui::Ui, date: &mut ModDate, index: usize) -> (ModDate, u32) {
+fn fill_modnumber(gui: &mut WindowMain, ui: &mut egui::Ui, number: &mut ModNumber, index: usize) -> (ModNumber, u32) {
     let mut modifications: u32 = 0;
+    let mut seperator: String = number.seperator.to_string();
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
             ui.label("Mode");
-            egui::ComboBox::new(format!("date-{}", index), "")
-            .selected_text(date.mode_name.to_owned())
+            egui::ComboBox::new(format!("number-{}", index), "")
+            .selected_text(number.mode_name.to_owned())
             .show_ui(ui, |ui| {
                 if ui.selectable_label(false, "None").clicked() {
-                    date.mode_name = String::from("None");
-                    date.mode = DateMode::None;
+                    number.mode_name = String::from("None");
+                    number.mode = NumberMode::None
                 }
                 if ui.selectable_label(false, "Prefix").clicked() {
-                    date.mode_name = String::from("Prefix");
-                    date.mode = DateMode::Prefix;
+                    number.mode_name = String::from("Prefix");
+                    number.mode = NumberMode::Prefix
                 }
                 if ui.selectable_label(false, "Suffix").clicked() {
-                    date.mode_name = String::from("Suffix");
-                    date.mode = DateMode::Suffix;
+                    number.mode_name = String::from("Suffix");
+                    number.mode = NumberMode::Suffix
                 }
                 if ui.selectable_label(false, "Insert").clicked() {
-                    date.mode_name = String::from("Insert");
-                    date.mode = DateMode::Insert;
+                    number.mode_name = String::from("Insert");
+                    number.mode = NumberMode::Insert
+                }
+                if ui.selectable_label(false, "Prefix+Suffix").clicked() {
+                    number.mode_name = String::from("Prefix+Suffix");
+                    number.mode = NumberMode::PrefixAndSuffix
                 }
             });
-            ui.add_enabled_ui(date.at_enabled, |ui| {
+            ui.separator();
+            ui.add_enabled_ui(number.insert_enabled, |ui| {
                 ui.label("at");
                 let drag = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut date.at_pos)
+                    egui::DragValue::new(&mut number.insert_at)
                     .range(-255..=255)
                     .speed(0.05)
                 );
@@ -923,752 +2283,789 @@ fn fill_moddate(gui: &mut WindowMain, ui: &mut egui::Ui, date: &mut ModDate, ind
                 };
         
                 if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    date.at_pos += 1;
+                    number.insert_at += 1;
                 } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if date.at_pos >= 1 {
-                        date.at_pos -= 1;
+                    if number.insert_at >= 1 {
+                        number.insert_at -= 1;
                     }
                 };
                 if ui.small_button("➖").clicked() {
-                    date.at_pos -= 1;
+                    number.insert_at -= 1;
                 };
-        
                 ui.separator();
         
                 if ui.small_button("➕").clicked() {
-                    date.at_pos += 1;
+                    number.insert_at += 1;
                 };
             });
         });
+        if number.insert_enabled {
+            modifications += fill_insert_anchor(ui, &mut number.anchor, format!("number-{}", index));
+        };
         ui.horizontal(|ui| {
-            ui.label("Format");
-            ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                egui::ComboBox::new(format!("date_format-{}", index), "")
-                .selected_text(date.format_name.to_owned())
-                .show_ui(ui, |ui| {
-                    if ui.selectable_label(false, "Y").clicked() {
-                        date.format_name = String::from("Y");
-                        date.format = DateFormatMode::Y;
-                    }
-                    if ui.selectable_label(false, "MY").clicked() {
-                        date.format_name = String::from("MY");
-                        date.format = DateFormatMode::MY;
-                    }
-                    if ui.selectable_label(false, "DMY").clicked() {
-                        date.format_name = String::from("DMY");
-                        date.format = DateFormatMode::DMY;
-                    }
-                    if ui.selectable_label(false, "DMYH").clicked() {
-                        date.format_name = String::from("DMYH");
-                        date.format = DateFormatMode::DMYH;
-                    }
-                    if ui.selectable_label(false, "DMYHM").clicked() {
-                        date.format_name = String::from("DMYHM");
-                        date.format = DateFormatMode::DMYHM;
-                    }
-                    if ui.selectable_label(false, "DMYHMS").clicked() {
-                        date.format_name = String::from("DMYHMS");
-                        date.format = DateFormatMode::DMYHMS;
-                    }
-                    if ui.selectable_label(false, "YM").clicked() {
-                        date.format_name = String::from("YM");
-                        date.format = DateFormatMode::YM;
-                    }
-                    if ui.selectable_label(false, "YMD").clicked() {
-                        date.format_name = String::from("YMD");
-                        date.format = DateFormatMode::YMD;
-                    }
-                    if ui.selectable_label(false, "YMDH").clicked() {
-                        date.format_name = String::from("YMDH");
-                        date.format = DateFormatMode::YMDH;
+            ui.add_enabled_ui(number.widgets_enabled, |ui| {
+                ui.label("Starting");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut number.starting_num)
+                    .range(0..=25565)
+                    .speed(0.05)
+                );
+
+                if drag.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    number.starting_num += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if number.starting_num >= 1 {
+                        number.starting_num -= 1;
                     }
-                    if ui.selectable_label(false, "YMDHM").clicked() {
-                        date.format_name = String::from("YMDHM");
-                        date.format = DateFormatMode::YMDHM;
+                };
+                if ui.small_button("➖").clicked() {
+                    if number.starting_num >= 1 {
+                        number.starting_num -= 1;
                     }
-                    if ui.selectable_label(false, "YMDHMS").clicked() {
-                        date.format_name = String::from("YMDHMS");
-                        date.format = DateFormatMode::YMDHMS;
+                };
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    number.starting_num += 1;
+                };
+
+                ui.label("Increment");
+                let increment_before: i32 = number.increment_num;
+                let drag_increment = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut number.increment_num)
+                    .range(-255..=255)
+                    .speed(0.05)
+                );
+                if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    number.increment_num += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    number.increment_num -= 1;
+                };
+                if ui.small_button("➖").clicked() {
+                    number.increment_num -= 1;
+                };
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    number.increment_num += 1;
+                };
+                // A step of 0 would give every item the same number, step over it.
+                if number.increment_num == 0 {
+                    number.increment_num = match increment_before > 0 {
+                        true => -1,
+                        false => 1
+                    };
+                };
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(number.widgets_enabled, |ui| {
+                ui.label("Scope");
+                egui::ComboBox::new(format!("numberscope-{}", index), "")
+                .selected_text(number.scope_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Per Folder").clicked() {
+                        number.scope_name = String::from("Per Folder");
+                        number.scope = NumberScope::Folder
                     }
-                    if ui.selectable_label(false, "MDY").clicked() {
-                        date.format_name = String::from("MDY");
-                        date.format = DateFormatMode::MDY;
+                    if ui.selectable_label(false, "Global").clicked() {
+                        number.scope_name = String::from("Global");
+                        number.scope = NumberScope::Global
                     }
-                    if ui.selectable_label(false, "MDYH").clicked() {
-                        date.format_name = String::from("MDYH");
-                        date.format = DateFormatMode::MDYH;
+                    if ui.selectable_label(false, "Per Extension").clicked() {
+                        number.scope_name = String::from("Per Extension");
+                        number.scope = NumberScope::Extension
                     }
-                    if ui.selectable_label(false, "MDYHM").clicked() {
-                        date.format_name = String::from("MDYHM");
-                        date.format = DateFormatMode::MDYHM;
+                    if ui.selectable_label(false, "Per Group").clicked() {
+                        number.scope_name = String::from("Per Group");
+                        number.scope = NumberScope::Group
                     }
-                    if ui.selectable_label(false, "MDYHMS").clicked() {
-                        date.format_name = String::from("MDYHMS");
-                        date.format = DateFormatMode::MDYHMS;
+                });
+                ui.label("Reverse");
+                ui.checkbox(&mut number.reverse, "");
+                ui.label("Auto Padding");
+                ui.checkbox(&mut number.auto_padding, "");
+            });
+        });
+        if number.widgets_enabled && number.scope == NumberScope::Group {
+            ui.horizontal(|ui| {
+                ui.label("Group Regex");
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut number.group_regex)
+                    .char_limit(255)
+                );
+            });
+            if let Err(error) = regex::Regex::new(&number.group_regex) {
+                ui.colored_label(egui::Color32::RED, error.to_string());
+            };
+        };
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(number.widgets_enabled, |ui| {
+                ui.label("Padding");
+                let drag_increment = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut number.padding)
+                    .range(0..=255)
+                    .speed(0.05)
+                );
+
+                if drag_increment.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    number.padding += 1;
+                } else if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if number.padding >= 1 {
+                        number.padding -= 1;
                     }
-                    if ui.selectable_label(false, "Custom").clicked() {
-                        date.format_name = String::from("Custom");
-                        date.format = DateFormatMode::Custom;
+                };
+                if ui.small_button("➖").clicked() {
+                    if number.padding >= 1 {
+                        number.padding -= 1;
                     }
+                };
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    number.padding += 1;
+                };
+
+                ui.label("Seperator Char");
+                ui.checkbox(&mut number.seperator_enabled, "");
+                ui.add_enabled_ui(number.seperator_enabled, |ui| {
+                    let response = ui.add_sized(
+                        egui::vec2(20.0, ui.available_height()), 
+                        egui::text_edit::TextEdit::singleline(&mut seperator)
+                        .char_limit(2)
+                        .cursor_at_end(false)
+                    );
+                    if response.changed() {
+                        if seperator.len() >= 1 {
+                            let c = seperator.pop().unwrap();
+                            number.seperator = c;
+                        };
+                    };
                 });
             });
-            ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                ui.label("Seperator");
-                egui::ComboBox::new(format!("date_seperator-{}", index), "")
-                .selected_text(date.seperator_name.to_owned())
+        });
+        ui.horizontal(|ui| {
+            ui.add_enabled_ui(number.widgets_enabled, |ui| {
+                ui.label("Type Mode");
+                egui::ComboBox::new(format!("numbertype-{}", index), "")
+                .selected_text(number.mode_type_name.to_owned())
                 .show_ui(ui, |ui| {
-                    if ui.selectable_label(false, "None").clicked() {
-                        date.seperator = DateSeperator::None;
-                        date.seperator_name = String::from("None");
-                    }
-                    if ui.selectable_label(false, "Space  ").clicked() {
-                        date.seperator = DateSeperator::Space;
-                        date.seperator_name = String::from("Space  ");
-                    }
-                    if ui.selectable_label(false, "Asterisk **").clicked() {
-                        date.seperator = DateSeperator::Asterisk;
-                        date.seperator_name = String::from("Asterisk **");
-                    }
-                    if ui.selectable_label(false, "Bracket []").clicked() {
-                        date.seperator = DateSeperator::Bracket;
-                        date.seperator_name = String::from("Bracket []");
+                    if ui.selectable_label(false, "Base 2").clicked() {
+                        number.mode_type_name = String::from("Base 2");
+                        number.mode_type = NumberTypeMode::BaseTwo
                     }
-                    ui.add_enabled_ui(!cfg!(windows), |ui| {
-                        if ui.selectable_label(false, "Colan ::").clicked() {
-                            date.seperator = DateSeperator::Colan;
-                            date.seperator_name = String::from("Colan :");
-                        }
-                    });
-                    if ui.selectable_label(false, "CurlyBracket {}").clicked() {
-                        date.seperator = DateSeperator::CurlyBracket;
-                        date.seperator_name = String::from("CurlyBracket {}");
+                    if ui.selectable_label(false, "Base 8").clicked() {
+                        number.mode_type_name = String::from("Base 8");
+                        number.mode_type = NumberTypeMode::BaseEight
                     }
-                    ui.add_enabled_ui(!cfg!(windows), |ui| {
-                        if ui.selectable_label(false, "Line ||").clicked() {
-                            date.seperator = DateSeperator::Line;
-                            date.seperator_name = String::from("Line ||");
-                        }
-                    });
-                    if ui.selectable_label(false, "Minus --").clicked() {
-                        date.seperator = DateSeperator::Minus;
-                        date.seperator_name = String::from("Minus --");
+                    if ui.selectable_label(false, "Base 10").clicked() {
+                        number.mode_type_name = String::from("Base Ten");
+                        number.mode_type = NumberTypeMode::BaseTen
                     }
-                    if ui.selectable_label(false, "Parenthesis ()").clicked() {
-                        date.seperator = DateSeperator::Parenthesis;
-                        date.seperator_name = String::from("Parenthesis ()");
+                    if ui.selectable_label(false, "Base 16").clicked() {
+                        number.mode_type_name = String::from("Base 16");
+                        number.mode_type = NumberTypeMode::BaseSixteen
                     }
-                    if ui.selectable_label(false, "Plus ++").clicked() {
-                        date.seperator = DateSeperator::Plus;
-                        date.seperator_name = String::from("Plus ++");
+                    if ui.selectable_label(false, "Base 16 (A-F)").clicked() {
+                        number.mode_type_name = String::from("Base 16 (A-F)");
+                        number.mode_type = NumberTypeMode::BaseSixteenUpper
                     }
-                    if ui.selectable_label(false, "Sign <>").clicked() {
-                        date.seperator = DateSeperator::Sign;
-                        date.seperator_name = String::from("Sign <>");
+                    if ui.selectable_label(false, "Base 36").clicked() {
+                        number.mode_type_name = String::from("Base 36");
+                        number.mode_type = NumberTypeMode::BaseThirtySix
                     }
-                    if ui.selectable_label(false, "Underscore __").clicked() {
-                        date.seperator = DateSeperator::Underscore;
-                        date.seperator_name = String::from("Underscore __");
+                    if ui.selectable_label(false, "Base 62").clicked() {
+                        number.mode_type_name = String::from("Base 62");
+                        number.mode_type = NumberTypeMode::BaseSixtyTwo
                     }
-                });
-            });
-        });
-        ui.horizontal(|ui| {
-            ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                ui.label("Seg. Date");
-                egui::ComboBox::new(format!("date_seg_year-{}", index), "")
-                .selected_text(date.segregator_year_name.to_owned())
-                .show_ui(ui, |ui| {
-                    ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                        if ui.selectable_label(false, "None").clicked() {
-                            date.segregator_year = DateSeperator::None;
-                            date.segregator_year_name = String::from("None");
-                        }
-                        if ui.selectable_label(false, "Space ").clicked() {
-                            date.segregator_year = DateSeperator::Space;
-                            date.segregator_year_name = String::from("Space ");
-                        }
-                        ui.add_enabled_ui(!cfg!(windows), |ui| {
-                            if ui.selectable_label(false, "Colan :").clicked() {
-                                date.segregator_year = DateSeperator::Colan;
-                                date.segregator_year_name = String::from("Colan :");
-                            }
-                        });
-                        ui.add_enabled_ui(!cfg!(windows), |ui| {
-                            if ui.selectable_label(false, "Line |").clicked() {
-                                date.segregator_year = DateSeperator::Line;
-                                date.segregator_year_name = String::from("Line |");
-                            }
-                        });
-                        if ui.selectable_label(false, "Minus -").clicked() {
-                            date.segregator_year = DateSeperator::Minus;
-                            date.segregator_year_name = String::from("Minus -");
-                        }
-                        if ui.selectable_label(false, "Plus +").clicked() {
-                            date.segregator_year = DateSeperator::Plus;
-                            date.segregator_year_name = String::from("Plus +");
-                        }
-                        if ui.selectable_label(false, "Underscore _").clicked() {
-                            date.segregator_hour = DateSeperator::Underscore;
-                            date.segregator_year_name = String::from("Underscore _");
-                        }
-                    });
-                });
-                ui.label("Seg. Time");
-                egui::ComboBox::new(format!("date_seg_hour-{}", index), "")
-                .selected_text(date.segregator_hour_name.to_owned())
-                .show_ui(ui, |ui| {
-                    ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                        if ui.selectable_label(false, "None").clicked() {
-                            date.segregator_hour = DateSeperator::None;
-                            date.segregator_hour_name = String::from("None");
-                        }
-                        if ui.selectable_label(false, "Space ").clicked() {
-                            date.segregator_hour = DateSeperator::Space;
-                            date.segregator_hour_name = String::from("Space ");
-                        }
-                        ui.add_enabled_ui(!cfg!(windows), |ui| {
-                            if ui.selectable_label(false, "Colan :").clicked() {
-                                date.segregator_hour = DateSeperator::Colan;
-                                date.segregator_hour_name = String::from("Colan :");
-                            }
-                        });
-                        ui.add_enabled_ui(!cfg!(windows), |ui| {
-                            if ui.selectable_label(false, "Line |").clicked() {
-                                date.segregator_hour = DateSeperator::Line;
-                                date.segregator_hour_name = String::from("Line |");
-                            }
-                        });
-                        if ui.selectable_label(false, "Minus -").clicked() {
-                            date.segregator_hour = DateSeperator::Minus;
-                            date.segregator_hour_name = String::from("Minus -");
-                        }
-                        if ui.selectable_label(false, "Plus +").clicked() {
-                            date.segregator_hour = DateSeperator::Plus;
-                            date.segregator_hour_name = String::from("Plus +");
-                        }
-                        if ui.selectable_label(false, "Underscore _").clicked() {
-                            date.segregator_hour = DateSeperator::Underscore;
-                            date.segregator_hour_name = String::from("Underscore _");
-                        }
-                    });
-                });
-            });
-        });
-        ui.horizontal(|ui| {
-            ui.add_enabled_ui(date.widgets_enabled, |ui| {
-                ui.label("Century").on_hover_text("1999 -> 99");
-                ui.checkbox(&mut date.century, "");
-            });
-            ui.add_enabled_ui(date.custom_enabled, |ui| {
-                ui.label("Custom");
-                ui.add_sized(
-                    egui::vec2(ui.available_width(), ui.available_height()), 
-                    egui::text_edit::TextEdit::singleline(&mut date.custom
-                )).on_hover_text(format!(
-                    "Create a custom format using the following"
-                ))
-            });
-        });
-    });
-    match date.mode {
-        DateMode::None => {
-            date.widgets_enabled = false;
-            date.at_enabled = false;
-        },
-        DateMode::Insert => {
-            date.widgets_enabled = true;
-            date.at_enabled = true;
-        }
-        _ => { 
-            date.widgets_enabled = true;
-            date.at_enabled = false;
-        }
-    };
-    match date.format {
-        DateFormatMode::Custom => {
-            date.custom_enabled = true;
-        },
-        _ => {
-            date.custom_enabled = false;
-        }
-    };
-    // Fill modifications
-    {
-        if date.segregator_hour_enabled == true { modifications += 1 };
-        if date.segregator_year_enabled == true { modifications += 1 };
-        if date.at_pos != 0 { modifications += 1 };
-        if date.custom.chars().count() >= 1 { modifications += 1 };
-        if date.mode != DateMode::None { modifications += 1 };
-    }
-    return (date.to_owned(), modifications);
-}
-
-fn fill_modextension(_gui: &mut WindowMain, ui: &mut egui::Ui, ext: &mut ModExtension) -> u32 {
-    let mut modifications: u32 = 0;
-    ui.horizontal(|ui| {ui.label("Mode");
-        egui::ComboBox::new(format!("extension"), "")
-        .selected_text(ext.mode_name.to_owned())
-        .show_ui(ui, |ui| {
-            if ui.selectable_label(false, "Same").clicked() {
-                ext.mode_name = String::from("Same");
-                ext.mode = ExtensionMode::Same;
-            }
-            if ui.selectable_label(false, "Upper").clicked() {
-                ext.mode_name = String::from("Upper");
-                ext.mode = ExtensionMode::Upper;
-            }
-            if ui.selectable_label(false, "Lower").clicked() {
-                ext.mode_name = String::from("Lower");
-                ext.mode = ExtensionMode::Lower;
-            }
-            if ui.selectable_label(false, "UpperFirst").clicked() {
-                ext.mode_name = String::from("UpperFirst");
-                ext.mode = ExtensionMode::UpperFirst;
-            }
-            if ui.selectable_label(false, "Fixed").clicked() {
-                ext.mode_name = String::from("Fixed");
-                ext.mode = ExtensionMode::Fixed;
-            }
-            if ui.selectable_label(false, "Extra").clicked() {
-                ext.mode_name = String::from("Extra");
-                ext.mode = ExtensionMode::Extra;
-            }
-            if ui.selectable_label(false, "Remove").clicked() {
-                ext.mode_name = String::from("Remove");
-                ext.mode = ExtensionMode::Remove;
-            }
-        });
-    });
-    ui.horizontal(|ui| {
-        ui.add_enabled_ui(ext.widgets_enabled, |ui| {
-            ui.label("Fixed");
-            ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut ext.fixed)
-            );
-        });
-    });
-    ui.horizontal(|ui| {
-        ui.add_enabled_ui(ext.widgets_enabled, |ui| {
-            ui.label("Extra");
-            ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut ext.extra)
-            );
+                    if ui.selectable_label(false, "Roman Numeral").clicked() {
+                        number.mode_type_name = String::from("Roman Numeral");
+                        number.mode_type = NumberTypeMode::RomanNumeral
+                    }
+                    if ui.selectable_label(false, "a - z").clicked() {
+                        number.mode_type_name = String::from("a - z");
+                        number.mode_type = NumberTypeMode::AlphaLower
+                    }
+                    if ui.selectable_label(false, "A - Z").clicked() {
+                        number.mode_type_name = String::from("A - Z");
+                        number.mode_type = NumberTypeMode::AlphaUpper
+                    }
+                    if ui.selectable_label(false, "a - Z").clicked() {
+                        number.mode_type_name = String::from("a - Z");
+                        number.mode_type = NumberTypeMode::AlphaLowerToUpper
+                    }
+                    if ui.selectable_label(false, "Custom Alphabet").clicked() {
+                        number.mode_type_name = String::from("Custom Alphabet");
+                        number.mode_type = NumberTypeMode::Custom
+                    }
+                    if ui.selectable_label(false, "Words").clicked() {
+                        number.mode_type_name = String::from("Words");
+                        number.mode_type = NumberTypeMode::Words
+                    }
+                    if ui.selectable_label(false, "Ordinal").clicked() {
+                        number.mode_type_name = String::from("Ordinal");
+                        number.mode_type = NumberTypeMode::Ordinal
+                    }
+                });
+                if number.mode_type == NumberTypeMode::Custom {
+                    ui.add_sized(
+                        egui::vec2(ui.available_width(), ui.available_height()), 
+                        egui::text_edit::TextEdit::singleline(&mut number.alphabet)
+                        .hint_text("abcdef")
+                    ).on_hover_text("Letters to count with, in order.");
+                };
+            });
         });
     });
-    match ext.mode {
-        ExtensionMode::Same => {
-            ext.widgets_enabled = false;
+    match number.mode {
+        NumberMode::None => {
+            number.widgets_enabled = false;
+            number.insert_enabled = false;
         },
-        _ => { ext.widgets_enabled = true }
+        NumberMode::Insert => {
+            number.widgets_enabled = true;
+            number.insert_enabled = true;
+        },
+        _ => { 
+            number.widgets_enabled = true; 
+            number.insert_enabled = false; 
+        }
     };
     // Fill modifications
     {
-        if ext.extra.chars().count() >= 1 { modifications += 1 };
-        if ext.fixed.chars().count() >= 1 { modifications += 1 };
-        if ext.mode != ExtensionMode::Same { modifications += 1 };
+        if number.increment_num != 1 { modifications += 1 };
+        if number.insert_at != 0 { modifications += 1 };
+        if number.mode != NumberMode::None { modifications += 1 };
+        if number.starting_num != 1 { modifications += 1 };
+        if number.scope != NumberScope::Folder { modifications += 1 };
+        if number.group_regex.chars().count() >= 1 { modifications += 1 };
+        if number.reverse == true { modifications += 1 };
+        if number.auto_padding == true { modifications += 1 };
+        if number.alphabet.chars().count() >= 1 { modifications += 1 };
     }
-    return modifications;
+    return (number.to_owned(), modifications);
 }
 
-fn fill_modhash(_gui: &mut WindowMain, ui: &mut egui::Ui, hash: &mut ModHashing) -> u32 {
+fn fill_modregex(gui: &mut WindowMain, ui: &mut egui::Ui, regex: &mut ModRegex, index: usize) -> (ModRegex, u32) {
     let mut modifications: u32 = 0;
-    ui.horizontal(|ui| {
-        ui.label("Mode");
-        egui::ComboBox::new(format!("hashmode"), "")
-        .selected_text(hash.mode_name.to_owned())
-        .show_ui(ui, |ui| {
-            if ui.selectable_label(false, "None").clicked() {
-                hash.mode_name = String::from("None");
-                hash.mode = threads::HashMode::None;
-            }
-            if ui.selectable_label(false, "Prefix").clicked() {
-                hash.mode_name = String::from("Prefix");
-                hash.mode = threads::HashMode::Prefix;
-            }
-            if ui.selectable_label(false, "Suffix").clicked() {
-                hash.mode_name = String::from("Suffix");
-                hash.mode = threads::HashMode::Suffix;
-            }
-            if ui.selectable_label(false, "File").clicked() {
-                hash.mode_name = String::from("File");
-                hash.mode = threads::HashMode::File;
-            }
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("Exp");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()),
+                egui::text_edit::TextEdit::singleline(&mut regex.replace_match))
         });
-        ui.label("Algorithm");
-        ui.add_enabled_ui(hash.widgets_enabled, |ui| {
-            egui::ComboBox::new(format!("hashalgorithm"), "")
-            .selected_text(hash.algorithm_name.to_owned())
-            .show_ui(ui, |ui| {
-                if ui.selectable_label(false, "CRC32").clicked() {
-                    hash.algorithm_name = String::from("CRC32");
-                    hash.algorithm = threads::HashType::CRC32;
-                }
-                if ui.selectable_label(false, "MD5").clicked() {
-                    hash.algorithm_name = String::from("MD5");
-                    hash.algorithm = threads::HashType::MD5;
-                }
-                if ui.selectable_label(false, "Sha1").clicked() {
-                    hash.algorithm_name = String::from("Sha1");
-                    hash.algorithm = threads::HashType::Sha1;
-                }
-                if ui.selectable_label(false, "Sha256").clicked() {
-                    hash.algorithm_name = String::from("Sha256");
-                    hash.algorithm = threads::HashType::Sha256;
-                }
+        ui.horizontal(|ui| {
+            ui.label("Replace");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()),
+                egui::text_edit::TextEdit::singleline(&mut regex.replace_with))
+                .on_hover_text(format!("{}{}{}{}{}{}{}{}{}",
+                    "$1 / ${1}     : Capture group 1\n",
+                    "$name         : Named group, (?P<name>...)\n",
+                    "${1:03}       : Group 1 zero-padded to 3 digits\n",
+                    "\\U           : Uppercase what follows\n",
+                    "\\L           : Lowercase what follows\n",
+                    "\\T           : Title-case what follows\n",
+                    "\\E           : End \\U, \\L or \\T\n",
+                    "$$            : Literal $\n",
+                    "\\\\           : Literal \\"
+                ));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Scope");
+            egui::ComboBox::new(format!("regex-scope-{}", index), "")
+                .selected_text(regex.scope_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Name").clicked() {
+                        regex.scope_name = String::from("Name");
+                        regex.scope = RegexScope::Name;
+                    }
+                    if ui.selectable_label(false, "Extension").clicked() {
+                        regex.scope_name = String::from("Extension");
+                        regex.scope = RegexScope::Extension;
+                    }
+                    if ui.selectable_label(false, "Full").clicked() {
+                        regex.scope_name = String::from("Full");
+                        regex.scope = RegexScope::Full;
+                    }
+                });
+
+            ui.separator();
+            ui.label("Replace");
+            egui::ComboBox::new(format!("regex-occurrence-{}", index), "")
+                .selected_text(regex.occurrence_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "All").clicked() {
+                        regex.occurrence_name = String::from("All");
+                        regex.occurrence = RegexOccurrence::All;
+                    }
+                    if ui.selectable_label(false, "Nth").clicked() {
+                        regex.occurrence_name = String::from("Nth");
+                        regex.occurrence = RegexOccurrence::Nth;
+                    }
+                });
+            ui.add_enabled_ui(regex.occurrence == RegexOccurrence::Nth, |ui| {
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut regex.nth)
+                    .range(1..=255)
+                    .speed(0.05)
+                );
+
+                if drag.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    regex.nth += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if regex.nth >= 2 {
+                        regex.nth -= 1;
+                    };
+                };
+                if ui.small_button("➖").clicked() {
+                    if regex.nth >= 2 {
+                        regex.nth -= 1;
+                    };
+                };
+        
+                ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    regex.nth += 1;
+                };
             });
         });
-    });
-    ui.horizontal(|ui| {
-        ui.label("Seperator");
-        egui::ComboBox::new(format!("hashaseperator"), "")
-        .selected_text(hash.seperator_name.to_owned())
-        .show_ui(ui, |ui| {
-            if ui.selectable_label(false, "None").clicked() {
-                hash.seperator_name = String::from("None");
-                hash.seperator = HashSeperator::None;
-            }
-            if ui.selectable_label(false, "Asterisk **").clicked() {
-                hash.seperator_name = String::from("Asterisk **");
-                hash.seperator = HashSeperator::Asterisk;
-            }
-            if ui.selectable_label(false, "Bracket []").clicked() {
-                hash.seperator_name = String::from("Bracket []");
-                hash.seperator = HashSeperator::Bracket;
-            }
-            if ui.selectable_label(false, "Colan ::").clicked() {
-                hash.seperator_name = String::from("Colan ::");
-                hash.seperator = HashSeperator::Colan;
-            }
-            if ui.selectable_label(false, "CurlyBracket {}").clicked() {
-                hash.seperator_name = String::from("CurlyBracket {}");
-                hash.seperator = HashSeperator::CurlyBracket;
-            }
-            if ui.selectable_label(false, "Line ||").clicked() {
-                hash.seperator_name = String::from("Line ||");
-                hash.seperator = HashSeperator::Line;
-            }
-            if ui.selectable_label(false, "Minus --").clicked() {
-                hash.seperator_name = String::from("Minus --");
-                hash.seperator = HashSeperator::Minus;
-            }
-            if ui.selectable_label(false, "Parenthesis ()").clicked() {
-                hash.seperator_name = String::from("Parenthesis ()");
-                hash.seperator = HashSeperator::Parenthesis;
-            }
-            if ui.selectable_label(false, "Plus ++").clicked() {
-                hash.seperator_name = String::from("Plus ++");
-                hash.seperator = HashSeperator::Plus;
-            }
-            if ui.selectable_label(false, "Sign <>").clicked() {
-                hash.seperator_name = String::from("Sign <>");
-                hash.seperator = HashSeperator::Sign;
-            }
-            if ui.selectable_label(false, "Space  ").clicked() {
-                hash.seperator_name = String::from("Space  ");
-                hash.seperator = HashSeperator::Space;
-            }
-            if ui.selectable_label(false, "Underscore __").clicked() {
-                hash.seperator_name = String::from("Underscore __");
-                hash.seperator = HashSeperator::Underscore;
-            }
+        ui.horizontal(|ui| {
+            ui.label("Ignore Case");
+            ui.checkbox(&mut regex.case_insensitive, "");
+
+            ui.label("Multi-line");
+            ui.checkbox(&mut regex.multi_line, "");
+
+            ui.label("Unicode");
+            ui.checkbox(&mut regex.unicode, "");
         });
+        let preview = tester_preview(gui, ui.ctx(), format!("regex-tester-{}", index), format!("{:?}", regex), |names| file_processing::preview_regex(regex, names));
+        if let Err(error) = &preview {
+            ui.colored_label(egui::Color32::RED, error);
+        };
+        fill_tester(ui, format!("regex-tester-{}", index), preview, gui.tester_cache.names.len());
     });
-    match hash.mode {
-        threads::HashMode::None => {
-            hash.widgets_enabled = false;
-        },
-        _ => { hash.widgets_enabled = true }
-    };
-    // Fill modification
+    // Fill modifications
     {
-        if hash.mode != threads::HashMode::None { modifications += 1 };
+        if regex.replace_match.chars().count() >= 1 { modifications += 1 };
+        if regex.replace_with.chars().count() >= 1 { modifications += 1 };
+        if regex.scope != RegexScope::Name { modifications += 1 };
+        if regex.occurrence != RegexOccurrence::Nth || regex.nth != 1 { modifications += 1 };
+        if regex.case_insensitive == true { modifications += 1 };
+        if regex.multi_line == true { modifications += 1 };
+        if regex.unicode == false { modifications += 1 };
     }
-    return modifications;
+    return (regex.to_owned(), modifications);
 }
 
-fn fill_modmovecopy(gui: &mut WindowMain, ui: &mut egui::Ui, movecopy: &mut ModMoveCopy, index: usize) -> (ModMoveCopy, u32) {
+fn fill_modremove(gui: &mut WindowMain, ui: &mut egui::Ui, remove: &mut ModRemove, index: usize) -> (ModRemove, u32) {
     let mut modifications: u32 = 0;
-    let mut seperator: String = movecopy.seperator.to_string();
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Mode");
-            egui::ComboBox::new(format!("movecopyfrom-{}", index), "")
-            .selected_text(movecopy.mode_from_name.to_owned())
-            .show_ui(ui, |ui| {
-                if ui.selectable_label(false, "None").clicked() {
-                    movecopy.mode_from_name = String::from("None");
-                    movecopy.mode_from = MoveCopyFromMode::None;
-                }
-                if ui.selectable_label(false, "Copy First N").clicked() {
-                    movecopy.mode_from_name = String::from("Copy First N");
-                    movecopy.mode_from = MoveCopyFromMode::CopyFirstN;
-                }
-                if ui.selectable_label(false, "Copy Last N").clicked() {
-                    movecopy.mode_from_name = String::from("Copy Last N");
-                    movecopy.mode_from = MoveCopyFromMode::CopyLastN;
-                }
-                if ui.selectable_label(false, "Move First N").clicked() {
-                    movecopy.mode_from_name = String::from("Move First N");
-                    movecopy.mode_from = MoveCopyFromMode::MoveFirstN;
-                }
-                if ui.selectable_label(false, "Move Last N").clicked() {
-                    movecopy.mode_from_name = String::from("Move Last N");
-                    movecopy.mode_from = MoveCopyFromMode::MoveLastN;
-                }
+            ui.group(|ui| {
+                ui.label("First");
+                let drag_first = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut remove.first_n)
+                    .range(0..=255)
+                    .speed(0.05)
+                );
+
+                if drag_first.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag_first.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    remove.first_n += 1;
+                } else if drag_first.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if remove.first_n >= 1 {
+                        remove.first_n -= 1;
+                    }
+                };
+                if ui.small_button("➖").clicked() {
+                    if remove.first_n >= 1 {
+                        remove.first_n -= 1;
+                    }
+                };
+                ui.separator();
+
+                if ui.small_button("➕").clicked() {
+                    remove.first_n += 1;
+                };
             });
-            ui.add_enabled_ui(movecopy.widgets_enabled, |ui| {
-                ui.separator();
-                ui.label("N");
-                let drag = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut movecopy.letters_count)
+            
+            ui.group(|ui| {
+                ui.label("Last");
+                let drag_last = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut remove.last_n)
                     .range(0..=255)
                     .speed(0.05)
                 );
 
-                if drag.hovered() {
+                if drag_last.hovered() {
                     gui.modifiers.drag_box_hovered = true;
                 };
         
-                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    movecopy.letters_count += 1;
-                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if movecopy.letters_count >= 1 {
-                        movecopy.letters_count -= 1;
+                if drag_last.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    remove.last_n += 1;
+                } else if drag_last.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if remove.last_n >= 1 {
+                        remove.last_n -= 1;
                     }
                 };
                 if ui.small_button("➖").clicked() {
-                    if movecopy.letters_count >= 1 {
-                        movecopy.letters_count -= 1;
+                    if remove.last_n >= 1 {
+                        remove.last_n -= 1;
                     }
                 };
-        
                 ui.separator();
-        
+
                 if ui.small_button("➕").clicked() {
-                    movecopy.letters_count += 1;
+                    remove.last_n += 1;
                 };
-            })
+            });
         });
         ui.horizontal(|ui| {
-            ui.add_enabled_ui(movecopy.widgets_enabled, |ui| {
-                ui.label("to");
-                egui::ComboBox::new(format!("movecopyto-{}", index), "")
-                .selected_text(movecopy.mode_to_name.to_owned())
-                .show_ui(ui, |ui| {
-                    if ui.selectable_label(false, "None").clicked() {
-                        movecopy.mode_to_name = String::from("None");
-                        movecopy.mode_to = MoveCopyToMode::None;
-                    }
-                    if ui.selectable_label(false, "To Start").clicked() {
-                        movecopy.mode_to_name = String::from("To Start");
-                        movecopy.mode_to = MoveCopyToMode::ToStart;
-                    }
-                    if ui.selectable_label(false, "To End").clicked() {
-                        movecopy.mode_to_name = String::from("To End");
-                        movecopy.mode_to = MoveCopyToMode::ToEnd;
+            ui.group(|ui| {
+                ui.label("From");
+                let drag_from = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut remove.from_x)
+                    .range(0..=25565)
+                    .speed(0.05)
+                );
+
+                if drag_from.hovered() {
+                    gui.modifiers.drag_box_hovered = true;
+                };
+        
+                if drag_from.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    remove.from_x += 1;
+                } else if drag_from.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if remove.from_x >= 1 {
+                        remove.from_x -= 1;
                     }
-                    if ui.selectable_label(false, "To Pos").clicked() {
-                        movecopy.mode_to_name = String::from("To Pos");
-                        movecopy.mode_to = MoveCopyToMode::ToPos;
+                };
+                if ui.small_button("➖").clicked() {
+                    if remove.from_x >= 1 {
+                        remove.from_x -= 1;
                     }
-                });
-            });
-            ui.add_enabled_ui(movecopy.widgets_enabled_two, |ui| {
+                };
                 ui.separator();
-                ui.label("pos");
-                let drag = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut movecopy.mode_to_pos)
-                    .range(0..=255)
+
+                if ui.small_button("➕").clicked() {
+                    remove.from_x += 1;
+                };
+            });
+
+            ui.group(|ui| {
+                ui.label("To");
+                let drag_to = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut remove.to_y)
+                    .range(remove.from_x..=25565)
                     .speed(0.05)
                 );
 
-                if drag.hovered() {
+                if drag_to.hovered() {
                     gui.modifiers.drag_box_hovered = true;
                 };
         
-                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    movecopy.mode_to_pos += 1;
-                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if movecopy.mode_to_pos >= 1 {
-                        movecopy.mode_to_pos -= 1;
+                if drag_to.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    remove.to_y += 1;
+                } else if drag_to.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if remove.to_y >= 1 {
+                        remove.to_y -= 1;
                     }
                 };
                 if ui.small_button("➖").clicked() {
-                    if movecopy.mode_to_pos >= 1 {
-                        movecopy.mode_to_pos -= 1;
+                    if remove.to_y >= 1 {
+                        remove.to_y -= 1;
                     }
                 };
-        
                 ui.separator();
-        
+
                 if ui.small_button("➕").clicked() {
-                    movecopy.mode_to_pos += 1;
+                    remove.to_y += 1;
                 };
-            });
+            })
         });
         ui.horizontal(|ui| {
-            ui.add_enabled_ui(movecopy.widgets_enabled_two, |ui| {
-                ui.label("Seperator Char");
-                ui.checkbox(&mut movecopy.seperator_enabled, "");
-                ui.add_enabled_ui(movecopy.seperator_enabled, |ui| {
-                    let response = ui.add_sized(
-                        egui::vec2(20.0, ui.available_height()), 
-                        egui::text_edit::TextEdit::singleline(&mut seperator)
-                        .char_limit(2)
-                        .cursor_at_end(false)
-                    );
-                    if response.changed() {
-                        if seperator.len() >= 1 {
-                            let c = seperator.pop().unwrap();
-                            movecopy.seperator = c;
-                        }
+            ui.label("Chars");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut remove.chars_comma_seperated)
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Words");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut remove.words_comma_seperated)
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Crop");
+            egui::ComboBox::new(format!("remove_crop-{}", index), "")
+            .selected_text(remove.crop_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "None").clicked() {
+                    remove.crop_name = String::from("None");
+                    remove.crop = RemoveCropMode::None
+                }
+                if ui.selectable_label(false, "Before").clicked() {
+                    remove.crop_name = String::from("Before");
+                    remove.crop = RemoveCropMode::Before
+                }
+                if ui.selectable_label(false, "After").clicked() {
+                    remove.crop_name = String::from("After");
+                    remove.crop = RemoveCropMode::After
+                }
+                if ui.selectable_label(false, "Keep Between").clicked() {
+                    remove.crop_name = String::from("Keep Between");
+                    remove.crop = RemoveCropMode::KeepBetween
+                }
+                if ui.selectable_label(false, "Remove Between").clicked() {
+                    remove.crop_name = String::from("Remove Between");
+                    remove.crop = RemoveCropMode::RemoveBetween
+                }
+            });
+            ui.add_enabled_ui(remove.crop_enabled, |ui| {
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut remove.crop_match)
+                    .char_limit(255)
+                );
+            });
+        });
+        let crop_between = remove.crop == RemoveCropMode::KeepBetween || remove.crop == RemoveCropMode::RemoveBetween;
+        if crop_between {
+            ui.horizontal(|ui| {
+                ui.label("And");
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut remove.crop_match_end)
+                    .char_limit(255)
+                );
+            });
+        };
+        ui.add_enabled_ui(remove.crop_enabled, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("At");
+                egui::ComboBox::new(format!("remove_crop_occurrence-{}", index), "")
+                .selected_text(remove.crop_occurrence_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "First").clicked() {
+                        remove.crop_occurrence_name = String::from("First");
+                        remove.crop_occurrence = RemoveCropOccurrence::First
+                    }
+                    if ui.selectable_label(false, "Last").clicked() {
+                        remove.crop_occurrence_name = String::from("Last");
+                        remove.crop_occurrence = RemoveCropOccurrence::Last
+                    }
+                    if ui.selectable_label(false, "Nth").clicked() {
+                        remove.crop_occurrence_name = String::from("Nth");
+                        remove.crop_occurrence = RemoveCropOccurrence::Nth
                     }
                 });
+                ui.add_enabled_ui(remove.crop_occurrence == RemoveCropOccurrence::Nth, |ui| {
+                    let drag = ui.add_enabled(true, 
+                        egui::DragValue::new(&mut remove.crop_nth)
+                        .range(1..=255)
+                        .speed(0.05)
+                    );
+
+                    if drag.hovered() {
+                        gui.modifiers.drag_box_hovered = true;
+                    };
+            
+                    if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                        remove.crop_nth += 1;
+                    } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                        if remove.crop_nth >= 2 {
+                            remove.crop_nth -= 1;
+                        };
+                    };
+                    if ui.small_button("➖").clicked() {
+                        if remove.crop_nth >= 2 {
+                            remove.crop_nth -= 1;
+                        };
+                    };
+            
+                    ui.separator();
+            
+                    if ui.small_button("➕").clicked() {
+                        remove.crop_nth += 1;
+                    };
+                });
+
+                ui.label("Regex");
+                ui.checkbox(&mut remove.crop_regex, "");
+
+                if crop_between {
+                    ui.label("Keep Markers");
+                    ui.checkbox(&mut remove.crop_keep_markers, "");
+                };
             });
         });
-    });
-    match movecopy.mode_from {
-        MoveCopyFromMode::None => {
-            movecopy.widgets_enabled = false;
-        },
-        _ => { movecopy.widgets_enabled = true }
-    };
-    match movecopy.mode_to {
-        MoveCopyToMode::None => {
-            movecopy.widgets_enabled_two = false;
-        },
-        MoveCopyToMode::ToPos => {
-            movecopy.widgets_enabled_two = true;
-        }
-        _ => { movecopy.widgets_enabled_two = false }
-    };
-    // Fill modifications
-    {
-        if movecopy.mode_to != MoveCopyToMode::None { modifications += 1 };
-        if movecopy.mode_from != MoveCopyFromMode::None { modifications += 1 };
-        if movecopy.letters_count >= 1 { modifications += 1 };
-        if movecopy.mode_to_pos >= 1 { modifications += 1 };
-        if movecopy.seperator_enabled == true { modifications += 1 };
-    }
-    return (movecopy.to_owned(), modifications);
-}
-
-fn fill_modname(_gui: &mut WindowMain, ui: &mut egui::Ui, name: &mut ModName, index: usize) -> (ModName, u32) {
-    let mut modifications: u32 = 0;
-    ui.horizontal(|ui| {
-        ui.label("Mode");
-        egui::ComboBox::new(format!("name-{}", index), "")
-        .selected_text(name.mode_name.to_owned())
-        .show_ui(ui, |ui| {
-            if ui.selectable_label(false, "Keep").clicked() {
-                name.mode_name = String::from("Keep");
-                name.mode = NameMode::Keep
-            }
-            if ui.selectable_label(false, "Remove").clicked() {
-                name.mode_name = String::from("Remove");
-                name.mode = NameMode::Remove
-            }
-            if ui.selectable_label(false, "Fixed").clicked() {
-                name.mode_name = String::from("Fixed");
-                name.mode = NameMode::Fixed
-            }
-            if ui.selectable_label(false, "Reverse").clicked() {
-                name.mode_name = String::from("Reverse");
-                name.mode = NameMode::Reverse
+        if remove.crop_regex {
+            for marker in [&remove.crop_match, &remove.crop_match_end] {
+                if let Err(error) = regex::Regex::new(marker) {
+                    ui.colored_label(egui::Color32::RED, error.to_string());
+                };
             }
+        };
+        ui.horizontal(|ui| {
+            ui.label("Brackets");
+            ui.checkbox(&mut remove.brackets_round, "()");
+            ui.checkbox(&mut remove.brackets_square, "[]");
+            ui.checkbox(&mut remove.brackets_curly, "{}");
+            ui.checkbox(&mut remove.brackets_angle, "<>");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut remove.brackets_custom)
+                .hint_text("Custom: «»,「」")
+            );
         });
-        ui.add_enabled_ui(name.widgets_enabled, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("Segments");
+            egui::ComboBox::new(format!("remove_brackets-{}", index), "")
+            .selected_text(remove.brackets_which_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "All").clicked() {
+                    remove.brackets_which_name = String::from("All");
+                    remove.brackets_which = RemoveBracketsMode::All
+                }
+                if ui.selectable_label(false, "First").clicked() {
+                    remove.brackets_which_name = String::from("First");
+                    remove.brackets_which = RemoveBracketsMode::First
+                }
+                if ui.selectable_label(false, "Last").clicked() {
+                    remove.brackets_which_name = String::from("Last");
+                    remove.brackets_which = RemoveBracketsMode::Last
+                }
+            });
+            ui.label("Keep");
             ui.add_sized(
                 egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut name.fixed
-            ));
+                egui::text_edit::TextEdit::singleline(&mut remove.brackets_keep)
+                .hint_text("^\\d{4}$")
+            ).on_hover_text("Regex, segments whose contents match are kept. ^\\d{4}$ keeps a year.");
+        });
+        if let Err(error) = regex::Regex::new(&remove.brackets_keep) {
+            ui.colored_label(egui::Color32::RED, error.to_string());
+        };
+        ui.horizontal(|ui| {
+            ui.label("Digits");
+            ui.checkbox(&mut remove.digits, "");
+
+            ui.label("Dbl. Spaces");
+            ui.checkbox(&mut remove.double_spaces, "");
+
+            ui.label("Lead ..");
+            ui.checkbox(&mut remove.leading_dots, "");
+
+            ui.label("Symb.");
+            ui.checkbox(&mut remove.symbols, "");
+
+            ui.label("Trim");
+            ui.checkbox(&mut remove.trim, "");
         });
     });
-    match name.mode {
-        NameMode::Keep => {
-            name.widgets_enabled = false;
-        },
-        NameMode::Fixed => {
-            name.widgets_enabled = true;
+    match remove.crop {
+        RemoveCropMode::None => {
+            remove.crop_enabled = false;
         },
-        _ => { name.widgets_enabled = false }
-    };
+        _ => {
+            remove.crop_enabled = true;
+        }
+    }
     // Fill modifications
     {
-        if name.fixed.chars().count() >= 1 { modifications += 1 };
-        if name.mode != NameMode::Keep { modifications += 1 };
+        if remove.digits == true { modifications += 1 };
+        if remove.double_spaces == true { modifications += 1 };
+        if remove.leading_dots == true { modifications += 1 };
+        if remove.symbols == true { modifications += 1 };
+        if remove.trim == true { modifications += 1 };
+        if remove.crop_match.chars().count() >= 1 { modifications += 1};
+        if remove.crop != RemoveCropMode::None { modifications += 1 };
+        if remove.crop_match_end.chars().count() >= 1 { modifications += 1 };
+        if remove.crop_occurrence != RemoveCropOccurrence::First { modifications += 1 };
+        if remove.crop_regex == true { modifications += 1 };
+        if remove.crop_keep_markers == true { modifications += 1 };
+        if remove.chars_comma_seperated.chars().count() >= 1 { modifications += 1 };
+        if remove.words_comma_seperated.chars().count() >= 1 { modifications += 1 };
+        if remove.to_y >= 1 { modifications += 1 };
+        if remove.from_x >= 1 { modifications += 1 };
+        if remove.first_n >= 1 { modifications += 1 };
+        if remove.last_n >= 1 { modifications += 1 };
+        if remove.brackets_round == true { modifications += 1 };
+        if remove.brackets_square == true { modifications += 1 };
+        if remove.brackets_curly == true { modifications += 1 };
+        if remove.brackets_angle == true { modifications += 1 };
+        if remove.brackets_custom.chars().count() >= 1 { modifications += 1 };
+        if remove.brackets_which != RemoveBracketsMode::All { modifications += 1 };
+        if remove.brackets_keep.chars().count() >= 1 { modifications += 1 };
     }
-    return (name.to_owned(), modifications);
+    return (remove.to_owned(), modifications);
 }
 
-fn fill_modnumber(gui: &mut WindowMain, ui: &mut egui::Ui, number: &mut ModNumber, index: usize) -> (ModNumber, u32) {
+fn fill_modreplace(gui: &mut WindowMain, ui: &mut egui::Ui, replace: &mut ModReplace, index: usize) -> (ModReplace, u32) {
     let mut modifications: u32 = 0;
-    let mut seperator: String = number.seperator.to_string();
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Mode");
-            egui::ComboBox::new(format!("number-{}", index), "")
-            .selected_text(number.mode_name.to_owned())
-            .show_ui(ui, |ui| {
-                if ui.selectable_label(false, "None").clicked() {
-                    number.mode_name = String::from("None");
-                    number.mode = NumberMode::None
-                }
-                if ui.selectable_label(false, "Prefix").clicked() {
-                    number.mode_name = String::from("Prefix");
-                    number.mode = NumberMode::Prefix
-                }
-                if ui.selectable_label(false, "Suffix").clicked() {
-                    number.mode_name = String::from("Suffix");
-                    number.mode = NumberMode::Suffix
-                }
-                if ui.selectable_label(false, "Insert").clicked() {
-                    number.mode_name = String::from("Insert");
-                    number.mode = NumberMode::Insert
-                }
-                if ui.selectable_label(false, "Prefix+Suffix").clicked() {
-                    number.mode_name = String::from("Prefix+Suffix");
-                    number.mode = NumberMode::PrefixAndSuffix
-                }
-            });
-            ui.separator();
-            ui.add_enabled_ui(number.insert_enabled, |ui| {
-                ui.label("at");
-                let drag = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut number.insert_at)
-                    .range(-255..=255)
-                    .speed(0.05)
-                );
-
-                if drag.hovered() {
-                    gui.modifiers.drag_box_hovered = true;
-                };
-        
-                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    number.insert_at += 1;
-                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if number.insert_at >= 1 {
-                        number.insert_at -= 1;
-                    }
-                };
-                if ui.small_button("➖").clicked() {
-                    number.insert_at -= 1;
-                };
-                ui.separator();
-        
-                if ui.small_button("➕").clicked() {
-                    number.insert_at += 1;
-                };
-            });
+            ui.label("Match");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut replace.replace_match)
+            );
         });
         ui.horizontal(|ui| {
-            ui.add_enabled_ui(number.widgets_enabled, |ui| {
-                ui.label("Starting");
+            ui.label("Replace");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut replace.replace_with)
+            );
+        });
+        ui.horizontal(|ui| {
+            ui.label("Occurrence");
+            egui::ComboBox::new(format!("replace-occurrence-{}", index), "")
+                .selected_text(replace.occurrence_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "All").clicked() {
+                        replace.occurrence_name = String::from("All");
+                        replace.occurrence = ReplaceOccurrence::All;
+                    }
+                    if ui.selectable_label(false, "First").clicked() {
+                        replace.occurrence_name = String::from("First");
+                        replace.occurrence = ReplaceOccurrence::First;
+                    }
+                    if ui.selectable_label(false, "Last").clicked() {
+                        replace.occurrence_name = String::from("Last");
+                        replace.occurrence = ReplaceOccurrence::Last;
+                    }
+                    if ui.selectable_label(false, "Nth").clicked() {
+                        replace.occurrence_name = String::from("Nth");
+                        replace.occurrence = ReplaceOccurrence::Nth;
+                    }
+                });
+            ui.add_enabled_ui(replace.occurrence == ReplaceOccurrence::Nth, |ui| {
                 let drag = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut number.starting_num)
-                    .range(0..=25565)
+                    egui::DragValue::new(&mut replace.nth)
+                    .range(1..=255)
                     .speed(0.05)
                 );
 
@@ -1677,427 +3074,1193 @@ fn fill_modnumber(gui: &mut WindowMain, ui: &mut egui::Ui, number: &mut ModNumbe
                 };
         
                 if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    number.starting_num += 1;
+                    replace.nth += 1;
                 } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if number.starting_num >= 1 {
-                        number.starting_num -= 1;
-                    }
+                    if replace.nth >= 2 {
+                        replace.nth -= 1;
+                    };
                 };
                 if ui.small_button("➖").clicked() {
-                    if number.starting_num >= 1 {
-                        number.starting_num -= 1;
-                    }
+                    if replace.nth >= 2 {
+                        replace.nth -= 1;
+                    };
                 };
-                ui.separator();
         
-                if ui.small_button("➕").clicked() {
-                    number.starting_num += 1;
-                };
-
-                ui.label("Increment");
-                let drag_increment = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut number.increment_num)
-                    .range(1..=255)
-                    .speed(0.05)
-                );
-                if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    number.increment_num += 1;
-                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if number.increment_num >= 2 {
-                        number.increment_num -= 1;
-                    }
-                };
-                if ui.small_button("➖").clicked() {
-                    if number.increment_num >= 2 {
-                        number.increment_num -= 1;
-                    }
-                };
                 ui.separator();
         
                 if ui.small_button("➕").clicked() {
-                    number.increment_num += 1;
+                    replace.nth += 1;
                 };
             });
         });
         ui.horizontal(|ui| {
-            ui.add_enabled_ui(number.widgets_enabled, |ui| {
-                ui.label("Padding");
-                let drag_increment = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut number.padding)
-                    .range(0..=255)
-                    .speed(0.05)
-                );
+            ui.label("Ignore Case");
+            ui.checkbox(&mut replace.case_insensitive, "");
 
-                if drag_increment.hovered() {
-                    gui.modifiers.drag_box_hovered = true;
-                };
-        
-                if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    number.padding += 1;
-                } else if drag_increment.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if number.padding >= 1 {
-                        number.padding -= 1;
-                    }
-                };
-                if ui.small_button("➖").clicked() {
-                    if number.padding >= 1 {
-                        number.padding -= 1;
-                    }
-                };
-                ui.separator();
-        
-                if ui.small_button("➕").clicked() {
-                    number.padding += 1;
-                };
+            ui.label("Whole Word");
+            ui.checkbox(&mut replace.whole_word, "");
 
-                ui.label("Seperator Char");
-                ui.checkbox(&mut number.seperator_enabled, "");
-                ui.add_enabled_ui(number.seperator_enabled, |ui| {
-                    let response = ui.add_sized(
-                        egui::vec2(20.0, ui.available_height()), 
-                        egui::text_edit::TextEdit::singleline(&mut seperator)
-                        .char_limit(2)
-                        .cursor_at_end(false)
-                    );
-                    if response.changed() {
-                        if seperator.len() >= 1 {
-                            let c = seperator.pop().unwrap();
-                            number.seperator = c;
-                        };
-                    };
-                });
-            });
+            ui.label("Keep Case");
+            ui.checkbox(&mut replace.preserve_case, "")
+                .on_hover_text("Replacement follows the matched text's case: UPPER, lower or Title");
         });
-        ui.horizontal(|ui| {
-            ui.add_enabled_ui(number.widgets_enabled, |ui| {
-                ui.label("Type Mode");
-                egui::ComboBox::new(format!("numbertype-{}", index), "")
-                .selected_text(number.mode_type_name.to_owned())
-                .show_ui(ui, |ui| {
-                    if ui.selectable_label(false, "Base 2").clicked() {
-                        number.mode_type_name = String::from("Base 2");
-                        number.mode_type = NumberTypeMode::BaseTwo
-                    }
-                    if ui.selectable_label(false, "Base 8").clicked() {
-                        number.mode_type_name = String::from("Base 8");
-                        number.mode_type = NumberTypeMode::BaseEight
-                    }
-                    if ui.selectable_label(false, "Base 10").clicked() {
-                        number.mode_type_name = String::from("Base Ten");
-                        number.mode_type = NumberTypeMode::BaseTen
-                    }
-                    if ui.selectable_label(false, "Base 16").clicked() {
-                        number.mode_type_name = String::from("Base 16");
-                        number.mode_type = NumberTypeMode::BaseSixteen
-                    }
-                    if ui.selectable_label(false, "Roman Numeral").clicked() {
-                        number.mode_type_name = String::from("Roman Numeral");
-                        number.mode_type = NumberTypeMode::RomanNumeral
-                    }
-                    if ui.selectable_label(false, "a - z").clicked() {
-                        number.mode_type_name = String::from("a - z");
-                        number.mode_type = NumberTypeMode::AlphaLower
-                    }
-                    if ui.selectable_label(false, "A - Z").clicked() {
-                        number.mode_type_name = String::from("A - Z");
-                        number.mode_type = NumberTypeMode::AlphaUpper
-                    }
-                    if ui.selectable_label(false, "a - Z").clicked() {
-                        number.mode_type_name = String::from("a - Z");
-                        number.mode_type = NumberTypeMode::AlphaLowerToUpper
-                    }
-                });
-            });
+        let preview = tester_preview(gui, ui.ctx(), format!("replace-tester-{}", index), format!("{:?}", replace), |names| Ok(file_processing::preview_replace(replace, names)));
+        fill_tester(ui, format!("replace-tester-{}", index), preview, gui.tester_cache.names.len());
+    });
+    // Fill modifications
+    {
+        if replace.occurrence != ReplaceOccurrence::All { modifications += 1 };
+        if replace.case_insensitive == true { modifications += 1 };
+        if replace.whole_word == true { modifications += 1 };
+        if replace.preserve_case == true { modifications += 1 };
+        if replace.replace_match.chars().count() >= 1 { modifications += 1};
+        if replace.replace_with.chars().count() >= 1 { modifications += 1};
+    }
+    return (replace.to_owned(), modifications);
+}
+
+fn fill_modtemplate(gui: &mut WindowMain, ui: &mut egui::Ui, template: &mut ModTemplate, _index: usize) -> (ModTemplate, u32) {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("Template");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut template.template)
+            ).on_hover_text(format!("{}{}{}{}{}{}{}{}{}{}{}",
+                "{name}          : Original name\n",
+                "{ext}           : Original extension (.jpg)\n",
+                "{counter:03}    : Counter, padded to 3 digits\n",
+                "{date:%Y%m%d}   : Current date\n",
+                "{mtime:%Y%m%d}  : Modified date\n",
+                "{ctime:%Y%m%d}  : Created date\n",
+                "{atime:%Y%m%d}  : Accessed date\n",
+                "{hash}          : Hash, using the Hash modifier's algorithm\n",
+                "{size:kb}       : Size in b, kb, mb or gb\n",
+                "{parent:2}      : Parent folder name, 2 = the grandparent\n",
+                "{{ and }}       : Literal braces"
+            ));
+        });
+        ui.horizontal(|ui| {
+            ui.label("Counter Start");
+            let drag = ui.add_enabled(true, 
+                egui::DragValue::new(&mut template.counter_start)
+                .range(0..=25565)
+                .speed(0.05)
+            );
+
+            if drag.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                template.counter_start += 1;
+            } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                if template.counter_start >= 1 {
+                    template.counter_start -= 1;
+                }
+            };
+            if ui.small_button("➖").clicked() {
+                if template.counter_start >= 1 {
+                    template.counter_start -= 1;
+                }
+            };
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                template.counter_start += 1;
+            };
+
+            ui.label("Step");
+            let drag_step = ui.add_enabled(true, 
+                egui::DragValue::new(&mut template.counter_step)
+                .range(1..=255)
+                .speed(0.05)
+            );
+
+            if drag_step.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag_step.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                template.counter_step += 1;
+            } else if drag_step.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                if template.counter_step >= 2 {
+                    template.counter_step -= 1;
+                }
+            };
+            if ui.small_button("➖").clicked() {
+                if template.counter_step >= 2 {
+                    template.counter_step -= 1;
+                }
+            };
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                template.counter_step += 1;
+            };
         });
+        if let Err(error) = file_processing::validate_template(&template.template) {
+            ui.colored_label(egui::Color32::RED, error);
+        };
     });
-    match number.mode {
-        NumberMode::None => {
-            number.widgets_enabled = false;
-            number.insert_enabled = false;
-        },
-        NumberMode::Insert => {
-            number.widgets_enabled = true;
-            number.insert_enabled = true;
+    // Fill modifications
+    {
+        if template.template.chars().count() >= 1 { modifications += 1 };
+        if template.counter_start != 1 { modifications += 1 };
+        if template.counter_step >= 2 { modifications += 1 };
+    }
+    return (template.to_owned(), modifications);
+}
+
+fn fill_modtransliterate(_gui: &mut WindowMain, ui: &mut egui::Ui, transliterate: &mut ModTransliterate) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {
+        ui.label("Mode");
+        egui::ComboBox::new(format!("transliterate"), "")
+        .selected_text(transliterate.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "None").clicked() {
+                transliterate.mode_name = String::from("None");
+                transliterate.mode = TransliterateMode::None;
+            }
+            if ui.selectable_label(false, "ASCII").clicked() {
+                transliterate.mode_name = String::from("ASCII");
+                transliterate.mode = TransliterateMode::Ascii;
+            }
+            if ui.selectable_label(false, "Non-Latin").clicked() {
+                transliterate.mode_name = String::from("Non-Latin");
+                transliterate.mode = TransliterateMode::NonLatin;
+            }
+        }).response.on_hover_text("ASCII: Everything, accents included, becomes plain ASCII.\nNon-Latin: Only Cyrillic, Greek and other scripts, accented latin letters are kept.");
+    });
+    ui.horizontal(|ui| {
+        ui.add_enabled_ui(transliterate.widgets_enabled, |ui| {
+            ui.label("Unknown");
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut transliterate.unknown)
+            ).on_hover_text("Used for characters that have no ASCII equivalent.");
+        });
+    });
+    match transliterate.mode {
+        TransliterateMode::None => {
+            transliterate.widgets_enabled = false;
         },
-        _ => { 
-            number.widgets_enabled = true; 
-            number.insert_enabled = false; 
-        }
+        _ => { transliterate.widgets_enabled = true }
     };
     // Fill modifications
     {
-        if number.increment_num >= 2 { modifications += 1 };
-        if number.insert_at != 0 { modifications += 1 };
-        if number.mode != NumberMode::None { modifications += 1 };
-        if number.starting_num != 1 { modifications += 1 };
+        if transliterate.mode != TransliterateMode::None { modifications += 1 };
+        if transliterate.unknown != "_" { modifications += 1 };
     }
-    return (number.to_owned(), modifications);
+    return modifications;
+}
+
+fn fill_modnormalize(_gui: &mut WindowMain, ui: &mut egui::Ui, normalize: &mut ModNormalize) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.horizontal(|ui| {
+        ui.label("Form");
+        egui::ComboBox::new(format!("normalize"), "")
+        .selected_text(normalize.mode_name.to_owned())
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(false, "None").clicked() {
+                normalize.mode_name = String::from("None");
+                normalize.mode = NormalizeMode::None;
+            }
+            if ui.selectable_label(false, "NFC").clicked() {
+                normalize.mode_name = String::from("NFC");
+                normalize.mode = NormalizeMode::NFC;
+            }
+            if ui.selectable_label(false, "NFD").clicked() {
+                normalize.mode_name = String::from("NFD");
+                normalize.mode = NormalizeMode::NFD;
+            }
+            if ui.selectable_label(false, "NFKC").clicked() {
+                normalize.mode_name = String::from("NFKC");
+                normalize.mode = NormalizeMode::NFKC;
+            }
+            if ui.selectable_label(false, "NFKD").clicked() {
+                normalize.mode_name = String::from("NFKD");
+                normalize.mode = NormalizeMode::NFKD;
+            }
+        }).response.on_hover_text("NFC: Composed, what Windows and Linux usually use.\nNFD: Decomposed, what macOS hands out.\nNFKC / NFKD: Also folds compatibility characters like ﬁ or ² into plain ones.\n\nNames in another form are shown in yellow, names that collide once normalized in red.");
+    });
+    // Fill modifications
+    {
+        if normalize.mode != NormalizeMode::None { modifications += 1 };
+    }
+    return modifications;
 }
 
-fn fill_modregex(_gui: &mut WindowMain, ui: &mut egui::Ui, regex: &mut ModRegex, _index: usize) -> (ModRegex, u32) {
+fn fill_moddictionary(_gui: &mut WindowMain, ui: &mut egui::Ui, dictionary: &mut ModDictionary) -> u32 {
     let mut modifications: u32 = 0;
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Exp");
+            ui.label("File");
+            if ui.button("Load").on_hover_text("Replaces the table below with the file's pairs.").clicked() {
+                match std::fs::read_to_string(&dictionary.file_path) {
+                    Ok(text) => {
+                        dictionary.table = text;
+                        dictionary.load_error = String::new();
+                    },
+                    Err(error) => {
+                        dictionary.load_error = format!("Couldn't load {}: {}", dictionary.file_path, error);
+                    }
+                }
+            };
             ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()),
-                egui::text_edit::TextEdit::singleline(&mut regex.replace_match))
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut dictionary.file_path)
+                .hint_text("/path/to/pairs.csv")
+            );
         });
+        if !dictionary.load_error.is_empty() {
+            ui.colored_label(egui::Color32::RED, dictionary.load_error.to_owned());
+        };
+        ui.add_sized(
+            egui::vec2(ui.available_width(), 0.0),
+            egui::text_edit::TextEdit::multiline(&mut dictionary.table)
+            .desired_rows(4)
+            .hint_text("find,replace\nfind<tab>replace")
+        );
+        let (pairs, malformed) = file_processing::parse_pairs(&dictionary.table);
+        ui.label(format!("{} pairs", pairs.len()));
+        if !malformed.is_empty() {
+            let lines: Vec<String> = malformed.iter().map(|line| line.to_string()).collect();
+            ui.colored_label(egui::Color32::YELLOW, format!("Skipped line(s) {}, they need a find and a replace.", lines.join(", ")));
+        };
         ui.horizontal(|ui| {
-            ui.label("Replace");
-            ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()),
-                egui::text_edit::TextEdit::singleline(&mut regex.replace_with))
+            ui.label("Order");
+            egui::ComboBox::new(format!("dictionary-order"), "")
+                .selected_text(dictionary.order_name.to_owned())
+                .show_ui(ui, |ui| {
+                    if ui.selectable_label(false, "Longest First").clicked() {
+                        dictionary.order_name = String::from("Longest First");
+                        dictionary.order = DictionaryOrder::Longest;
+                    }
+                    if ui.selectable_label(false, "As Listed").clicked() {
+                        dictionary.order_name = String::from("As Listed");
+                        dictionary.order = DictionaryOrder::Listed;
+                    }
+                }).response.on_hover_text("Which pair wins when several match at the same spot.");
+        });
+        ui.horizontal(|ui| {
+            ui.label("Ignore Case");
+            ui.checkbox(&mut dictionary.case_insensitive, "");
+
+            ui.label("Whole Word");
+            ui.checkbox(&mut dictionary.whole_word, "");
+
+            ui.label("Keep Case");
+            ui.checkbox(&mut dictionary.preserve_case, "")
+                .on_hover_text("Replacement follows the matched text's case: UPPER, lower or Title");
         });
     });
     // Fill modifications
     {
-        if regex.replace_match.chars().count() >= 1 { modifications += 1 };
-        if regex.replace_with.chars().count() >= 1 { modifications += 1 };
+        if !dictionary.table.trim().is_empty() { modifications += 1 };
+        if dictionary.order != DictionaryOrder::Longest { modifications += 1 };
+        if dictionary.case_insensitive == true { modifications += 1 };
+        if dictionary.whole_word == true { modifications += 1 };
+        if dictionary.preserve_case == true { modifications += 1 };
     }
-    return (regex.to_owned(), modifications);
+    return modifications;
 }
 
-fn fill_modremove(gui: &mut WindowMain, ui: &mut egui::Ui, remove: &mut ModRemove, index: usize) -> (ModRemove, u32) {
+fn fill_modrenumber(gui: &mut WindowMain, ui: &mut egui::Ui, renumber: &mut ModRenumber) -> u32 {
     let mut modifications: u32 = 0;
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.group(|ui| {
-                ui.label("First");
-                let drag_first = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut remove.first_n)
-                    .range(0..=255)
+            ui.label("Number");
+            egui::ComboBox::new(format!("renumber-occurrence"), "")
+            .selected_text(renumber.occurrence_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "First").clicked() {
+                    renumber.occurrence_name = String::from("First");
+                    renumber.occurrence = RenumberOccurrence::First;
+                }
+                if ui.selectable_label(false, "Last").clicked() {
+                    renumber.occurrence_name = String::from("Last");
+                    renumber.occurrence = RenumberOccurrence::Last;
+                }
+                if ui.selectable_label(false, "Nth").clicked() {
+                    renumber.occurrence_name = String::from("Nth");
+                    renumber.occurrence = RenumberOccurrence::Nth;
+                }
+            });
+            ui.add_enabled_ui(renumber.occurrence == RenumberOccurrence::Nth, |ui| {
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut renumber.nth)
+                    .range(1..=255)
                     .speed(0.05)
                 );
 
-                if drag_first.hovered() {
+                if drag.hovered() {
                     gui.modifiers.drag_box_hovered = true;
                 };
         
-                if drag_first.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    remove.first_n += 1;
-                } else if drag_first.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if remove.first_n >= 1 {
-                        remove.first_n -= 1;
-                    }
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    renumber.nth += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if renumber.nth >= 2 {
+                        renumber.nth -= 1;
+                    };
                 };
                 if ui.small_button("➖").clicked() {
-                    if remove.first_n >= 1 {
-                        remove.first_n -= 1;
-                    }
+                    if renumber.nth >= 2 {
+                        renumber.nth -= 1;
+                    };
                 };
+        
                 ui.separator();
-
+        
                 if ui.small_button("➕").clicked() {
-                    remove.first_n += 1;
+                    renumber.nth += 1;
                 };
             });
-            
-            ui.group(|ui| {
-                ui.label("Last");
-                let drag_last = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut remove.last_n)
-                    .range(0..=255)
+        });
+        ui.horizontal(|ui| {
+            ui.label("Padding");
+            let drag = ui.add_enabled(true, 
+                egui::DragValue::new(&mut renumber.padding)
+                .range(0..=32)
+                .speed(0.05)
+            ).on_hover_text("0 keeps zero padded numbers at their width.");
+
+            if drag.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                renumber.padding += 1;
+            } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                if renumber.padding >= 1 {
+                    renumber.padding -= 1;
+                };
+            };
+            if ui.small_button("➖").clicked() {
+                if renumber.padding >= 1 {
+                    renumber.padding -= 1;
+                };
+            };
+    
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                renumber.padding += 1;
+            };
+
+            ui.label("Offset");
+            let drag_offset = ui.add_enabled(true, 
+                egui::DragValue::new(&mut renumber.offset)
+                .range(-25565..=25565)
+                .speed(0.05)
+            );
+
+            if drag_offset.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag_offset.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                renumber.offset += 1;
+            } else if drag_offset.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                renumber.offset -= 1;
+            };
+            if ui.small_button("➖").clicked() {
+                renumber.offset -= 1;
+            };
+    
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                renumber.offset += 1;
+            };
+        });
+        ui.horizontal(|ui| {
+            ui.label("Resequence");
+            ui.checkbox(&mut renumber.resequence, "")
+                .on_hover_text("Numbers the folder's items one after another, in the order of their old numbers.");
+            ui.add_enabled_ui(renumber.resequence, |ui| {
+                ui.label("Start");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut renumber.start)
+                    .range(0..=25565)
                     .speed(0.05)
                 );
 
-                if drag_last.hovered() {
+                if drag.hovered() {
                     gui.modifiers.drag_box_hovered = true;
                 };
         
-                if drag_last.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    remove.last_n += 1;
-                } else if drag_last.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if remove.last_n >= 1 {
-                        remove.last_n -= 1;
-                    }
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    renumber.start += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if renumber.start >= 1 {
+                        renumber.start -= 1;
+                    };
                 };
                 if ui.small_button("➖").clicked() {
-                    if remove.last_n >= 1 {
-                        remove.last_n -= 1;
-                    }
+                    if renumber.start >= 1 {
+                        renumber.start -= 1;
+                    };
                 };
+        
                 ui.separator();
-
+        
                 if ui.small_button("➕").clicked() {
-                    remove.last_n += 1;
+                    renumber.start += 1;
                 };
+
+                ui.label("Keep Gaps");
+                ui.checkbox(&mut renumber.keep_gaps, "");
             });
         });
+    });
+    // Fill modifications
+    {
+        if renumber.occurrence != RenumberOccurrence::First { modifications += 1 };
+        if renumber.padding >= 1 { modifications += 1 };
+        if renumber.offset != 0 { modifications += 1 };
+        if renumber.resequence == true { modifications += 1 };
+        if renumber.start != 1 { modifications += 1 };
+        if renumber.keep_gaps == true { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modsequence(gui: &mut WindowMain, ui: &mut egui::Ui, sequence: &mut ModSequence) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.group(|ui| {
-                ui.label("From");
-                let drag_from = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut remove.from_x)
-                    .range(0..=25565)
+            ui.label("Renumber");
+            ui.checkbox(&mut sequence.renumber, "")
+                .on_hover_text("Renumbers each sequence's frames from Start, keeping their order.");
+            ui.add_enabled_ui(sequence.renumber, |ui| {
+                ui.label("Start");
+                let drag = ui.add_enabled(true, 
+                    egui::DragValue::new(&mut sequence.start)
+                    .range(0..=999999)
                     .speed(0.05)
                 );
 
-                if drag_from.hovered() {
+                if drag.hovered() {
                     gui.modifiers.drag_box_hovered = true;
                 };
         
-                if drag_from.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    remove.from_x += 1;
-                } else if drag_from.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if remove.from_x >= 1 {
-                        remove.from_x -= 1;
-                    }
+                if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                    sequence.start += 1;
+                } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                    if sequence.start >= 1 {
+                        sequence.start -= 1;
+                    };
                 };
                 if ui.small_button("➖").clicked() {
-                    if remove.from_x >= 1 {
-                        remove.from_x -= 1;
-                    }
+                    if sequence.start >= 1 {
+                        sequence.start -= 1;
+                    };
                 };
+        
                 ui.separator();
+        
+                if ui.small_button("➕").clicked() {
+                    sequence.start += 1;
+                };
+
+                ui.label("Close Gaps");
+                ui.checkbox(&mut sequence.close_gaps, "")
+                    .on_hover_text("Numbers the frames one after another instead of keeping missing frames as gaps.");
+            });
+        });
+        ui.horizontal(|ui| {
+            ui.label("Padding");
+            let drag = ui.add_enabled(true, 
+                egui::DragValue::new(&mut sequence.padding)
+                .range(0..=32)
+                .speed(0.05)
+            ).on_hover_text("0 keeps each sequence's own padding.");
+
+            if drag.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                sequence.padding += 1;
+            } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                if sequence.padding >= 1 {
+                    sequence.padding -= 1;
+                };
+            };
+            if ui.small_button("➖").clicked() {
+                if sequence.padding >= 1 {
+                    sequence.padding -= 1;
+                };
+            };
+    
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                sequence.padding += 1;
+            };
+        });
+        ui.horizontal(|ui| {
+            ui.label("Prefix");
+            ui.checkbox(&mut sequence.prefix_enabled, "");
+            ui.add_enabled_ui(sequence.prefix_enabled, |ui| {
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), ui.available_height()), 
+                    egui::text_edit::TextEdit::singleline(&mut sequence.prefix)
+                    .hint_text("shot_")
+                );
+            });
+        });
+    });
+    // Fill modifications
+    {
+        if sequence.renumber == true { modifications += 1 };
+        if sequence.start != 1 { modifications += 1 };
+        if sequence.close_gaps == true { modifications += 1 };
+        if sequence.padding >= 1 { modifications += 1 };
+        if sequence.prefix_enabled == true { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modrandom(gui: &mut WindowMain, ui: &mut egui::Ui, modrandom: &mut ModRandom) -> u32 {
+    let mut modifications: u32 = 0;
+    let mut seperator: String = modrandom.seperator.to_string();
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("Mode");
+            egui::ComboBox::new(format!("random-mode"), "")
+            .selected_text(modrandom.mode_name.to_owned())
+            .show_ui(ui, |ui| {
+                for (name, mode) in [("None", RandomMode::None), ("Replace", RandomMode::Replace), ("Prefix", RandomMode::Prefix), ("Suffix", RandomMode::Suffix)] {
+                    if ui.selectable_label(false, name).clicked() {
+                        modrandom.mode_name = String::from(name);
+                        modrandom.mode = mode;
+                    }
+                }
+            });
+            ui.label("Kind");
+            egui::ComboBox::new(format!("random-kind"), "")
+            .selected_text(modrandom.kind_name.to_owned())
+            .show_ui(ui, |ui| {
+                let kinds = [
+                    ("Alphanumeric", RandomKind::Alphanumeric), ("Hex", RandomKind::Hex), ("NanoID", RandomKind::NanoId), 
+                    ("UUID v4", RandomKind::UuidV4), ("UUID v7", RandomKind::UuidV7), ("ULID", RandomKind::Ulid)
+                ];
+                for (name, kind) in kinds {
+                    if ui.selectable_label(false, name).clicked() {
+                        modrandom.kind_name = String::from(name);
+                        modrandom.kind = kind;
+                    }
+                }
+            });
+        });
+        ui.add_enabled_ui(modrandom.mode != RandomMode::None, |ui| {
+            ui.horizontal(|ui| {
+                let has_length = matches!(modrandom.kind, RandomKind::Alphanumeric | RandomKind::Hex | RandomKind::NanoId);
+                ui.add_enabled_ui(has_length, |ui| {
+                    ui.label("Length");
+                    let drag = ui.add_enabled(true, 
+                        egui::DragValue::new(&mut modrandom.length)
+                        .range(1..=128)
+                        .speed(0.05)
+                    );
 
-                if ui.small_button("➕").clicked() {
-                    remove.from_x += 1;
+                    if drag.hovered() {
+                        gui.modifiers.drag_box_hovered = true;
+                    };
+            
+                    if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                        modrandom.length += 1;
+                    } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                        if modrandom.length >= 2 {
+                            modrandom.length -= 1;
+                        };
+                    };
+                    if ui.small_button("➖").clicked() {
+                        if modrandom.length >= 2 {
+                            modrandom.length -= 1;
+                        };
+                    };
+            
+                    ui.separator();
+            
+                    if ui.small_button("➕").clicked() {
+                        modrandom.length += 1;
+                    };
+                });
+
+                ui.add_enabled_ui(modrandom.mode != RandomMode::Replace, |ui| {
+                    ui.label("Seperator Char");
+                    ui.checkbox(&mut modrandom.seperator_enabled, "");
+                    ui.add_enabled_ui(modrandom.seperator_enabled, |ui| {
+                        let response = ui.add_sized(
+                            egui::vec2(20.0, ui.available_height()), 
+                            egui::text_edit::TextEdit::singleline(&mut seperator)
+                            .char_limit(2)
+                            .cursor_at_end(false)
+                        );
+                        if response.changed() {
+                            if seperator.len() >= 1 {
+                                let c = seperator.pop().unwrap();
+                                modrandom.seperator = c;
+                            };
+                        };
+                    });
+                });
+            });
+            ui.horizontal(|ui| {
+                ui.label("Seed");
+                ui.add(egui::DragValue::new(&mut modrandom.seed).speed(1.0).range(0..=random::SEED_MAXIMUM))
+                    .on_hover_text("The same seed gives the same names, saved with presets.");
+                if ui.small_button("🎲").on_hover_text("New seed").clicked() {
+                    modrandom.seed = random::new_seed();
                 };
             });
+        });
+    });
+    // Fill modifications
+    {
+        if modrandom.mode != RandomMode::None { modifications += 1 };
+        if modrandom.kind != RandomKind::Hex { modifications += 1 };
+        if modrandom.length != 8 { modifications += 1 };
+        if modrandom.seperator_enabled == true { modifications += 1 };
+    }
+    return modifications;
+}
 
-            ui.group(|ui| {
-                ui.label("To");
-                let drag_to = ui.add_enabled(true, 
-                    egui::DragValue::new(&mut remove.to_y)
-                    .range(remove.from_x..=25565)
-                    .speed(0.05)
-                );
+fn fill_modtruncate(gui: &mut WindowMain, ui: &mut egui::Ui, modtruncate: &mut ModTruncate) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("Limit");
+            let drag = ui.add_enabled(true, 
+                egui::DragValue::new(&mut modtruncate.limit)
+                .range(0..=1024)
+                .speed(0.05)
+            ).on_hover_text("0 leaves names alone.");
 
-                if drag_to.hovered() {
-                    gui.modifiers.drag_box_hovered = true;
-                };
-        
-                if drag_to.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
-                    remove.to_y += 1;
-                } else if drag_to.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
-                    if remove.to_y >= 1 {
-                        remove.to_y -= 1;
-                    }
+            if drag.hovered() {
+                gui.modifiers.drag_box_hovered = true;
+            };
+    
+            if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y >= 1.0}){
+                modtruncate.limit += 1;
+            } else if drag.hovered() && ui.input(|input| {input.raw_scroll_delta.y <= -1.0}) {
+                if modtruncate.limit >= 1 {
+                    modtruncate.limit -= 1;
                 };
-                if ui.small_button("➖").clicked() {
-                    if remove.to_y >= 1 {
-                        remove.to_y -= 1;
-                    }
+            };
+            if ui.small_button("➖").clicked() {
+                if modtruncate.limit >= 1 {
+                    modtruncate.limit -= 1;
                 };
-                ui.separator();
+            };
+    
+            ui.separator();
+    
+            if ui.small_button("➕").clicked() {
+                modtruncate.limit += 1;
+            };
 
-                if ui.small_button("➕").clicked() {
-                    remove.to_y += 1;
-                };
-            })
+            egui::ComboBox::new(format!("truncate-unit"), "")
+            .selected_text(modtruncate.unit_name.to_owned())
+            .show_ui(ui, |ui| {
+                if ui.selectable_label(false, "Characters").clicked() {
+                    modtruncate.unit_name = String::from("Characters");
+                    modtruncate.unit = TruncateUnit::Characters;
+                }
+                if ui.selectable_label(false, "Bytes").clicked() {
+                    modtruncate.unit_name = String::from("Bytes");
+                    modtruncate.unit = TruncateUnit::Bytes;
+                }
+            });
+        });
+        ui.add_enabled_ui(modtruncate.limit >= 1, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Count Extension");
+                ui.checkbox(&mut modtruncate.count_extension, "")
+                    .on_hover_text("The extension is never cut, this only decides if it counts against the limit.");
+                ui.label("Word Boundary");
+                ui.checkbox(&mut modtruncate.word_boundary, "");
+            });
+            ui.horizontal(|ui| {
+                ui.label("Protect Counter / Hash");
+                ui.checkbox(&mut modtruncate.protect_tail, "")
+                    .on_hover_text("Keeps a trailing number or bracketed hash.");
+                ui.label("Dedupe");
+                ui.checkbox(&mut modtruncate.dedupe, "")
+                    .on_hover_text("Adds a short hash when a cut name is already taken.");
+            });
+            ui.horizontal(|ui| {
+                ui.label("Marker");
+                ui.checkbox(&mut modtruncate.marker_enabled, "");
+                ui.add_enabled_ui(modtruncate.marker_enabled, |ui| {
+                    ui.add_sized(
+                        egui::vec2(ui.available_width(), ui.available_height()), 
+                        egui::text_edit::TextEdit::singleline(&mut modtruncate.marker)
+                        .hint_text("…")
+                    );
+                });
+            });
         });
+    });
+    // Fill modifications
+    {
+        if modtruncate.limit >= 1 { modifications += 1 };
+        if modtruncate.unit != TruncateUnit::Characters { modifications += 1 };
+        if modtruncate.marker_enabled == true { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modseperators(ui: &mut egui::Ui, modseperators: &mut ModSeperators) -> u32 {
+    let mut modifications: u32 = 0;
+    let mut to: String = modseperators.to.to_string();
+    ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Chars");
+            ui.label("From");
             ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut remove.chars_comma_seperated)
+                egui::vec2(80.0, ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut modseperators.from)
+                .hint_text("._-")
+            ).on_hover_text("Every character here becomes the target.");
+            ui.label("To");
+            let response = ui.add_sized(
+                egui::vec2(20.0, ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut to)
+                .char_limit(2)
+                .cursor_at_end(false)
             );
+            if response.changed() {
+                if to.len() >= 1 {
+                    let c = to.pop().unwrap();
+                    modseperators.to = c;
+                };
+            };
         });
         ui.horizontal(|ui| {
-            ui.label("Words");
+            ui.label("Collapse Runs");
+            ui.checkbox(&mut modseperators.collapse, "")
+                .on_hover_text("Turns a run of seperators into one and trims them from the ends.");
+            ui.label("Split camelCase");
+            ui.checkbox(&mut modseperators.split_camel, "");
+        });
+        ui.horizontal(|ui| {
+            ui.label("Protect");
+            ui.checkbox(&mut modseperators.protect_enabled, "")
+                .on_hover_text("Regexes, one a line, for what's left alone like v1.2.3 or 5.1. The first capture group is kept when there is one.");
+        });
+        if modseperators.protect_enabled {
+            ui.add_sized(
+                egui::vec2(ui.available_width(), 0.0),
+                egui::text_edit::TextEdit::multiline(&mut modseperators.protect)
+                .desired_rows(3)
+            );
+            if let Err(err) = file_processing::build_seperator_protect(&modseperators.protect) {
+                ui.colored_label(egui::Color32::RED, err);
+            };
+        };
+    });
+    // Fill modifications
+    {
+        if modseperators.from.chars().count() >= 1 { modifications += 1 };
+        if modseperators.to != ' ' { modifications += 1 };
+        if modseperators.collapse == false { modifications += 1 };
+        if modseperators.split_camel == true { modifications += 1 };
+        if modseperators.protect_enabled == false { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modmapping(gui: &mut WindowMain, ui: &mut egui::Ui, mapping: &mut ModMapping) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
+        ui.horizontal(|ui| {
+            ui.label("File");
+            if ui.button("Load").on_hover_text("Replaces the table below with the file's rows.").clicked() {
+                match std::fs::read_to_string(&mapping.file_path) {
+                    Ok(text) => {
+                        mapping.table = text;
+                        mapping.load_error = String::new();
+                    },
+                    Err(error) => {
+                        mapping.load_error = format!("Couldn't load {}: {}", mapping.file_path, error);
+                    }
+                }
+            };
             ui.add_sized(
                 egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut remove.words_comma_seperated)
+                egui::text_edit::TextEdit::singleline(&mut mapping.file_path)
+                .hint_text("/path/to/mapping.csv")
             );
         });
+        if !mapping.load_error.is_empty() {
+            ui.colored_label(egui::Color32::RED, mapping.load_error.to_owned());
+        };
+        egui::ScrollArea::vertical()
+            .id_salt("mapping-table")
+            .max_height(120.0)
+            .show(ui, |ui| {
+                ui.add_sized(
+                    egui::vec2(ui.available_width(), 0.0),
+                    egui::text_edit::TextEdit::multiline(&mut mapping.table)
+                    .desired_rows(4)
+                    .hint_text("old_name,new_name\nIMG_0001.jpg,Beach.jpg")
+                );
+            });
+        match mapping_summary(gui, ui.ctx(), &mapping.table) {
+            Ok(summary) => {
+                if summary.rows == 0 { return };
+                let (unmatched, unmapped) = (&summary.unmatched, summary.unmapped);
+                ui.horizontal(|ui| {
+                    ui.label(format!("{} rows", summary.rows));
+                    if ui.button("Select Mapped").on_hover_text("Selects exactly the listed items the mapping names.").clicked() {
+                        let pairs = file_processing::parse_mapping(&mapping.table).unwrap_or_default();
+                        let list_folders = gui.options.file_selection.list_folders;
+                        for folder in gui.file_selector.folders.iter_mut() {
+                            for (index, item) in folder.list_files.iter().enumerate() {
+                                folder.selected_files[index] = pairs.iter().any(|(old, _)| *old == item.name);
+                            }
+                            for (index, item) in folder.list_folders.iter().enumerate() {
+                                folder.selected_folders[index] = list_folders && pairs.iter().any(|(old, _)| *old == item.name);
+                            }
+                        }
+                    };
+                });
+                if !unmatched.is_empty() {
+                    let shown: Vec<&str> = unmatched.iter().take(5).map(|old| old.as_str()).collect();
+                    let more = match unmatched.len() > 5 {
+                        true => format!(" and {} more", unmatched.len() - 5),
+                        false => String::new()
+                    };
+                    ui.colored_label(egui::Color32::YELLOW, format!("{} row(s) match nothing: {}{}", unmatched.len(), shown.join(", "), more));
+                };
+                if unmapped != 0 {
+                    ui.colored_label(egui::Color32::YELLOW, format!("{} selected item(s) aren't in the mapping and keep their name.", unmapped));
+                };
+            },
+            Err(error) => {
+                ui.colored_label(egui::Color32::RED, error);
+            }
+        }
+    });
+    // Fill modifications
+    {
+        if !mapping.table.trim().is_empty() { modifications += 1 };
+    }
+    return modifications;
+}
+
+fn fill_modnamelist(gui: &mut WindowMain, ui: &mut egui::Ui, name_list: &mut ModNameList) -> u32 {
+    let mut modifications: u32 = 0;
+    ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Crop");
-            egui::ComboBox::new(format!("remove_crop-{}", index), "")
-            .selected_text(remove.crop_name.to_owned())
-            .show_ui(ui, |ui| {
-                if ui.selectable_label(false, "None").clicked() {
-                    remove.crop_name = String::from("None");
-                    remove.crop = RemoveCropMode::None
-                }
-                if ui.selectable_label(false, "Before").clicked() {
-                    remove.crop_name = String::from("Before");
-                    remove.crop = RemoveCropMode::Before
-                }
-                if ui.selectable_label(false, "After").clicked() {
-                    remove.crop_name = String::from("After");
-                    remove.crop = RemoveCropMode::After
+            ui.label("File");
+            if ui.button("Load").on_hover_text("Replaces the list below with the file's lines.").clicked() {
+                match std::fs::read_to_string(&name_list.file_path) {
+                    Ok(text) => {
+                        name_list.table = text;
+                        name_list.load_error = String::new();
+                    },
+                    Err(error) => {
+                        name_list.load_error = format!("Couldn't load {}: {}", name_list.file_path, error);
+                    }
                 }
-            });
-            ui.add_enabled_ui(remove.crop_enabled, |ui| {
+            };
+            ui.add_sized(
+                egui::vec2(ui.available_width(), ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut name_list.file_path)
+                .hint_text("/path/to/tracklist.txt")
+            );
+        });
+        if !name_list.load_error.is_empty() {
+            ui.colored_label(egui::Color32::RED, name_list.load_error.to_owned());
+        };
+        egui::ScrollArea::vertical()
+            .id_salt("name-list-table")
+            .max_height(120.0)
+            .show(ui, |ui| {
                 ui.add_sized(
-                    egui::vec2(ui.available_width(), ui.available_height()), 
-                    egui::text_edit::TextEdit::singleline(&mut remove.crop_match)
-                    .char_limit(255)
+                    egui::vec2(ui.available_width(), 0.0),
+                    egui::text_edit::TextEdit::multiline(&mut name_list.table)
+                    .desired_rows(4)
+                    .hint_text("One name per line, given out in selection order")
                 );
             });
-        });
         ui.horizontal(|ui| {
-            ui.label("Digits");
-            ui.checkbox(&mut remove.digits, "");
-
-            ui.label("Dbl. Spaces");
-            ui.checkbox(&mut remove.double_spaces, "");
-
-            ui.label("Lead ..");
-            ui.checkbox(&mut remove.leading_dots, "");
+            ui.label("Keep Extension");
+            ui.checkbox(&mut name_list.keep_extension, "");
 
-            ui.label("Symb.");
-            ui.checkbox(&mut remove.symbols, "");
+            ui.label("Strip Numbering");
+            ui.checkbox(&mut name_list.strip_numbering, "")
+                .on_hover_text("Drops leading numbers like 01. / 1 - / [03]");
 
-            ui.label("Trim");
-            ui.checkbox(&mut remove.trim, "");
+            ui.label("Trim Whitespace");
+            ui.checkbox(&mut name_list.trim_whitespace, "");
         });
+        let names_total = file_processing::name_list_names(name_list).len();
+        if names_total != 0 {
+            let selected_total = gui.file_selected_total as usize;
+            if names_total == selected_total {
+                ui.label(format!("{} names for {} selected items.", names_total, selected_total));
+            } else if names_total < selected_total {
+                ui.colored_label(egui::Color32::YELLOW, format!("{} names for {} selected items, the last {} keep their name.", 
+                    names_total, selected_total, selected_total - names_total));
+            } else {
+                ui.colored_label(egui::Color32::YELLOW, format!("{} names for {} selected items, the last {} names go unused.", 
+                    names_total, selected_total, names_total - selected_total));
+            };
+        };
     });
-    match remove.crop {
-        RemoveCropMode::None => {
-            remove.crop_enabled = false;
-        },
-        _ => {
-            remove.crop_enabled = true;
-        }
-    }
     // Fill modifications
     {
-        if remove.digits == true { modifications += 1 };
-        if remove.double_spaces == true { modifications += 1 };
-        if remove.leading_dots == true { modifications += 1 };
-        if remove.symbols == true { modifications += 1 };
-        if remove.trim == true { modifications += 1 };
-        if remove.crop_match.chars().count() >= 1 { modifications += 1};
-        if remove.crop != RemoveCropMode::None { modifications += 1 };
-        if remove.chars_comma_seperated.chars().count() >= 1 { modifications += 1 };
-        if remove.words_comma_seperated.chars().count() >= 1 { modifications += 1 };
-        if remove.to_y >= 1 { modifications += 1 };
-        if remove.from_x >= 1 { modifications += 1 };
-        if remove.first_n >= 1 { modifications += 1 };
-        if remove.last_n >= 1 { modifications += 1 };
+        if !name_list.table.trim().is_empty() { modifications += 1 };
+        if name_list.keep_extension == false { modifications += 1 };
+        if name_list.strip_numbering == true { modifications += 1 };
+        if name_list.trim_whitespace == false { modifications += 1 };
     }
-    return (remove.to_owned(), modifications);
+    return modifications;
 }
 
-fn fill_modreplace(_gui: &mut WindowMain, ui: &mut egui::Ui, replace: &mut ModReplace, _index: usize) -> (ModReplace, u32) {
+fn fill_modsegment(gui: &mut WindowMain, ui: &mut egui::Ui, segment: &mut ModSegment, _index: usize) -> (ModSegment, u32) {
     let mut modifications: u32 = 0;
     ui.vertical(|ui| {
         ui.horizontal(|ui| {
-            ui.label("Match");
+            ui.label("Split");
             ui.add_sized(
-                egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut replace.replace_match)
+                egui::vec2(ui.available_width() - 90.0, ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut segment.delimiter)
             );
+            ui.label("Regex");
+            ui.checkbox(&mut segment.delimiter_regex, "");
         });
         ui.horizontal(|ui| {
-            ui.label("Replace");
+            ui.label("Order");
+            ui.add_sized(
+                egui::vec2(ui.available_width() / 2.0, ui.available_height()), 
+                egui::text_edit::TextEdit::singleline(&mut segment.order)
+                .hint_text("2,0,1")
+            ).on_hover_text("Segment numbers to rebuild the name from, starting at 0. Unlisted segments are dropped.");
+            ui.label("Join");
             ui.add_sized(
                 egui::vec2(ui.available_width(), ui.available_height()), 
-                egui::text_edit::TextEdit::singleline(&mut replace.replace_with)
+                egui::text_edit::TextEdit::singleline(&mut segment.joiner)
+                .hint_text("Delimiter")
             );
         });
         ui.horizontal(|ui| {
-            ui.label("First");
-            ui.checkbox(&mut replace.first_occurance, "");
+            ui.label("Trim");
+            ui.checkbox(&mut segment.trim, "");
         });
+        if let Err(error) = file_processing::segment_order(&segment.order) {
+            ui.colored_label(egui::Color32::RED, error);
+        };
+        // Show how the last clicked name splits up.
+        tester_refresh(gui, ui.ctx());
+        if let Some((name, is_folder)) = gui.tester_cache.names.first() {
+            let (file, _) = file_processing::split_extension(name, *is_folder);
+            match file_processing::segment_split(&file, segment) {
+                Ok((segments, _)) => {
+                    let indexed: Vec<String> = segments.iter().enumerate().map(|(index, segment)| format!("{}: {}", index, segment)).collect();
+                    ui.label(indexed.join("   "));
+                },
+                Err(error) => {
+                    ui.colored_label(egui::Color32::RED, error);
+                }
+            };
+        };
     });
     // Fill modifications
     {
-        if replace.first_occurance == true { modifications += 1 };
-        if replace.replace_match.chars().count() >= 1 { modifications += 1};
-        if replace.replace_with.chars().count() >= 1 { modifications += 1};
+        if segment.order.chars().count() >= 1 { modifications += 1 };
+        if segment.joiner.chars().count() >= 1 { modifications += 1 };
+        if segment.delimiter_regex == true { modifications += 1 };
     }
-    return (replace.to_owned(), modifications);
-}
\ No newline at end of file
+    return (segment.to_owned(), modifications);
+}
+
+/// What the testers show, worked out again when a pattern or the selection changes rather than on every repaint.
+#[derive(Default)]
+pub struct TesterCache {
+    /// Pass the selection was last fingerprinted in.
+    pass: Option<u64>,
+    /// Fingerprint of every listed name, what's selected and what was clicked last.
+    selection: u64,
+    /// (Name, Is Folder) of everything selected, with the last clicked item first.
+    pub names: Vec<(String, bool)>,
+    /// Tester id to the settings its preview was made with and the preview.
+    previews: HashMap<String, (String, Result<Option<file_processing::MatchPreview>, String>)>,
+    /// Table the mapping summary was made from and the summary.
+    mapping: Option<(String, Result<MappingSummary, String>)>
+}
+
+#[derive(Clone)]
+struct MappingSummary {
+    rows: usize,
+    /// Rows whose old name isn't listed in the selector.
+    unmatched: Vec<String>,
+    /// Selected items no row names.
+    unmapped: usize
+}
+
+/// Fingerprints the selector once a pass, and drops everything cached when it's changed.
+fn tester_refresh(gui: &mut WindowMain, ctx: &egui::Context) {
+    let pass = ctx.cumulative_pass_nr();
+    if gui.tester_cache.pass == Some(pass) {
+        return;
+    };
+    let mut hasher = DefaultHasher::new();
+    let selector = &gui.file_selector;
+    match selector.last_selected_type {
+        SelectedType::Folder => 1,
+        SelectedType::File => 2,
+        _ => 0
+    }.hash(&mut hasher);
+    selector.last_selected_folder.hash(&mut hasher);
+    selector.last_selected_file.hash(&mut hasher);
+    for folder in selector.folders.iter() {
+        folder.path.hash(&mut hasher);
+        folder.selected_folders.hash(&mut hasher);
+        folder.selected_files.hash(&mut hasher);
+        for item in folder.list_folders.iter().chain(folder.list_files.iter()) {
+            item.name.hash(&mut hasher);
+        }
+    }
+    let selection = hasher.finish();
+    if gui.tester_cache.pass.is_none() || gui.tester_cache.selection != selection {
+        gui.tester_cache.names = tester_names(gui);
+        gui.tester_cache.previews.clear();
+        gui.tester_cache.mapping = None;
+        gui.tester_cache.selection = selection;
+    };
+    gui.tester_cache.pass = Some(pass);
+}
+
+/// The tester's preview, only run again over the selection when `settings` or the selection changes.
+fn tester_preview<F>(gui: &mut WindowMain, ctx: &egui::Context, id: String, settings: String, preview: F) -> Result<Option<file_processing::MatchPreview>, String> 
+where F: FnOnce(&Vec<(String, bool)>) -> Result<Option<file_processing::MatchPreview>, String> {
+    tester_refresh(gui, ctx);
+    let cache = &mut gui.tester_cache;
+    if let Some((cached, cached_preview)) = cache.previews.get(&id) {
+        if *cached == settings {
+            return cached_preview.to_owned();
+        };
+    };
+    let fresh = preview(&cache.names);
+    cache.previews.insert(id, (settings, fresh.to_owned()));
+    fresh
+}
+
+/// The mapping panel's row counts, only worked out again when the table or the selection changes.
+fn mapping_summary(gui: &mut WindowMain, ctx: &egui::Context, table: &str) -> Result<MappingSummary, String> {
+    tester_refresh(gui, ctx);
+    if let Some((cached, summary)) = &gui.tester_cache.mapping {
+        if cached == table {
+            return summary.to_owned();
+        };
+    };
+    let summary = file_processing::parse_mapping(table).map(|pairs| {
+        // Rows are matched against everything listed in the selector, not just what's selected.
+        let mut listed: HashSet<&String> = HashSet::new();
+        for folder in gui.file_selector.folders.iter() {
+            listed.extend(folder.list_files.iter().map(|item| &item.name));
+            listed.extend(folder.list_folders.iter().map(|item| &item.name));
+        }
+        let mapped: HashSet<&String> = pairs.iter().map(|(old, _)| old).collect();
+        MappingSummary {
+            rows: pairs.len(),
+            unmatched: pairs.iter().map(|(old, _)| old).filter(|old| !listed.contains(old)).cloned().collect(),
+            unmapped: gui.tester_cache.names.iter().filter(|(name, _)| !mapped.contains(name)).count()
+        }
+    });
+    gui.tester_cache.mapping = Some((table.to_string(), summary.to_owned()));
+    summary
+}
+
+/// (Name, Is Folder) of everything selected, with the last clicked item first since that's what the tester shows.
+fn tester_names(gui: &WindowMain) -> Vec<(String, bool)> {
+    let mut names: Vec<(String, bool)> = vec![];
+    let mut current: Option<(String, bool)> = None;
+    for (folder_index, folder) in gui.file_selector.folders.iter().enumerate() {
+        for (index, item) in folder.list_folders.iter().enumerate() {
+            if folder.selected_folders.get(index) == Some(&true) {
+                if gui.file_selector.last_selected_type == SelectedType::Folder && gui.file_selector.last_selected_folder.get(folder_index) == Some(&index) {
+                    current = Some((item.name.to_owned(), true));
+                };
+                names.push((item.name.to_owned(), true));
+            };
+        }
+        for (index, item) in folder.list_files.iter().enumerate() {
+            if folder.selected_files.get(index) == Some(&true) {
+                if gui.file_selector.last_selected_type == SelectedType::File && gui.file_selector.last_selected_file.get(folder_index) == Some(&index) {
+                    current = Some((item.name.to_owned(), false));
+                };
+                names.push((item.name.to_owned(), false));
+            };
+        }
+    }
+    if let Some(current) = current {
+        if let Some(position) = names.iter().position(|name| *name == current) {
+            names.remove(position);
+            names.insert(0, current);
+        };
+    };
+    names
+}
+
+fn fill_tester(ui: &mut egui::Ui, id: String, preview: Result<Option<file_processing::MatchPreview>, String>, names_total: usize) {
+    if let Ok(Some(preview)) = &preview {
+        if preview.names_matched == 0 {
+            ui.colored_label(egui::Color32::YELLOW, format!("Matches none of the {} selected names.", names_total));
+        };
+    };
+    egui::CollapsingHeader::new("Tester")
+        .id_salt(id)
+        .show(ui, |ui| {
+            match preview {
+                Ok(Some(preview)) => {
+                    let format = egui::TextFormat {
+                        font_id: egui::TextStyle::Body.resolve(ui.style()),
+                        color: ui.visuals().text_color(),
+                        ..Default::default()
+                    };
+                    let format_match = egui::TextFormat {
+                        background: egui::Color32::from_rgb(0x28, 0x35, 0x93),
+                        color: egui::Color32::WHITE,
+                        ..format.clone()
+                    };
+                    let mut highlighted = egui::text::LayoutJob::default();
+                    let mut last: usize = 0;
+                    for (start, end) in preview.spans.iter() {
+                        highlighted.append(&preview.text[last..*start], 0.0, format.clone());
+                        highlighted.append(&preview.text[*start..*end], 0.0, format_match.clone());
+                        last = *end;
+                    }
+                    highlighted.append(&preview.text[last..], 0.0, format.clone());
+                    ui.horizontal(|ui| {
+                        ui.label("Match");
+                        ui.label(highlighted);
+                    });
+                    for (group, value) in preview.captures.iter() {
+                        ui.label(format!("  ${} = {}", group, value));
+                    }
+                    ui.horizontal(|ui| {
+                        ui.label("Result");
+                        ui.label(preview.result);
+                    });
+                },
+                Ok(None) => {
+                    if names_total == 0 {
+                        ui.label("Select a file to test against.");
+                    } else {
+                        ui.label("Enter a pattern to test.");
+                    };
+                },
+                Err(_) => {} // Already shown under the pattern.
+            }
+        });
+}
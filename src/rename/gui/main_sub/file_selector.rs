@@ -1,21 +1,31 @@
 use super::super::super::app::WindowMain;
-use super::super::super::util::dir::{Folder, get_folder};
+use super::super::super::util::dir::{Folder, FolderOrder, Sequence, SortMode, get_folder, sort_folder, detect_sequences, 
+    sort_keys_read, merge_sort_keys, folder_order, restore_order};
+use super::super::super::util::threads;
 use super::super::super::debug::DebugStatType;
 
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 
-pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
+pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, ctx: &egui::Context) {
     ui.add_enabled_ui(gui.section_selector_enabled, |ui| {
         let start = Instant::now();
         // Update Files if necessary. 
         if gui.file_browser.selected_folders != gui.file_selector.previously_selected_folders {
             gui.file_selector.previously_selected_folders = gui.file_browser.selected_folders.to_owned(); // Copy new paths.
+            gui.file_selector.remember_orders();
             gui.file_selector.folders.clear(); // Clean local folders.
             gui.file_selector.last_selected_folder.clear();
             gui.file_selector.last_selected_file.clear();
             for path in gui.file_browser.selected_folders.to_owned() {
                 match get_folder(path, false) {
-                    Ok(folder) => { 
+                    Ok(mut folder) => { 
+                        if gui.options.file_selection.sort == SortMode::Manual {
+                            if let Some(order) = gui.file_selector.orders.get(&folder.path) {
+                                restore_order(&mut folder, order);
+                            };
+                        };
                         gui.file_selector.folders.push(folder); 
                         gui.file_selector.last_selected_folder.push(0);
                         gui.file_selector.last_selected_file.push(0);
@@ -32,7 +42,18 @@ pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
                     }
                 };
             };
+            sort_folders(gui, ctx);
         }; 
+        // Sort the folders whose keys were read in the background.
+        let read: Vec<(SortMode, Folder)> = gui.file_selector.sort_keys.lock().unwrap().drain(..).collect();
+        for (mode, read) in read {
+            if let Some(folder) = gui.file_selector.folders.iter_mut().find(|folder| folder.path == read.path) {
+                merge_sort_keys(folder, &read);
+                if mode == gui.options.file_selection.sort {
+                    sort_folder(folder, &mode, gui.options.file_selection.sort_descending);
+                };
+            };
+        }
         
         // Gui
         ui.vertical(|ui| {
@@ -49,6 +70,32 @@ pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
                         };
                     }
                     ui.strong("File Selection");
+                    ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                        let sort_before = (gui.options.file_selection.sort.clone(), gui.options.file_selection.sort_descending);
+                        let descending_text = match gui.options.file_selection.sort_descending {
+                            true => "⬆",
+                            false => "⬇"
+                        };
+                        ui.add_enabled_ui(gui.options.file_selection.sort != SortMode::Manual, |ui| {
+                            ui.toggle_value(&mut gui.options.file_selection.sort_descending, descending_text)
+                            .on_hover_text("Descending");
+                        });
+                        egui::ComboBox::new("selector_sort", "")
+                        .selected_text(sort_name(&gui.options.file_selection.sort))
+                        .show_ui(ui, |ui| {
+                            for mode in [SortMode::Name, SortMode::Natural, SortMode::Modified, SortMode::Created, SortMode::Size, SortMode::Extension, SortMode::Manual] {
+                                if ui.selectable_label(false, sort_name(&mode)).clicked() {
+                                    gui.options.file_selection.sort = mode;
+                                }
+                            }
+                        });
+                        ui.label("Sort");
+                        ui.toggle_value(&mut gui.options.file_selection.collapse_sequences, "🎞")
+                        .on_hover_text("Collapse frame sequences");
+                        if sort_before != (gui.options.file_selection.sort.clone(), gui.options.file_selection.sort_descending) {
+                            sort_folders(gui, ctx);
+                        };
+                    });
                 });
                 ui.separator();
                 let space = ui.add_enabled_ui(true, |ui| {
@@ -67,6 +114,9 @@ pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
                             gui.file_selector.folders[index].selected_folders = tables[0].clone();
                             gui.file_selector.folders[index].selected_files = tables[1].clone();
                         }
+                        if let Some(manual_move) = gui.file_selector.manual_move.take() {
+                            move_item(&mut gui.file_selector.folders[manual_move.0], manual_move.1, manual_move.2, manual_move.3);
+                        };
                     });
                 });
                 if space.response.hovered() && gui.input_state.is_some() {
@@ -104,6 +154,63 @@ pub fn selector(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
     });
 }
 
+fn sort_name(mode: &SortMode) -> &'static str {
+    match mode {
+        SortMode::Name => "Name",
+        SortMode::Natural => "Natural",
+        SortMode::Modified => "Modified",
+        SortMode::Created => "Created / Taken",
+        SortMode::Size => "Size",
+        SortMode::Extension => "Extension",
+        SortMode::Manual => "Manual"
+    }
+}
+
+/// Sorts the folders whose keys are known now, the rest are read in the background and sorted once they're in.
+fn sort_folders(gui: &mut WindowMain, ctx: &egui::Context) {
+    let mode = gui.options.file_selection.sort.clone();
+    let mut unread: Vec<Folder> = vec![];
+    for folder in gui.file_selector.folders.iter_mut() {
+        if sort_keys_read(folder, &mode) {
+            sort_folder(folder, &mode, gui.options.file_selection.sort_descending);
+        } else {
+            unread.push(folder.to_owned());
+        };
+    }
+    if !unread.is_empty() {
+        threads::read_sort_keys(unread, mode, Arc::clone(&gui.file_selector.sort_keys), ctx.to_owned());
+    };
+}
+
+/// Swaps an item with its neighbour for the manual order.
+fn move_item(folder: &mut Folder, is_folder: bool, index: usize, up: bool) {
+    let (list, selected) = match is_folder {
+        true => (&mut folder.list_folders, &mut folder.selected_folders),
+        false => (&mut folder.list_files, &mut folder.selected_files)
+    };
+    let other = match up {
+        true => index.checked_sub(1),
+        false => Some(index + 1)
+    };
+    if let Some(other) = other.filter(|other| *other < list.len()) {
+        list.swap(index, other);
+        selected.swap(index, other);
+        folder.sequences = detect_sequences(&folder.list_files.iter().map(|item| item.name.to_owned()).collect());
+    };
+}
+
+/// Up / down buttons for the manual order, returns the move asked for.
+fn manual_buttons(ui: &mut egui::Ui, index: usize, count: usize) -> Option<bool> {
+    let mut up: Option<bool> = None;
+    if ui.add_enabled(index != 0, egui::Button::new("⏶").small()).clicked() {
+        up = Some(true);
+    };
+    if ui.add_enabled(index + 1 != count, egui::Button::new("⏷").small()).clicked() {
+        up = Some(false);
+    };
+    up
+}
+
 fn fill_table(
     gui: &mut WindowMain, 
     body: &mut egui_extras::TableBody, 
@@ -140,6 +247,11 @@ fn fill_table(
             body.row(16.0, |mut ui| {
                 ui.col(|ui| {
                     ui.set_width(width_available);
+                    if gui.options.file_selection.sort == SortMode::Manual {
+                        if let Some(up) = manual_buttons(ui, index, folder.list_folders.len()) {
+                            gui.file_selector.manual_move = Some((folder_index, true, index, up));
+                        };
+                    };
                     let selected = ui.toggle_value(&mut selected_folders[index], format!("🗁 {}", item.name.to_owned())).clicked();
                     if gui.input_state.is_some() {
                         let state = gui.input_state.clone().unwrap();
@@ -180,6 +292,11 @@ fn fill_table(
                                 egui::Color32::RED, format!("---> {}", 
                                 item.name_modified.to_owned()))
                             .on_hover_text(item.error.to_owned());
+                        } else if !item.warning.is_empty() {
+                            ui.colored_label(
+                                egui::Color32::YELLOW, format!("---> {}", 
+                                item.name_modified.to_owned()))
+                            .on_hover_text(item.warning.to_owned());
                         } else {
                             ui.label(format!("---> {}", item.name_modified.to_owned()));
                         }
@@ -196,10 +313,32 @@ fn fill_table(
         };
     };
 
+    // Each collapsed sequence is drawn once, at its first file in the list.
+    let mut sequence_rows: Vec<Option<(usize, bool)>> = vec![None; folder.list_files.len()];
+    if gui.options.file_selection.collapse_sequences == true {
+        for (sequence_index, sequence) in folder.sequences.iter().enumerate() {
+            let first = sequence.frames.iter().map(|(_, index)| *index).min().unwrap_or_default();
+            for (_, index) in sequence.frames.iter() {
+                sequence_rows[*index] = Some((sequence_index, *index == first));
+            }
+        }
+    };
+
     for (index, file) in folder.list_files.iter().enumerate() {
+        if let Some((sequence_index, first)) = sequence_rows[index] {
+            if first == true {
+                fill_sequence(gui, body, folder, &folder.sequences[sequence_index], &mut selected_files, width_available);
+            };
+            continue;
+        };
         body.row(16.0, |mut ui| {
             ui.col(|ui| {
                 ui.set_width(width_available);
+                if gui.options.file_selection.sort == SortMode::Manual {
+                    if let Some(up) = manual_buttons(ui, index, folder.list_files.len()) {
+                        gui.file_selector.manual_move = Some((folder_index, false, index, up));
+                    };
+                };
                 let selected = ui.toggle_value(&mut selected_files[index], format!("{}", file.name.to_owned())).clicked();
                 if gui.input_state.is_some() {
                     let state = gui.input_state.clone().unwrap();
@@ -240,6 +379,11 @@ fn fill_table(
                             egui::Color32::RED, format!("---> {}", 
                             file.name_modified.to_owned()))
                         .on_hover_text(file.error.to_owned());
+                    } else if !file.warning.is_empty() {
+                        ui.colored_label(
+                            egui::Color32::YELLOW, format!("---> {}", 
+                            file.name_modified.to_owned()))
+                        .on_hover_text(file.warning.to_owned());
                     } else {
                         ui.label(format!("---> {}", file.name_modified.to_owned()));
                     }
@@ -258,6 +402,79 @@ fn fill_table(
     return vec![selected_folders, selected_files];
 }
 
+/// One row for a whole sequence, selecting it selects every frame.
+fn fill_sequence(
+    gui: &mut WindowMain, 
+    body: &mut egui_extras::TableBody, 
+    folder: &Folder, 
+    sequence: &Sequence, 
+    selected_files: &mut Vec<bool>, 
+    width_available: f32
+) {
+    let mut selected = sequence.frames.iter().all(|(_, index)| selected_files[*index]);
+    let gaps = sequence.gaps_text();
+    body.row(16.0, |mut ui| {
+        ui.col(|ui| {
+            ui.set_width(width_available);
+            let label = match gaps.is_empty() {
+                true => format!("🎞 {} ({} frames)", sequence.label(), sequence.frames.len()),
+                false => format!("🎞 {} ({} frames, missing {})", sequence.label(), sequence.frames.len(), gaps)
+            };
+            if ui.toggle_value(&mut selected, label).clicked() {
+                for (_, index) in sequence.frames.iter() {
+                    selected_files[*index] = selected;
+                }
+                gui.file_selector.last_selected_type = SelectedType::File;
+            };
+            ui.end_row();
+        });
+    });
+    let members: Vec<usize> = sequence.frames.iter()
+        .map(|(_, index)| *index)
+        .filter(|index| selected_files[*index])
+        .collect();
+    if members.is_empty() {
+        if gui.options.file_selection.always_show_extra_row == true {
+            body.row(16.0, |mut ui| {
+                ui.col(|ui| {
+                    ui.set_width(width_available);
+                    ui.label(format!("---> "));
+                });
+            });
+        };
+        return;
+    };
+    let first = &folder.list_files[members[0]];
+    let last = &folder.list_files[members[members.len() - 1]];
+    let preview = match members.len() {
+        1 => format!("---> {}", first.name_modified.to_owned()),
+        _ => format!("---> {} … {}", first.name_modified.to_owned(), last.name_modified.to_owned())
+    };
+    let errored: Vec<String> = members.iter()
+        .filter(|index| folder.list_files[**index].errored == true)
+        .map(|index| folder.list_files[*index].error.to_owned())
+        .collect();
+    let warnings: Vec<String> = members.iter()
+        .filter(|index| !folder.list_files[**index].warning.is_empty())
+        .map(|index| folder.list_files[*index].warning.to_owned())
+        .collect();
+    body.row(16.0, |mut ui| {
+        ui.col(|ui| {
+            ui.set_width(width_available);
+            if !errored.is_empty() {
+                gui.file_selector.total_errored += errored.len() as u32;
+                ui.colored_label(egui::Color32::RED, preview)
+                .on_hover_text(errored[0].to_owned());
+            } else if !warnings.is_empty() {
+                ui.colored_label(egui::Color32::YELLOW, preview)
+                .on_hover_text(warnings[0].to_owned());
+            } else {
+                ui.label(preview);
+            }
+        });
+    });
+}
+
 #[derive(Clone)]
 pub struct FileSelection {
     pub folders: Vec<Folder>,
@@ -268,7 +485,21 @@ pub struct FileSelection {
     pub selected_folder_paths: Vec<(String, usize, usize)>,
     pub selected_file_paths: Vec<(String, usize, usize)>,
     pub total_errored: u32,
-    pub allow_frame: bool
+    pub allow_frame: bool,
+    /// Folder, is a folder, item index and up, waiting for the table to finish drawing.
+    pub manual_move: Option<(usize, bool, usize, bool)>,
+    /// Folders with their sort keys read in the background, and the sort they were read for.
+    pub sort_keys: Arc<Mutex<Vec<(SortMode, Folder)>>>,
+    /// Each folder's order by path, so a reload keeps the manual order.
+    pub orders: HashMap<String, FolderOrder>
+}
+impl FileSelection {
+    /// Remembers how the folders are ordered before they're reloaded.
+    pub fn remember_orders(&mut self) {
+        for folder in self.folders.iter().filter(|folder| folder.errored.is_none()) {
+            self.orders.insert(folder.path.to_owned(), folder_order(folder));
+        }
+    }
 }
 
 impl Default for FileSelection {
@@ -282,7 +513,10 @@ impl Default for FileSelection {
             selected_folder_paths: vec![],
             selected_file_paths: vec![],
             total_errored: 0,
-            allow_frame: true
+            allow_frame: true,
+            manual_move: None,
+            sort_keys: Arc::new(Mutex::new(vec![])),
+            orders: HashMap::new()
         }
     }
 }
@@ -36,7 +36,8 @@ pub fn window(gui: &mut WindowMain, ui: &mut egui::Ui, _ctx: &egui::Context) {
                     if ui.button("Save").clicked() {
                         // Do some saving
                         gui.popups.save_confirmation = false;
-                        if gui.modifiers.hash_enable && gui.modifiers.hash.mode != threads::HashMode::None {
+                        let template_hash = gui.modifiers.template_enabled && gui.modifiers.template.iter().any(|t| t.template.contains("{hash}"));
+                        if (gui.modifiers.hash_enable && gui.modifiers.hash.mode != threads::HashMode::None) || template_hash {
                             gui.hash();
                         } else {
                             gui.save(None);
@@ -269,13 +269,19 @@ impl eframe::App for WindowMain {
             if self.reset_processing == true {
                 self.reset_processing = false;
                 //self.file_browser = structs::FileBrowser::default();
-                self.file_selector = file_selector::FileSelection::default();
+                // Keep the order the folders were shown in, the manual sort has nothing else to go on.
+                self.file_selector.remember_orders();
+                self.file_selector = file_selector::FileSelection {
+                    orders: std::mem::take(&mut self.file_selector.orders),
+                    ..Default::default()
+                };
                 self.modifier_thread_storage = ModifierThreadStorage {
                     kill_sig_string_processor: Arc::new(Mutex::new(false)),
                     modifiers: Arc::new(Mutex::new(None)),
                     modifier_order: Arc::new(Mutex::new(None)),
                     eddited_files: Arc::new(Mutex::new(None)),
                     raw_files: Arc::new(Mutex::new(None)),
+                    listings: Arc::new(Mutex::new(None)),
                     errors: Arc::new(Mutex::new(None)),
                     state: Arc::new(Mutex::new(ThreadState::None)),
                     thread_calc_time: Arc::new(Mutex::new(0))
@@ -350,6 +356,7 @@ impl eframe::App for WindowMain {
     
             let files = Arc::clone(&self.modifier_thread_storage.raw_files);
             if files.lock().unwrap().is_none() {
+                self.modifier_thread_storage.listings.lock().unwrap().replace(self.create_listings());
                 files.lock().unwrap().replace(self.create_selected_vec());
             };
     
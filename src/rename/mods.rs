@@ -1,32 +1,58 @@
 use serde::{Deserialize, Serialize};
 use super::util::threads::{HashMode, HashType, Endianness};
+use super::util::random;
 
 // Modifiers
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Modifiers {
     pub add: Vec<ModAdd>,
     pub case: Vec<ModCase>,
     pub date: Vec<ModDate>,
+    pub dictionary: ModDictionary,
     pub extension: ModExtension,
     pub hash: ModHashing,
+    pub mapping: ModMapping,
     pub movecopy: Vec<ModMoveCopy>,
+    pub normalize: ModNormalize,
     pub name: Vec<ModName>,
+    pub name_list: ModNameList,
     pub number: Vec<ModNumber>,
+    pub random: ModRandom,
     pub regex: Vec<ModRegex>,
     pub remove: Vec<ModRemove>,
+    pub renumber: ModRenumber,
     pub replace: Vec<ModReplace>,
+    pub segment: Vec<ModSegment>,
+    pub seperators: ModSeperators,
+    pub sequence: ModSequence,
+    pub template: Vec<ModTemplate>,
+    pub transliterate: ModTransliterate,
+    pub truncate: ModTruncate,
     pub add_enabled: bool,
     pub append_folder_enabled: bool,
     pub case_enabled: bool,
     pub date_enabled: bool,
+    pub dictionary_enabled: bool,
     pub extension_enabled: bool,
     pub hash_enable: bool,
+    pub mapping_enabled: bool,
     pub movecopy_enabled: bool,
+    pub normalize_enabled: bool,
     pub name_enabled: bool,
+    pub name_list_enabled: bool,
     pub number_enabled: bool,
+    pub random_enabled: bool,
     pub regex_enabled: bool,
     pub remove_enabled: bool,
+    pub renumber_enabled: bool,
     pub replace_enabled: bool,
+    pub segment_enabled: bool,
+    pub seperators_enabled: bool,
+    pub sequence_enabled: bool,
+    pub template_enabled: bool,
+    pub transliterate_enabled: bool,
+    pub truncate_enabled: bool,
     pub allow_frame: bool,
     pub scroll_allowed: bool,
     pub drag_box_hovered: bool
@@ -45,14 +71,51 @@ pub enum ModsOrder {
     Number,
     Regex,
     Remove,
-    Replace
+    Replace,
+    Template,
+    Transliterate,
+    Normalize,
+    Dictionary,
+    Mapping,
+    NameList,
+    Segment,
+    Renumber,
+    Sequence,
+    Random,
+    Truncate,
+    Seperators
 }
 
 impl ModsOrder {
     pub fn iterate_over_oneness() -> impl Iterator<Item = ModsOrder> {
         [ModsOrder::Add, ModsOrder::Case, ModsOrder::Date, ModsOrder::Ext, 
             ModsOrder::Hash, ModsOrder::MoveCopy, ModsOrder::Name, ModsOrder::Number, 
-            ModsOrder::Regex, ModsOrder::Remove, ModsOrder::Replace].iter().copied()
+            ModsOrder::Regex, ModsOrder::Remove, ModsOrder::Replace, ModsOrder::Template, 
+            ModsOrder::Transliterate, ModsOrder::Normalize, ModsOrder::Dictionary, 
+            ModsOrder::Mapping, ModsOrder::NameList, ModsOrder::Segment, 
+            ModsOrder::Renumber, ModsOrder::Sequence, ModsOrder::Random, 
+            ModsOrder::Truncate, ModsOrder::Seperators].iter().copied()
+    }
+
+    /// Appends any varients missing from an order saved by an older version, so new modifiers still show up.
+    pub fn append_missing(order: &mut Vec<ModsOrder>) {
+        for varient in ModsOrder::iterate_over_oneness() {
+            if !order.contains(&varient) {
+                order.push(varient);
+            };
+        };
+    }
+}
+impl Modifiers {
+    /// Moves settings saved by older versions over to the fields that replaced them.
+    pub fn migrate(&mut self) {
+        for replace in self.replace.iter_mut() {
+            if replace.first_occurance {
+                replace.first_occurance = false;
+                replace.occurrence = ReplaceOccurrence::First;
+                replace.occurrence_name = String::from("First");
+            };
+        }
     }
 }
 impl Default for Modifiers {
@@ -61,26 +124,50 @@ impl Default for Modifiers {
             add: vec![ModAdd::default()],
             case: vec![ModCase::default()],
             date: vec![ModDate::default()],
+            dictionary: ModDictionary::default(),
             extension: ModExtension::default(),
             hash: ModHashing::default(),
+            mapping: ModMapping::default(),
             movecopy: vec![ModMoveCopy::default()],
+            normalize: ModNormalize::default(),
             name: vec![ModName::default()],
+            name_list: ModNameList::default(),
             number: vec![ModNumber::default()],
+            random: ModRandom::default(),
             regex: vec![ModRegex::default()],
             remove: vec![ModRemove::default()],
+            renumber: ModRenumber::default(),
             replace: vec![ModReplace::default()],
+            segment: vec![ModSegment::default()],
+            seperators: ModSeperators::default(),
+            sequence: ModSequence::default(),
+            template: vec![ModTemplate::default()],
+            transliterate: ModTransliterate::default(),
+            truncate: ModTruncate::default(),
             add_enabled: true,
             append_folder_enabled: true,
             case_enabled: true,
             date_enabled: true,
+            dictionary_enabled: true,
             extension_enabled: true,
             hash_enable: true,
+            mapping_enabled: true,
             movecopy_enabled: true,
+            normalize_enabled: true,
             name_enabled: true,
+            name_list_enabled: true,
             number_enabled: true,
+            random_enabled: true,
             regex_enabled: true,
             remove_enabled: true,
+            renumber_enabled: true,
             replace_enabled: true,
+            segment_enabled: true,
+            seperators_enabled: true,
+            sequence_enabled: true,
+            template_enabled: true,
+            transliterate_enabled: true,
+            truncate_enabled: true,
             allow_frame: true,
             scroll_allowed: true,
             drag_box_hovered: false
@@ -94,6 +181,8 @@ pub struct ModAdd {
     pub prefix: String,
     pub insert: String,
     pub insert_at: i32,
+    #[serde(default)]
+    pub anchor: InsertAnchor,
     pub suffix: String,
     pub seperator: char,
     pub seperator_enabled: bool,
@@ -104,6 +193,7 @@ impl Default for ModAdd {
             prefix: String::new(),
             insert: String::new(),
             insert_at: 0,
+            anchor: InsertAnchor::default(),
             suffix: String::new(),
             seperator: ' ',
             seperator_enabled: false
@@ -111,8 +201,49 @@ impl Default for ModAdd {
     }
 }
 
+// Insert anchor, shared by the modifiers that insert at a position.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct InsertAnchor {
+    pub mode: AnchorMode,
+    pub mode_name: String,
+    pub pattern: String,
+    pub regex: bool,
+    /// What happens when the pattern isn't in the name.
+    pub fallback: AnchorFallback,
+    pub fallback_name: String
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AnchorMode {
+    Position,
+    BeforeFirst,
+    AfterFirst,
+    BeforeLast,
+    AfterLast
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AnchorFallback {
+    Skip,
+    Position,
+    Start,
+    End
+}
+impl Default for InsertAnchor {
+    fn default() -> Self {
+        Self {
+            mode: AnchorMode::Position,
+            mode_name: String::from("Position"),
+            pattern: String::new(),
+            regex: false,
+            fallback: AnchorFallback::Skip,
+            fallback_name: String::from("Skip")
+        }
+    }
+}
+
 // Case
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModCase{
     pub mode: CaseMode,
     pub widgets_enabled: bool,
@@ -122,7 +253,13 @@ pub struct ModCase{
     pub except_enabled: bool,
     pub except_mode_name: String,
     pub except_from: u32,
-    pub except_to: u32
+    pub except_to: u32,
+    pub title_language: TitleLanguage,
+    pub title_language_name: String,
+    /// Extra words kept lowercase in Title mode, seperated by spaces or commas.
+    pub small_words: String,
+    /// Words (acronyms, brands) left exactly as written by every mode, seperated by spaces or commas.
+    pub protected_words: String
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum CaseMode {
@@ -130,7 +267,25 @@ pub enum CaseMode {
     Upper,
     Lower,
     Title,
-    UpperFirst
+    UpperFirst,
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    Constant,
+    Sentence
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TitleLanguage {
+    None,
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -150,7 +305,11 @@ impl Default for ModCase {
             except_enabled: false,
             except_mode_name: String::from("None"),
             except_from: 0,
-            except_to: 0
+            except_to: 0,
+            title_language: TitleLanguage::English,
+            title_language_name: String::from("English"),
+            small_words: String::new(),
+            protected_words: String::new()
         }
     }
 }
@@ -165,6 +324,8 @@ pub struct ModDate {
     pub format_name: String,
     pub at_enabled: bool,
     pub at_pos: i32,
+    #[serde(default)]
+    pub anchor: InsertAnchor,
     pub seperator: DateSeperator,
     pub seperator_name: String,
     pub seperator_enabled: bool,
@@ -231,6 +392,7 @@ impl Default for ModDate {
             format_name: String::from("YMDHM"),
             at_enabled: false,
             at_pos: 0,
+            anchor: InsertAnchor::default(),
             seperator: DateSeperator::Bracket,
             seperator_name: String::from("Bracket []"),
             seperator_enabled: false,
@@ -288,7 +450,11 @@ pub struct ModHashing {
     pub algorithm_name: String,
     pub endianness: Endianness,
     pub seperator: HashSeperator,
-    pub seperator_name: String
+    pub seperator_name: String,
+    #[serde(default)]
+    pub insert_at: i32,
+    #[serde(default)]
+    pub anchor: InsertAnchor
 }
 impl Default for ModHashing {
     fn default() -> Self {
@@ -300,7 +466,9 @@ impl Default for ModHashing {
             algorithm_name: String::from("CRC32"),
             endianness: Endianness::BigEndian,
             seperator: HashSeperator::Bracket,
-            seperator_name: String::from("Bracket []")
+            seperator_name: String::from("Bracket []"),
+            insert_at: 0,
+            anchor: InsertAnchor::default()
         }
     }
 }
@@ -323,6 +491,7 @@ pub enum HashSeperator {
 
 // Move / Copy
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModMoveCopy {
     pub mode_from: MoveCopyFromMode,
     pub mode_from_name: String,
@@ -333,7 +502,22 @@ pub struct ModMoveCopy {
     pub widgets_enabled_two: bool,
     pub mode_to_pos: u32,
     pub seperator: char,
-    pub seperator_enabled: bool
+    pub seperator_enabled: bool,
+    /// What letters_count counts, and what First / Last pick when it's Regex.
+    pub unit: MoveCopyUnit,
+    pub unit_name: String,
+    /// Characters that seperate words for the Words unit.
+    pub word_delimiters: String,
+    pub unit_regex: String,
+    /// Marker for the Before / After Match destinations.
+    pub mode_to_match: String,
+    pub mode_to_regex: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MoveCopyUnit {
+    Letters,
+    Words,
+    Regex
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum MoveCopyFromMode {
@@ -348,7 +532,9 @@ pub enum MoveCopyToMode {
     None,
     ToStart,
     ToEnd,
-    ToPos
+    ToPos,
+    BeforeMatch,
+    AfterMatch
 }
 impl Default for ModMoveCopy {
     fn default() -> Self {
@@ -363,6 +549,12 @@ impl Default for ModMoveCopy {
             mode_to_pos: 0,
             seperator: ' ',
             seperator_enabled: false,
+            unit: MoveCopyUnit::Letters,
+            unit_name: String::from("Letters"),
+            word_delimiters: String::from(" _-."),
+            unit_regex: String::new(),
+            mode_to_match: String::new(),
+            mode_to_regex: false
         }
     }
 }
@@ -396,19 +588,41 @@ impl Default for ModName {
 
 // Numbering
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModNumber {
     pub mode: NumberMode,
     pub mode_name: String,
     pub widgets_enabled: bool,
     pub insert_enabled: bool,
     pub insert_at: i32,
+    #[serde(default)]
+    pub anchor: InsertAnchor,
     pub starting_num: u32,
-    pub increment_num: u32,
+    /// Step between counts, negative counts down.
+    pub increment_num: i32,
     pub padding: u32,
     pub seperator: char,
     pub seperator_enabled: bool,
     pub mode_type: NumberTypeMode,
-    pub mode_type_name: String
+    pub mode_type_name: String,
+    /// Letters for the Custom type, counted like a - z.
+    pub alphabet: String,
+    /// Which items share a counter.
+    pub scope: NumberScope,
+    pub scope_name: String,
+    /// Key for the Group scope, the first capture group or else the whole match.
+    pub group_regex: String,
+    pub reverse: bool,
+    /// Pad to the widest number in the scope.
+    pub auto_padding: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NumberScope {
+    /// A folder's subfolders and its files each count from the start.
+    Folder,
+    Global,
+    Extension,
+    Group
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum NumberMode {
@@ -424,10 +638,19 @@ pub enum NumberTypeMode {
     BaseEight,
     BaseTen,
     BaseSixteen,
+    BaseSixteenUpper,
+    BaseThirtySix,
+    BaseSixtyTwo,
     RomanNumeral,
     AlphaLower,
     AlphaUpper,
-    AlphaLowerToUpper
+    AlphaLowerToUpper,
+    /// Counts with the letters in `alphabet`.
+    Custom,
+    /// Spelled out, "twenty-one".
+    Words,
+    /// Base 10 with its suffix, "21st".
+    Ordinal
 }
 impl Default for ModNumber {
     fn default() -> Self {
@@ -437,35 +660,72 @@ impl Default for ModNumber {
             widgets_enabled: false,
             insert_enabled: true,
             insert_at: 0,
+            anchor: InsertAnchor::default(),
             starting_num: 1,
             increment_num: 1,
             padding: 0,
             seperator: ' ',
             seperator_enabled: false,
             mode_type: NumberTypeMode::BaseTen,
-            mode_type_name: String::from("Base 10")
+            mode_type_name: String::from("Base 10"),
+            alphabet: String::new(),
+            scope: NumberScope::Folder,
+            scope_name: String::from("Per Folder"),
+            group_regex: String::new(),
+            reverse: false,
+            auto_padding: false
         }
     }
 }
 
 // Regex
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModRegex {
     pub replace_match: String,
-    pub replace_with: String
+    pub replace_with: String,
+    pub occurrence: RegexOccurrence,
+    pub occurrence_name: String,
+    /// Which match to replace when occurrence is Nth, starting at 1.
+    pub nth: u32,
+    pub scope: RegexScope,
+    pub scope_name: String,
+    pub case_insensitive: bool,
+    pub multi_line: bool,
+    pub unicode: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RegexOccurrence {
+    All,
+    Nth
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RegexScope {
+    Name,
+    Extension,
+    Full
 }
 
 impl Default for ModRegex {
     fn default() -> Self {
         Self {
             replace_match: String::new(),
-            replace_with: String::new()
+            replace_with: String::new(),
+            occurrence: RegexOccurrence::Nth,
+            occurrence_name: String::from("Nth"),
+            nth: 1,
+            scope: RegexScope::Name,
+            scope_name: String::from("Name"),
+            case_insensitive: false,
+            multi_line: false,
+            unicode: true
         }
     }
 }
 
 // Remove
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModRemove {
     pub first_n: u32,
     pub last_n: u32,
@@ -477,19 +737,52 @@ pub struct ModRemove {
     pub crop_name: String,
     pub crop_match: String,
     pub crop_enabled: bool,
-    pub crop_special: String,
+    /// Second marker for the Between modes, searched for after the first.
+    pub crop_match_end: String,
+    pub crop_occurrence: RemoveCropOccurrence,
+    pub crop_occurrence_name: String,
+    /// Which match of the first marker to crop at when occurrence is Nth, starting at 1.
+    pub crop_nth: u32,
+    /// Markers are regexes instead of literal text.
+    pub crop_regex: bool,
+    /// Between modes keep (Keep Between) or leave (Remove Between) the markers themselves.
+    pub crop_keep_markers: bool,
     pub digits: bool,
     pub trim: bool,
     pub double_spaces: bool,
     pub accented_chars: bool,
     pub symbols: bool,
-    pub leading_dots: bool
+    pub leading_dots: bool,
+    pub brackets_round: bool,
+    pub brackets_square: bool,
+    pub brackets_curly: bool,
+    pub brackets_angle: bool,
+    /// Extra open / close pairs, comma seperated, like `«»,「」`.
+    pub brackets_custom: String,
+    pub brackets_which: RemoveBracketsMode,
+    pub brackets_which_name: String,
+    /// Regex, segments whose contents match it are kept (like `^\d{4}$` for a year).
+    pub brackets_keep: String
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RemoveBracketsMode {
+    All,
+    First,
+    Last
 }
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum RemoveCropMode {
     None,
     Before,
-    After
+    After,
+    KeepBetween,
+    RemoveBetween
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RemoveCropOccurrence {
+    First,
+    Last,
+    Nth
 }
 impl Default for ModRemove {
     fn default() -> Self {
@@ -504,30 +797,431 @@ impl Default for ModRemove {
             crop_name: String::from("None"),
             crop_match: String::new(),
             crop_enabled: false,
-            crop_special: String::new(),
+            crop_match_end: String::new(),
+            crop_occurrence: RemoveCropOccurrence::First,
+            crop_occurrence_name: String::from("First"),
+            crop_nth: 1,
+            crop_regex: false,
+            crop_keep_markers: false,
             digits: false,
             trim: false,
             double_spaces: false,
             accented_chars: false,
             symbols: false,
-            leading_dots: false
+            leading_dots: false,
+            brackets_round: false,
+            brackets_square: false,
+            brackets_curly: false,
+            brackets_angle: false,
+            brackets_custom: String::new(),
+            brackets_which: RemoveBracketsMode::All,
+            brackets_which_name: String::from("All"),
+            brackets_keep: String::new()
         }
     }
 }
 
 // Replace
 #[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct ModReplace {
     pub replace_match: String,
     pub replace_with: String,
-    pub first_occurance: bool
+    /// Only read from presets saved before occurrence existed, see Modifiers::migrate.
+    #[serde(skip_serializing)]
+    pub first_occurance: bool,
+    pub occurrence: ReplaceOccurrence,
+    pub occurrence_name: String,
+    /// Which match to replace when occurrence is Nth, starting at 1.
+    pub nth: u32,
+    pub case_insensitive: bool,
+    pub whole_word: bool,
+    /// Matches the replacement's case to the matched text (UPPER, lower or Title).
+    pub preserve_case: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ReplaceOccurrence {
+    All,
+    First,
+    Last,
+    Nth
 }
 impl Default for ModReplace {
     fn default() -> Self {
         Self {
             replace_match: String::new(),
             replace_with: String::new(),
-            first_occurance: false
+            first_occurance: false,
+            occurrence: ReplaceOccurrence::All,
+            occurrence_name: String::from("All"),
+            nth: 1,
+            case_insensitive: false,
+            whole_word: false,
+            preserve_case: false
         }
     }
-}
\ No newline at end of file
+}
+
+// Template
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModTemplate {
+    pub template: String,
+    pub counter_start: u32,
+    pub counter_step: u32
+}
+impl Default for ModTemplate {
+    fn default() -> Self {
+        Self {
+            template: String::new(),
+            counter_start: 1,
+            counter_step: 1
+        }
+    }
+}
+
+// Transliterate
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModTransliterate {
+    pub mode: TransliterateMode,
+    pub mode_name: String,
+    pub widgets_enabled: bool,
+    /// Put in place of characters that have no ASCII equivalent.
+    pub unknown: String
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TransliterateMode {
+    None,
+    Ascii,
+    NonLatin
+}
+impl Default for ModTransliterate {
+    fn default() -> Self {
+        Self {
+            mode: TransliterateMode::None,
+            mode_name: String::from("None"),
+            widgets_enabled: false,
+            unknown: String::from("_")
+        }
+    }
+}
+
+// Normalize
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModNormalize {
+    pub mode: NormalizeMode,
+    pub mode_name: String
+}
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum NormalizeMode {
+    None,
+    NFC,
+    NFD,
+    NFKC,
+    NFKD
+}
+impl Default for ModNormalize {
+    fn default() -> Self {
+        Self {
+            mode: NormalizeMode::None,
+            mode_name: String::from("None")
+        }
+    }
+}
+
+// Dictionary
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModDictionary {
+    /// One `find,replace` or `find<tab>replace` pair per line, kept here so the preset carries the whole dictionary.
+    pub table: String,
+    /// CSV / TSV file the table was last loaded from.
+    pub file_path: String,
+    pub order: DictionaryOrder,
+    pub order_name: String,
+    pub case_insensitive: bool,
+    pub whole_word: bool,
+    pub preserve_case: bool,
+    #[serde(skip)]
+    pub load_error: String
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DictionaryOrder {
+    Longest,
+    Listed
+}
+impl Default for ModDictionary {
+    fn default() -> Self {
+        Self {
+            table: String::new(),
+            file_path: String::new(),
+            order: DictionaryOrder::Longest,
+            order_name: String::from("Longest First"),
+            case_insensitive: false,
+            whole_word: false,
+            preserve_case: false,
+            load_error: String::new()
+        }
+    }
+}
+
+// Mapping
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModMapping {
+    /// `old,new` CSV / TSV rows, or JSON as `{"old": "new"}`, `[["old", "new"]]` or `[{"old": .., "new": ..}]`.
+    pub table: String,
+    /// Mapping file the table was last loaded from.
+    pub file_path: String,
+    #[serde(skip)]
+    pub load_error: String
+}
+impl Default for ModMapping {
+    fn default() -> Self {
+        Self {
+            table: String::new(),
+            file_path: String::new(),
+            load_error: String::new()
+        }
+    }
+}
+
+// Names From List
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModNameList {
+    /// One name per line, handed out in selection order. Blank lines are skipped.
+    pub table: String,
+    /// Text file the list was last loaded from.
+    pub file_path: String,
+    /// Keeps the item's extension, dots in a line are part of the name.
+    pub keep_extension: bool,
+    /// Drops leading track numbers like `01. `, `1 - ` or `[03] `.
+    pub strip_numbering: bool,
+    /// Trims each line and collapses runs of whitespace.
+    pub trim_whitespace: bool,
+    #[serde(skip)]
+    pub load_error: String
+}
+impl Default for ModNameList {
+    fn default() -> Self {
+        Self {
+            table: String::new(),
+            file_path: String::new(),
+            keep_extension: true,
+            strip_numbering: false,
+            trim_whitespace: true,
+            load_error: String::new()
+        }
+    }
+}
+
+// Segment
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModSegment {
+    /// Literal text, or a regex when delimiter_regex is on.
+    pub delimiter: String,
+    pub delimiter_regex: bool,
+    /// Segment indexes to rebuild the name from, comma seperated and starting at 0, like `2,0,1`.
+    pub order: String,
+    /// Goes between the rebuilt segments. Empty reuses the first delimiter found.
+    pub joiner: String,
+    pub trim: bool
+}
+impl Default for ModSegment {
+    fn default() -> Self {
+        Self {
+            delimiter: String::from(" - "),
+            delimiter_regex: false,
+            order: String::new(),
+            joiner: String::new(),
+            trim: true
+        }
+    }
+}
+
+// Renumber
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModRenumber {
+    /// Which number in the name gets renumbered.
+    pub occurrence: RenumberOccurrence,
+    pub occurrence_name: String,
+    pub nth: u32,
+    /// Digits to pad to, 0 keeps the number's own width.
+    pub padding: u32,
+    pub offset: i32,
+    /// Numbers the folder's items consecutively from `start`, in the order of their old numbers.
+    pub resequence: bool,
+    pub start: u32,
+    /// Resequencing shifts the numbers so the lowest is `start`, keeping the gaps between them.
+    pub keep_gaps: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RenumberOccurrence {
+    First,
+    Last,
+    Nth
+}
+impl Default for ModRenumber {
+    fn default() -> Self {
+        Self {
+            occurrence: RenumberOccurrence::First,
+            occurrence_name: String::from("First"),
+            nth: 1,
+            padding: 0,
+            offset: 0,
+            resequence: false,
+            start: 1,
+            keep_gaps: false
+        }
+    }
+}
+
+// Frame Sequence
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModSequence {
+    /// Renumbers the frames from `start`, keeping their order.
+    pub renumber: bool,
+    pub start: u32,
+    /// Renumbering closes the gaps instead of keeping them.
+    pub close_gaps: bool,
+    /// Digits to pad the frames to, 0 keeps the sequence's own.
+    pub padding: u32,
+    pub prefix_enabled: bool,
+    pub prefix: String
+}
+impl Default for ModSequence {
+    fn default() -> Self {
+        Self {
+            renumber: false,
+            start: 1,
+            close_gaps: false,
+            padding: 0,
+            prefix_enabled: false,
+            prefix: String::new()
+        }
+    }
+}
+
+// Random Names
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModRandom {
+    pub mode: RandomMode,
+    pub mode_name: String,
+    pub kind: RandomKind,
+    pub kind_name: String,
+    /// Characters for the Alphanumeric, Hex and NanoID kinds.
+    pub length: u32,
+    pub seperator: char,
+    pub seperator_enabled: bool,
+    /// Each item's value comes from the seed and its path, so the preview doesn't change on every refresh. 
+    /// Up to `random::SEED_MAXIMUM`, so it survives editing as a number.
+    pub seed: u64
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RandomMode {
+    None,
+    Replace,
+    Prefix,
+    Suffix
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum RandomKind {
+    Alphanumeric,
+    Hex,
+    NanoId,
+    UuidV4,
+    /// Its time is the item's modified date.
+    UuidV7,
+    /// Its time is the item's modified date.
+    Ulid
+}
+impl Default for ModRandom {
+    fn default() -> Self {
+        Self {
+            mode: RandomMode::None,
+            mode_name: String::from("None"),
+            kind: RandomKind::Hex,
+            kind_name: String::from("Hex"),
+            length: 8,
+            seperator: ' ',
+            seperator_enabled: false,
+            seed: random::new_seed()
+        }
+    }
+}
+
+// Truncate
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModTruncate {
+    /// Longest a name may be, 0 leaves names alone.
+    pub limit: u32,
+    pub unit: TruncateUnit,
+    pub unit_name: String,
+    /// Counts the extension against the limit, it's never cut either way.
+    pub count_extension: bool,
+    /// Cuts at the last space, `_`, `-` or `.` that fits instead of mid word.
+    pub word_boundary: bool,
+    pub marker_enabled: bool,
+    pub marker: String,
+    /// Keeps a trailing number or bracketed hash.
+    pub protect_tail: bool,
+    /// Adds a short hash of the untruncated name when the truncated one is already taken.
+    pub dedupe: bool
+}
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TruncateUnit {
+    Characters,
+    Bytes
+}
+impl Default for ModTruncate {
+    fn default() -> Self {
+        Self {
+            limit: 0,
+            unit: TruncateUnit::Characters,
+            unit_name: String::from("Characters"),
+            count_extension: true,
+            word_boundary: true,
+            marker_enabled: false,
+            marker: String::from("…"),
+            protect_tail: true,
+            dedupe: true
+        }
+    }
+}
+
+// Seperators
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModSeperators {
+    /// Characters that become `to`, nothing is converted while it's empty.
+    pub from: String,
+    pub to: char,
+    /// Turns a run of seperators into one and trims them from the ends.
+    pub collapse: bool,
+    /// Puts `to` between camelCase humps.
+    pub split_camel: bool,
+    pub protect_enabled: bool,
+    /// Regexes, one a line, for what's left alone. The first capture group is kept when there is one.
+    pub protect: String
+}
+impl Default for ModSeperators {
+    fn default() -> Self {
+        Self {
+            from: String::new(),
+            to: ' ',
+            collapse: true,
+            split_camel: false,
+            protect_enabled: true,
+            // Versions like v1.2 and 1.2.3, and short decimals like 5.1.
+            protect: String::from("v\\d+(?:\\.\\d+)+\n\\d+(?:\\.\\d+){2,}\n(?:^|\\D)(\\d{1,2}\\.\\d{1,2})(?:$|\\D)")
+        }
+    }
+}
@@ -1,5 +1,7 @@
 use super::super::app::Options;
 use super::super::presets::Presets;
+use super::super::mods::ModsOrder;
+use super::dir;
 use whoami::username;
 use std::fs;
 
@@ -299,7 +301,11 @@ pub fn write_presets(presets: Presets) -> Result<(), String> {
 }
 
 pub fn serialize_presets(config: String) -> Presets {
-    let options: Presets = serde_json::from_str(&config).unwrap_or_default();
+    let mut options: Presets = serde_json::from_str(&config).unwrap_or_default();
+    for preset in options.sets.iter_mut() {
+        ModsOrder::append_missing(&mut preset.modifier_order);
+        preset.modifiers.migrate();
+    };
     options
 }
 
@@ -310,3 +316,49 @@ pub fn deserialize_presets(config: Presets) -> String {
     }
 }
 
+
+
+// Last CLI edit, kept so `-u` can undo it in a later run.
+fn path_config() -> String {
+    let path: String;
+    #[cfg(target_os = "linux")]
+    {
+        path = format!("{}{}{}", PATH_CONFIG_UNIX[0], username(), PATH_CONFIG_UNIX[1]);
+    }
+    #[cfg(target_os = "windows")]
+    {
+        path = format!("{}{}{}", PATH_CONFIG_WINDOWS[0], username(), PATH_CONFIG_WINDOWS[1]);
+    }
+    #[cfg(target_os = "macos")]
+    {
+        path = format!("{}{}{}", PATH_CONFIG_DARWIN[0], username(), PATH_CONFIG_DARWIN[1]);
+    }
+    path
+}
+
+pub fn read_last_edit() -> Option<dir::Edit> {
+    let edit = fs::read_to_string(format!("{}/last_edit.json", path_config())).ok()?;
+    serde_json::from_str(&edit).ok()
+}
+
+pub fn write_last_edit(edit: &dir::Edit) -> Result<(), String> {
+    let path = path_config();
+    let edit = match serde_json::to_string_pretty(edit) {
+        Ok(json_data) => json_data,
+        Err(error) => return Err(error.to_string())
+    };
+    if let Err(error) = fs::create_dir_all(&path) {
+        return Err(error.to_string());
+    };
+    match fs::write(format!("{}/last_edit.json", path), edit) {
+        Ok(()) => Ok(()),
+        Err(error) => Err(error.to_string())
+    }
+}
+
+pub fn remove_last_edit() -> Result<(), String> {
+    match fs::remove_file(format!("{}/last_edit.json", path_config())) {
+        Ok(()) => Ok(()),
+        Err(error) => Err(error.to_string())
+    }
+}
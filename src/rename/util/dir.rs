@@ -1,6 +1,14 @@
 use std::fs;
 use std::io;
+use std::cmp::Ordering;
+use std::path::Path;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::exif;
+use super::processing::file_processing::split_extension;
+
+// Fewer numbered files than this are just numbered files.
+const SEQUENCE_MINIMUM: usize = 3;
 
 pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
     let mut f = Folder {
@@ -9,6 +17,7 @@ pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
         list_folders: vec![],
         selected_files: vec![],
         selected_folders: vec![],
+        sequences: vec![],
         errored: None,
         errored_message: None
     };
@@ -26,7 +35,9 @@ pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
                                 path_plain: path.to_owned(),
                                 hash: String::new(),
                                 error: String::new(),
-                                errored: false
+                                errored: false,
+                                warning: String::new(),
+                                sort_cache: SortCache::default()
                             }
                         );
                         f.selected_folders.push(false);
@@ -39,7 +50,9 @@ pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
                                 path_plain: path.to_owned(),
                                 hash: String::new(),
                                 error: String::new(),
-                                errored: false
+                                errored: false,
+                                warning: String::new(),
+                                sort_cache: SortCache::default()
                             }
                         );
                         f.selected_files.push(false);
@@ -51,6 +64,7 @@ pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
             f.path = path;
             f.list_files.sort();
             f.list_folders.sort();
+            f.sequences = detect_sequences(&f.list_files.iter().map(|item| item.name.to_owned()).collect());
             return Ok(f)
         },
         Err(err) => {
@@ -59,6 +73,302 @@ pub fn get_folder(path: String, _ignore_hidden: bool) -> io::Result<Folder> {
     };
 }
 
+/// Order of the items in the selector, which is also the order counters number them in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum SortMode {
+    Name,
+    Natural,
+    Modified,
+    Created,
+    Size,
+    Extension,
+    Manual
+}
+impl Default for SortMode {
+    fn default() -> Self {
+        SortMode::Name
+    }
+}
+impl SortMode {
+    /// Sorts that read every item's metadata, or EXIF for Created, before they can sort.
+    pub fn reads_disk(&self) -> bool {
+        matches!(self, SortMode::Modified | SortMode::Created | SortMode::Size)
+    }
+}
+
+/// Sorts a folder's files and folders, keeping their selection with them. Manual leaves them as they are.
+/// Keys not read yet are read here, `read_sort_keys` reads them ahead of time off the GUI thread.
+pub fn sort_folder(folder: &mut Folder, mode: &SortMode, descending: bool) {
+    if *mode == SortMode::Manual {
+        return;
+    };
+    let (list_files, selected_files) = sort_items(&mut folder.list_files, &folder.selected_files, mode, descending, false);
+    folder.list_files = list_files;
+    folder.selected_files = selected_files;
+    let (list_folders, selected_folders) = sort_items(&mut folder.list_folders, &folder.selected_folders, mode, descending, true);
+    folder.list_folders = list_folders;
+    folder.selected_folders = selected_folders;
+    // The sequences hold file indexes.
+    folder.sequences = detect_sequences(&folder.list_files.iter().map(|item| item.name.to_owned()).collect());
+}
+
+/// Whether sorting the folder by mode can go without reading the disk.
+pub fn sort_keys_read(folder: &Folder, mode: &SortMode) -> bool {
+    folder.list_files.iter().chain(folder.list_folders.iter())
+        .all(|item| item.sort_cache.get(mode).map(|key| key.is_some()).unwrap_or(true))
+}
+
+/// Reads every item's key for the mode into its cache, without sorting.
+pub fn read_sort_keys(folder: &mut Folder, mode: &SortMode) {
+    for item in folder.list_files.iter_mut() {
+        sort_key(item, mode, false);
+    }
+    for item in folder.list_folders.iter_mut() {
+        sort_key(item, mode, true);
+    }
+}
+
+/// Copies keys read into a copy of the folder onto its items, matched by path.
+pub fn merge_sort_keys(folder: &mut Folder, read: &Folder) {
+    let caches: HashMap<&String, &SortCache> = read.list_files.iter().chain(read.list_folders.iter())
+        .map(|item| (&item.path, &item.sort_cache))
+        .collect();
+    for item in folder.list_files.iter_mut().chain(folder.list_folders.iter_mut()) {
+        if let Some(cache) = caches.get(&item.path) {
+            item.sort_cache.merge(cache);
+        };
+    }
+}
+
+/// The order a folder was shown in, to put it back that way when it's reloaded.
+#[derive(Debug, Clone, Default)]
+pub struct FolderOrder {
+    /// (Name, Name Modified) of every folder, so items renamed by a save keep their place.
+    pub folders: Vec<(String, String)>,
+    /// (Name, Name Modified) of every file.
+    pub files: Vec<(String, String)>
+}
+
+pub fn folder_order(folder: &Folder) -> FolderOrder {
+    let names = |items: &[FolderItem]| items.iter().map(|item| (item.name.to_owned(), item.name_modified.to_owned())).collect();
+    FolderOrder {
+        folders: names(&folder.list_folders),
+        files: names(&folder.list_files)
+    }
+}
+
+/// Puts a folder back in a remembered order. Items the order doesn't have go last, as they are.
+pub fn restore_order(folder: &mut Folder, order: &FolderOrder) {
+    let (list_files, selected_files) = restore_items(&folder.list_files, &folder.selected_files, &order.files);
+    folder.list_files = list_files;
+    folder.selected_files = selected_files;
+    let (list_folders, selected_folders) = restore_items(&folder.list_folders, &folder.selected_folders, &order.folders);
+    folder.list_folders = list_folders;
+    folder.selected_folders = selected_folders;
+    folder.sequences = detect_sequences(&folder.list_files.iter().map(|item| item.name.to_owned()).collect());
+}
+
+fn restore_items(items: &[FolderItem], selected: &[bool], order: &[(String, String)]) -> (Vec<FolderItem>, Vec<bool>) {
+    let mut places: HashMap<&String, usize> = HashMap::new();
+    // The current name wins over a modified one, which is what the item is called after a save.
+    for (place, (_, name_modified)) in order.iter().enumerate().rev() {
+        places.insert(name_modified, place);
+    }
+    for (place, (name, _)) in order.iter().enumerate().rev() {
+        places.insert(name, place);
+    }
+    let mut placed: Vec<(usize, FolderItem, bool)> = items.iter().zip(selected.iter())
+        .map(|(item, selected)| (places.get(&item.name).copied().unwrap_or(order.len()), item.to_owned(), *selected))
+        .collect();
+    placed.sort_by_key(|(place, _, _)| *place);
+    placed.into_iter().map(|(_, item, selected)| (item, selected)).unzip()
+}
+
+/// Files that only differ by a frame number, like `shot_0001.exr` to `shot_0240.exr`.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct Sequence {
+    /// Name up to the frame number.
+    pub prefix: String,
+    /// Name after the frame number, extension included.
+    pub suffix: String,
+    /// Digits every frame is padded to, 0 when they aren't padded.
+    pub padding: usize,
+    /// Frame number and the index of its name, in frame order.
+    pub frames: Vec<(u64, usize)>
+}
+impl Sequence {
+    /// Missing frame ranges between the first and last frame.
+    pub fn gaps(&self) -> Vec<(u64, u64)> {
+        self.frames.windows(2)
+            .filter(|pair| pair[1].0 > pair[0].0 + 1)
+            .map(|pair| (pair[0].0 + 1, pair[1].0 - 1))
+            .collect()
+    }
+
+    pub fn gaps_text(&self) -> String {
+        let gaps: Vec<String> = self.gaps().iter().map(|(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}-{}", start, end)
+        }).collect();
+        gaps.join(", ")
+    }
+
+    /// Collapsed name, like `shot_[0001-0240].exr`.
+    pub fn label(&self) -> String {
+        let first = self.frames.first().map(|frame| frame.0).unwrap_or_default();
+        let last = self.frames.last().map(|frame| frame.0).unwrap_or_default();
+        format!("{}[{:0width$}-{:0width$}]{}", self.prefix, first, last, self.suffix, width = self.padding)
+    }
+}
+
+/// Groups names by what's around their last number. Names in a group that's too small aren't a sequence.
+pub fn detect_sequences(names: &Vec<String>) -> Vec<Sequence> {
+    let mut groups: HashMap<(String, String), Vec<(u64, usize, usize)>> = HashMap::new();
+    let mut keys: Vec<(String, String)> = vec![];
+    for (index, name) in names.iter().enumerate() {
+        let (stem, ext) = split_extension(name, false);
+        let end = match stem.rfind(|char: char| char.is_ascii_digit()) {
+            Some(end) => end + 1,
+            None => continue
+        };
+        let start = stem[..end].rfind(|char: char| !char.is_ascii_digit()).map(|start| start + 1).unwrap_or(0);
+        let frame = match stem[start..end].parse::<u64>() {
+            Ok(frame) => frame,
+            Err(_) => continue
+        };
+        let key = (stem[..start].to_string(), format!("{}{}", &stem[end..], ext));
+        if !groups.contains_key(&key) {
+            keys.push(key.to_owned());
+        };
+        groups.entry(key).or_default().push((frame, index, end - start));
+    }
+    let mut sequences: Vec<Sequence> = vec![];
+    for key in keys {
+        let mut frames = groups.remove(&key).unwrap_or_default();
+        if frames.len() < SEQUENCE_MINIMUM {
+            continue;
+        };
+        frames.sort();
+        // Padded when any frame has a leading zero, to the narrowest width.
+        let padded = frames.iter().any(|(frame, _, width)| frame.to_string().len() < *width);
+        let padding = match padded {
+            true => frames.iter().map(|(_, _, width)| *width).min().unwrap_or_default(),
+            false => 0
+        };
+        sequences.push(Sequence {
+            prefix: key.0,
+            suffix: key.1,
+            padding: padding,
+            frames: frames.into_iter().map(|(frame, index, _)| (frame, index)).collect()
+        });
+    }
+    sequences
+}
+
+fn sort_items(items: &mut Vec<FolderItem>, selected: &Vec<bool>, mode: &SortMode, descending: bool, is_folder: bool) -> (Vec<FolderItem>, Vec<bool>) {
+    let keys: Vec<SortKey> = items.iter_mut().map(|item| sort_key(item, mode, is_folder)).collect();
+    let mut keyed: Vec<(SortKey, FolderItem, bool)> = keys.into_iter().zip(items.iter()).zip(selected.iter())
+        .map(|((key, item), selected)| (key, item.to_owned(), *selected))
+        .collect();
+    keyed.sort_by(|a, b| {
+        let order = a.0.cmp(&b.0).then_with(|| natural_cmp(&a.1.name, &b.1.name));
+        match descending {
+            true => order.reverse(),
+            false => order
+        }
+    });
+    keyed.into_iter().map(|(_, item, selected)| (item, selected)).unzip()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum SortKey {
+    None,
+    Number(u64),
+    Text(String)
+}
+
+/// The item's key, from its cache when it's already been read from disk.
+fn sort_key(item: &mut FolderItem, mode: &SortMode, is_folder: bool) -> SortKey {
+    match mode {
+        SortMode::Name => return SortKey::Text(item.name.to_owned()),
+        SortMode::Natural | SortMode::Manual => return SortKey::None,
+        SortMode::Extension => return match is_folder {
+            true => SortKey::None,
+            false => SortKey::Text(Path::new(&item.name).extension().unwrap_or_default().to_string_lossy().to_lowercase())
+        },
+        _ => {}
+    };
+    let cached = match item.sort_cache.get_mut(mode) {
+        Some(cached) => cached,
+        None => return SortKey::None
+    };
+    if let Some(key) = cached {
+        return key.to_owned();
+    };
+    let key = read_sort_key(&item.path, mode);
+    *cached = Some(key.to_owned());
+    key
+}
+
+/// Reads a key for the sorts that need the disk, EXIF is only read for Created.
+fn read_sort_key(path: &str, mode: &SortMode) -> SortKey {
+    let metadata = fs::metadata(path).ok();
+    let time = |time: Option<std::time::SystemTime>| match time {
+        // Same form as EXIF dates so the two compare.
+        Some(time) => SortKey::Text(chrono::DateTime::<chrono::Local>::from(time).format("%Y:%m:%d %H:%M:%S").to_string()),
+        None => SortKey::None
+    };
+    match mode {
+        SortMode::Modified => time(metadata.and_then(|metadata| metadata.modified().ok())),
+        SortMode::Created => match exif::date_taken(path) {
+            Some(date) => SortKey::Text(date),
+            None => time(metadata.and_then(|metadata| metadata.created().or(metadata.modified()).ok()))
+        },
+        SortMode::Size => match metadata {
+            Some(metadata) => SortKey::Number(metadata.len()),
+            None => SortKey::None
+        },
+        _ => SortKey::None
+    }
+}
+
+/// Compares names with runs of digits as numbers, so `file2` comes before `file10`.
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let mut a_digits = String::new();
+                while let Some(digit) = a_chars.next_if(|char| char.is_ascii_digit()) {
+                    a_digits.push(digit);
+                }
+                let mut b_digits = String::new();
+                while let Some(digit) = b_chars.next_if(|char| char.is_ascii_digit()) {
+                    b_digits.push(digit);
+                }
+                let a_number = a_digits.trim_start_matches('0');
+                let b_number = b_digits.trim_start_matches('0');
+                let order = a_number.len().cmp(&b_number.len()).then_with(|| a_number.cmp(b_number));
+                if order != Ordering::Equal {
+                    return order;
+                };
+            },
+            (Some(a_char), Some(b_char)) => {
+                let order = a_char.to_lowercase().cmp(b_char.to_lowercase());
+                if order != Ordering::Equal {
+                    return order;
+                };
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
 pub fn _read_folder(path: String) -> io::Result<Vec<String>> {
     let mut files: Vec<String> = vec![];
     match fs::read_dir(path.to_owned()) {
@@ -105,7 +415,8 @@ pub struct Folder {
     pub list_files: Vec<FolderItem>,
     pub selected_files: Vec<bool>,
     pub list_folders: Vec<FolderItem>,
-    pub selected_folders: Vec<bool>
+    pub selected_folders: Vec<bool>,
+    pub sequences: Vec<Sequence>
 }
 
 /// Path excludes file / folder name
@@ -117,7 +428,49 @@ pub struct FolderItem {
     pub path_plain: String,
     pub hash: String,
     pub errored: bool,
-    pub error: String
+    pub error: String,
+    /// Shown in the selector without blocking a save.
+    pub warning: String,
+    pub sort_cache: SortCache
+}
+
+/// Sort keys read from disk, kept after the first sort that needs them.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Eq, Ord, Default)]
+pub struct SortCache {
+    modified: Option<SortKey>,
+    created: Option<SortKey>,
+    size: Option<SortKey>
+}
+impl SortCache {
+    /// None for sorts that don't read the disk.
+    fn get(&self, mode: &SortMode) -> Option<&Option<SortKey>> {
+        match mode {
+            SortMode::Modified => Some(&self.modified),
+            SortMode::Created => Some(&self.created),
+            SortMode::Size => Some(&self.size),
+            _ => None
+        }
+    }
+
+    fn get_mut(&mut self, mode: &SortMode) -> Option<&mut Option<SortKey>> {
+        match mode {
+            SortMode::Modified => Some(&mut self.modified),
+            SortMode::Created => Some(&mut self.created),
+            SortMode::Size => Some(&mut self.size),
+            _ => None
+        }
+    }
+
+    /// Fills in the keys this one hasn't read yet.
+    fn merge(&mut self, other: &SortCache) {
+        for mode in [SortMode::Modified, SortMode::Created, SortMode::Size] {
+            if let (Some(key), Some(other)) = (self.get_mut(&mode), other.get(&mode)) {
+                if key.is_none() {
+                    *key = other.to_owned();
+                };
+            };
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -133,4 +486,99 @@ pub struct Edit {
     pub tag: String,
     pub items: Vec<EdittedItem>,
     pub edits: u32,
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_sort_folder() {
+        let item = |name: &str| super::FolderItem { name: name.to_string(), path: format!("/scratch/missing/{}", name), ..Default::default() };
+        let mut folder = super::Folder {
+            list_files: vec![item("file10.txt"), item("File2.png"), item("file1.txt"), item("file02.jpg")],
+            selected_files: vec![true, false, false, true],
+            ..Default::default()
+        };
+        let names = |folder: &super::Folder| folder.list_files.iter().map(|item| item.name.to_owned()).collect::<Vec<String>>();
+        super::sort_folder(&mut folder, &super::SortMode::Natural, false);
+        assert_eq!(names(&folder), vec!["file1.txt", "file02.jpg", "File2.png", "file10.txt"]);
+        assert_eq!(folder.selected_files, vec![false, true, false, true]);
+        super::sort_folder(&mut folder, &super::SortMode::Extension, false);
+        assert_eq!(names(&folder), vec!["file02.jpg", "File2.png", "file1.txt", "file10.txt"]);
+        super::sort_folder(&mut folder, &super::SortMode::Name, true);
+        assert_eq!(names(&folder), vec!["file10.txt", "file1.txt", "file02.jpg", "File2.png"]);
+        super::sort_folder(&mut folder, &super::SortMode::Manual, false);
+        assert_eq!(names(&folder), vec!["file10.txt", "file1.txt", "file02.jpg", "File2.png"]);
+
+        // Keys are read once, a file changing size after the first sort keeps its place.
+        let path = std::env::temp_dir().join(format!("kita_test_sort_{}", std::process::id()));
+        std::fs::write(&path, "12345").unwrap();
+        let mut folder = super::Folder {
+            list_files: vec![item("small"), super::FolderItem { name: String::from("big"), path: path.to_str().unwrap().to_string(), ..Default::default() }],
+            selected_files: vec![false, false],
+            ..Default::default()
+        };
+        super::sort_folder(&mut folder, &super::SortMode::Size, true);
+        assert_eq!(names(&folder), vec!["big", "small"]);
+        std::fs::remove_file(&path).unwrap();
+        super::sort_folder(&mut folder, &super::SortMode::Size, true);
+        assert_eq!(names(&folder), vec!["big", "small"]);
+    }
+
+    #[test]
+    fn test_read_sort_keys() {
+        let path = std::env::temp_dir().join(format!("kita_test_sort_keys_{}", std::process::id()));
+        std::fs::write(&path, "12345").unwrap();
+        let item = |name: &str, path: &str| super::FolderItem { name: name.to_string(), path: path.to_string(), ..Default::default() };
+        let mut folder = super::Folder {
+            path: String::from("/scratch"),
+            list_files: vec![item("small", "/scratch/missing/small"), item("big", path.to_str().unwrap())],
+            selected_files: vec![false, true],
+            ..Default::default()
+        };
+        assert!(super::sort_keys_read(&folder, &super::SortMode::Extension));
+        assert!(!super::sort_keys_read(&folder, &super::SortMode::Size));
+        // Keys read into a copy, like the background read does, then sorted without the disk.
+        let mut read = folder.clone();
+        super::read_sort_keys(&mut read, &super::SortMode::Size);
+        std::fs::remove_file(&path).unwrap();
+        super::merge_sort_keys(&mut folder, &read);
+        assert!(super::sort_keys_read(&folder, &super::SortMode::Size));
+        super::sort_folder(&mut folder, &super::SortMode::Size, true);
+        assert_eq!(folder.list_files[0].name, "big");
+        assert_eq!(folder.selected_files, vec![true, false]);
+    }
+
+    #[test]
+    fn test_restore_order() {
+        let item = |name: &str, name_modified: &str| super::FolderItem { name: name.to_string(), name_modified: name_modified.to_string(), ..Default::default() };
+        let shown = super::Folder {
+            list_files: vec![item("c.jpg", "c.jpg"), item("a.jpg", "Beach.jpg"), item("b.jpg", "b.jpg")],
+            ..Default::default()
+        };
+        let order = super::folder_order(&shown);
+        // Reloaded after a save renamed a.jpg, with a new file that wasn't there before.
+        let mut reloaded = super::Folder {
+            list_files: vec![item("Beach.jpg", "Beach.jpg"), item("b.jpg", "b.jpg"), item("c.jpg", "c.jpg"), item("new.jpg", "new.jpg")],
+            selected_files: vec![false, true, false, false],
+            ..Default::default()
+        };
+        super::restore_order(&mut reloaded, &order);
+        let names: Vec<&str> = reloaded.list_files.iter().map(|item| item.name.as_str()).collect();
+        assert_eq!(names, vec!["c.jpg", "Beach.jpg", "b.jpg", "new.jpg"]);
+        assert_eq!(reloaded.selected_files, vec![false, false, true, false]);
+    }
+
+    #[test]
+    fn test_detect_sequences() {
+        let names: Vec<String> = ["shot_0003.exr", "shot_0001.exr", "notes.txt", "shot_0002.exr", "shot_0006.exr", "a1.png", "a2.png"]
+            .iter().map(|name| name.to_string()).collect();
+        let sequences = super::detect_sequences(&names);
+        assert_eq!(sequences.len(), 1);
+        assert_eq!(sequences[0].prefix, "shot_");
+        assert_eq!(sequences[0].suffix, ".exr");
+        assert_eq!(sequences[0].padding, 4);
+        assert_eq!(sequences[0].frames, vec![(1, 1), (2, 3), (3, 0), (6, 4)]);
+        assert_eq!(sequences[0].gaps(), vec![(4, 5)]);
+        assert_eq!(sequences[0].gaps_text(), "4-5");
+        assert_eq!(sequences[0].label(), "shot_[0001-0006].exr");
+    }
+}
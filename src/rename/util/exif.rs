@@ -0,0 +1,112 @@
+use std::fs;
+use std::io::Read;
+
+const TAG_EXIF_IFD: u16 = 0x8769;
+const TAG_DATE_TIME: u16 = 0x0132;
+const TAG_DATE_TIME_ORIGINAL: u16 = 0x9003;
+// The EXIF block sits near the start, don't read whole videos and raws.
+const READ_LIMIT: u64 = 256 * 1024;
+
+/// Date taken from a JPEG or TIFF based file's EXIF data, as `YYYY:MM:DD HH:MM:SS`.
+/// Falls back to the last edit date in IFD0 when there's no DateTimeOriginal.
+pub fn date_taken(path: &str) -> Option<String> {
+    let mut data: Vec<u8> = vec![];
+    fs::File::open(path).ok()?.take(READ_LIMIT).read_to_end(&mut data).ok()?;
+    let tiff: &[u8] = match data.get(0..2)? {
+        [0xFF, 0xD8] => jpeg_exif(&data)?,
+        b"II" | b"MM" => &data,
+        _ => return None
+    };
+    let little_endian = match tiff.get(0..2)? {
+        b"II" => true,
+        b"MM" => false,
+        _ => return None
+    };
+    let ifd0 = read_u32(tiff, 4, little_endian)? as usize;
+    if let Some(exif_ifd) = ifd_entry(tiff, ifd0, TAG_EXIF_IFD, little_endian) {
+        let exif_ifd = read_u32(tiff, exif_ifd + 8, little_endian)? as usize;
+        if let Some(date) = ifd_entry(tiff, exif_ifd, TAG_DATE_TIME_ORIGINAL, little_endian).and_then(|entry| ascii_value(tiff, entry, little_endian)) {
+            return Some(date);
+        };
+    };
+    ifd_entry(tiff, ifd0, TAG_DATE_TIME, little_endian).and_then(|entry| ascii_value(tiff, entry, little_endian))
+}
+
+/// The TIFF block of a JPEG's APP1 Exif segment.
+fn jpeg_exif(data: &[u8]) -> Option<&[u8]> {
+    let mut at: usize = 2;
+    while at + 4 <= data.len() {
+        if data[at] != 0xFF {
+            return None;
+        };
+        let marker = data[at + 1];
+        let length = u16::from_be_bytes([data[at + 2], data[at + 3]]) as usize;
+        // Start of scan, the image data follows and there are no more headers.
+        if marker == 0xDA {
+            return None;
+        };
+        let segment = data.get(at + 4..at + 2 + length)?;
+        if marker == 0xE1 && segment.starts_with(b"Exif\0\0") {
+            return Some(&segment[6..]);
+        };
+        at += 2 + length;
+    }
+    None
+}
+
+/// Offset of the entry with `tag` in the IFD at `ifd`.
+fn ifd_entry(tiff: &[u8], ifd: usize, tag: u16, little_endian: bool) -> Option<usize> {
+    let count = read_u16(tiff, ifd, little_endian)? as usize;
+    (0..count)
+        .map(|index| ifd + 2 + index * 12)
+        .find(|entry| read_u16(tiff, *entry, little_endian) == Some(tag))
+}
+
+fn ascii_value(tiff: &[u8], entry: usize, little_endian: bool) -> Option<String> {
+    let count = read_u32(tiff, entry + 4, little_endian)? as usize;
+    // Values over 4 bytes are stored elsewhere, the entry holds their offset.
+    let offset = match count > 4 {
+        true => read_u32(tiff, entry + 8, little_endian)? as usize,
+        false => entry + 8
+    };
+    let value = tiff.get(offset..offset + count)?;
+    let value = String::from_utf8_lossy(value).trim_end_matches('\0').trim().to_string();
+    match value.is_empty() {
+        true => None,
+        false => Some(value)
+    }
+}
+
+fn read_u16(data: &[u8], at: usize, little_endian: bool) -> Option<u16> {
+    let bytes: [u8; 2] = data.get(at..at + 2)?.try_into().ok()?;
+    match little_endian {
+        true => Some(u16::from_le_bytes(bytes)),
+        false => Some(u16::from_be_bytes(bytes))
+    }
+}
+
+fn read_u32(data: &[u8], at: usize, little_endian: bool) -> Option<u32> {
+    let bytes: [u8; 4] = data.get(at..at + 4)?.try_into().ok()?;
+    match little_endian {
+        true => Some(u32::from_le_bytes(bytes)),
+        false => Some(u32::from_be_bytes(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_date_taken() {
+        // JPEG with only an IFD0 DateTime.
+        let mut jpeg: Vec<u8> = vec![0xFF, 0xD8, 0xFF, 0xE1, 0x00, 54];
+        jpeg.extend_from_slice(b"Exif\0\0II*\0");
+        jpeg.extend_from_slice(&[8, 0, 0, 0, 1, 0, 0x32, 0x01, 2, 0, 20, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0]);
+        jpeg.extend_from_slice(b"2020:01:02 03:04:05\0");
+        jpeg.extend_from_slice(&[0xFF, 0xD9]);
+        let path = std::env::temp_dir().join("kita_test_exif.jpg");
+        std::fs::write(&path, jpeg).unwrap();
+        assert_eq!(super::date_taken(path.to_str().unwrap()), Some(String::from("2020:01:02 03:04:05")));
+        std::fs::remove_file(path).unwrap();
+        assert_eq!(super::date_taken("/scratch/missing.jpg"), None);
+    }
+}
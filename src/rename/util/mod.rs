@@ -2,6 +2,8 @@ pub mod hash;
 pub mod threads;
 pub mod config;
 pub mod dir;
+pub mod exif;
+pub mod random;
 pub mod processing;
 pub mod contextmenu;
 pub mod icon;
\ No newline at end of file
@@ -1,59 +1,224 @@
 use super::super::super::util::threads::{ModifierThreadError, HashMode, HashType};
+use super::super::super::util::dir::{Folder, Sequence, detect_sequences};
+use super::super::super::util::random;
 use super::super::super::mods::*;
 
 use chrono;
 use numerals;
 use alpha_counter;
-use regex::Regex;
+use regex::{Captures, Regex, RegexBuilder};
+use deunicode;
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::time::UNIX_EPOCH;
 
 const FORBIDDEN_CHARS_WINDOWS: [char; 9] = ['<', '>', ':', '"', '/', '\\', '|', '?', '*'];
 const FORBIDDEN_CHARS_UNIX: [char; 2] = ['/', '\\'];
 const FORBIDDEN_FILE_NAMES_WINDOWS: [&str; 22] = ["CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9", 
     "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9"];
-const NONTITLE_WORDS: [&str; 24] = ["a", "an", "and", "as", "at", "but", "by", "en", "for", "from", "if", "in", 
-    "nor", "of", "on", "or", "per", "the", "to", "up", "v", "vs", "viaare", "yet"];
-const ACCENTED_CHARS: [char; 29] = ['á', 'à', 'â', 'ä', 'ã', 'å', 'æ', 'ç', 'é', 'è', 'ê', 'ë', 'í', 'ì', 'î', 'ï', 'ñ', 'ó', 'ò', 'ô', 'ö', 'õ', 
-    'ø', 'œ', 'ß', 'ú', 'ù', 'û', 'ü'];
-const ACCENTED_CHARS_REPLACEMENT: [char; 29] = ['a', 'a', 'a', 'a', 'a', 'a', 'a', 'c', 'e', 'e', 'e', 'e', 'i', 'i', 'i', 'i', 'n', 'o', 'o', 'o',  
-    'o', 'o', 'o', 'a', 'B', 'u', 'u', 'u', 'u'];
+const NONTITLE_WORDS_ENGLISH: [&str; 24] = ["a", "an", "and", "as", "at", "but", "by", "en", "for", "from", "if", "in", "nor", "of", "on", 
+    "or", "per", "the", "to", "up", "v", "vs", "via", "yet"];
+const NONTITLE_WORDS_FRENCH: [&str; 17] = ["à", "au", "aux", "de", "des", "du", "en", "et", "la", "le", "les", "ou", "par", "pour", "sur", 
+    "un", "une"];
+const NONTITLE_WORDS_GERMAN: [&str; 24] = ["am", "an", "auf", "aus", "bei", "das", "dem", "den", "der", "des", "die", "ein", "eine", "für", 
+    "im", "in", "mit", "oder", "und", "vom", "von", "zu", "zum", "zur"];
+const NONTITLE_WORDS_SPANISH: [&str; 18] = ["a", "al", "con", "de", "del", "e", "el", "en", "la", "las", "los", "o", "para", "por", "u", 
+    "un", "una", "y"];
+const NONTITLE_WORDS_ITALIAN: [&str; 21] = ["a", "al", "alla", "con", "da", "dal", "di", "del", "della", "e", "ed", "il", "in", "la", "le", 
+    "lo", "o", "per", "su", "un", "una"];
+const NONTITLE_WORDS_PORTUGUESE: [&str; 20] = ["a", "ao", "as", "com", "da", "das", "de", "do", "dos", "e", "em", "na", "no", "o", "os", 
+    "ou", "para", "por", "um", "uma"];
+const NONTITLE_WORDS_DUTCH: [&str; 16] = ["aan", "als", "bij", "de", "een", "en", "het", "in", "met", "of", "op", "te", "tot", "uit", 
+    "van", "voor"];
+// Letters that don't decompose into a base letter and a mark under NFD.
+const ACCENTED_CHARS: [(char, &str); 22] = [('ß', "ss"), ('ẞ', "SS"), ('æ', "ae"), ('Æ', "AE"), ('œ', "oe"), ('Œ', "OE"), ('ø', "o"), 
+    ('Ø', "O"), ('ł', "l"), ('Ł', "L"), ('đ', "d"), ('Đ', "D"), ('ð', "d"), ('Ð', "D"), ('þ', "th"), ('Þ', "Th"), ('ħ', "h"), ('Ħ', "H"), 
+    ('ı', "i"), ('ŧ', "t"), ('Ŧ', "T"), ('ĸ', "k")];
 const SPECIAL_CHARS: [char; 29] = ['~', '`', '!', '@', '#', '$', '%', '^', '&', '*', '(', ')', '_', '-', '+', '=', '{', '}', '[', ']', '|', 
     ':', '\'', '<', '>', ',', '?', '/', '\\'];
+const CASE_SEPERATORS: [char; 3] = ['_', '-', '.'];
+const TEMPLATE_TOKENS: [&str; 10] = ["name", "ext", "counter", "date", "mtime", "ctime", "atime", "hash", "size", "parent"];
+const TEMPLATE_DATE_FORMAT: &str = "%Y-%m-%d";
 const ALPHA_LOWER_UPPER: [char; 52] = ['a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n','o', 'p', 'q', 'r', 's', 't', 
     'u', 'v', 'w', 'x', 'y', 'z', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N','O', 'P', 'Q', 'R', 'S', 'T', 'U', 
     'V', 'W', 'X', 'Y', 'Z'];
+const BASE_DIGITS: &str = "0123456789abcdefghijklmnopqrstuvwxyz";
+// ASCII order, so base 62 names still sort by count.
+const BASE_SIXTY_TWO_DIGITS: &str = "0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const ROMAN_MAXIMUM: i64 = 3999;
+const TRUNCATE_SEPERATORS: [char; 4] = [' ', '_', '-', '.'];
+// A trailing counter or bracketed hash, with what separates it from the name.
+const TRUNCATE_TAIL: &str = r"[ _.\-]*(?:\d+|[\[({][0-9A-Fa-f]{8,}[\])}])$";
+const WORDS_ONES: [&str; 20] = ["zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten", 
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"];
+const WORDS_TENS: [&str; 10] = ["", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety"];
+const WORDS_SCALES: [&str; 7] = ["", "thousand", "million", "billion", "trillion", "quadrillion", "quintillion"];
 
-pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String, usize, Option<String>)>, order: Vec<ModsOrder>, is_folder: bool) -> (Vec<(String, usize, Option<String>)>, Vec<ModifierThreadError>) {
+/// An item of the whole selection, in the order process sees them.
+#[derive(Clone, Debug)]
+pub struct SelectionItem {
+    pub folder: usize,
+    pub name: String,
+    pub is_folder: bool
+}
+
+/// Flattens the selected folders then files of every folder, matching the offsets process is called with.
+pub fn selection_items(proto_files: &Vec<(String, Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)>) -> Vec<SelectionItem> {
+    let mut items: Vec<SelectionItem> = vec![];
+    for (folder, (_, folders, files)) in proto_files.iter().enumerate() {
+        for (name, _, _) in folders.iter() {
+            items.push(SelectionItem { folder: folder, name: name.to_owned(), is_folder: true });
+        };
+        for (name, _, _) in files.iter() {
+            items.push(SelectionItem { folder: folder, name: name.to_owned(), is_folder: false });
+        };
+    };
+    items
+}
+
+/// An item listed in a folder, selected or not.
+#[derive(Clone, Debug)]
+pub struct ListedItem {
+    pub name: String,
+    pub is_folder: bool,
+    pub selected: bool
+}
+
+/// Every item in the folder, folders then files, so new names can be checked against what's already there.
+pub fn folder_listing(folder: &Folder) -> Vec<ListedItem> {
+    let mut items: Vec<ListedItem> = vec![];
+    for (index, item) in folder.list_folders.iter().enumerate() {
+        items.push(ListedItem { name: item.name.to_owned(), is_folder: true, selected: folder.selected_folders.get(index).copied().unwrap_or(false) });
+    };
+    for (index, item) in folder.list_files.iter().enumerate() {
+        items.push(ListedItem { name: item.name.to_owned(), is_folder: false, selected: folder.selected_files.get(index).copied().unwrap_or(false) });
+    };
+    items
+}
+
+/// Where a batch sits among everything being renamed.
+#[derive(Clone, Copy, Debug)]
+pub struct Batch<'a> {
+    pub folder_index: usize,
+    /// How many selected items came before this batch, so lists can carry on across folders.
+    pub offset: usize,
+    /// Every selected item, counters use it to number across folders.
+    pub selection: &'a [SelectionItem],
+    /// Everything in this batch's folder, new names can't take an unselected item's name.
+    pub listing: &'a [ListedItem]
+}
+
+pub fn process(batch: Batch, folder_path: String, modifiers: &mut Modifiers, file_names: Vec<(String, usize, Option<String>)>, order: Vec<ModsOrder>, is_folder: bool) -> (Vec<(String, usize, Option<String>)>, Vec<ModifierThreadError>) {
+    let Batch { folder_index, offset, selection, listing } = batch;
     let mut files: Vec<(String, usize, Option<String>)> = vec![];
     let mut errors: Vec<ModifierThreadError> = vec![];
     let mut duplicates: Vec<usize> = vec![];
     let mut files_over_length: Vec<(usize, u32)> = vec![];
     let mut invalid_chars: Vec<(usize, char)> = vec![];
     let mut invalid_names: Vec<(usize, String)> = vec![];
+    let mut invalid_templates: Vec<(usize, String)> = vec![];
+    let mut normalization_differs: Vec<usize> = vec![];
+    let mut normalization_collisions: Vec<(usize, String)> = vec![];
+    let mut invalid_regexes: Vec<(usize, String)> = vec![];
+    let mut invalid_numbers: Vec<(usize, String)> = vec![];
+    let mut invalid_mappings: Vec<(usize, String)> = vec![];
+    let mut unmapped: Vec<usize> = vec![];
+    let mut unlisted: Vec<usize> = vec![];
+    let mut sequence_gaps: Vec<(usize, String)> = vec![];
+    // Names the truncate modifier has given out so far and the ones staying in the folder, to tell when a cut makes a duplicate.
+    let mut truncate_taken: HashSet<String> = listing.iter().filter(|item| !item.selected).map(|item| item.name.to_owned()).collect();
+
+    // Compile every pattern once for the whole batch.
+    let patterns = Patterns::default();
+    let truncate_tail: Regex = Regex::new(TRUNCATE_TAIL).unwrap();
+    let mut regexes: Vec<Option<Regex>> = vec![];
+    let mut regex_error: Option<String> = None;
+    if modifiers.regex_enabled {
+        for modregex in modifiers.regex.iter() {
+            match build_regex(modregex) {
+                Ok(reg) => regexes.push(reg),
+                Err(err) => {
+                    regexes.push(None);
+                    regex_error = Some(err);
+                }
+            }
+        }
+    };
+    // A bracket keep pattern that doesn't compile would let every bracket go, the names are left alone instead.
+    let remove_error: Option<String> = match modifiers.remove_enabled {
+        true => modifiers.remove.iter()
+            .filter(|modremove| !modremove.brackets_keep.is_empty() && !remove_bracket_pairs(modremove).is_empty())
+            .find_map(|modremove| Regex::new(&modremove.brackets_keep).err())
+            .map(|err| err.to_string()),
+        false => None
+    };
+    let dictionary_pairs: Vec<(Regex, String)> = match modifiers.dictionary_enabled {
+        true => build_dictionary(&modifiers.dictionary),
+        false => vec![]
+    };
+    let (mapping, mapping_error): (HashMap<String, String>, Option<String>) = match modifiers.mapping_enabled {
+        true => match parse_mapping(&modifiers.mapping.table) {
+            Ok(pairs) => (pairs.into_iter().collect(), None),
+            Err(err) => (HashMap::new(), Some(err))
+        },
+        false => (HashMap::new(), None)
+    };
+    let (seperator_protect, seperator_error): (Vec<Regex>, Option<String>) = match modifiers.seperators_enabled && modifiers.seperators.protect_enabled {
+        true => match build_seperator_protect(&modifiers.seperators.protect) {
+            Ok(regexes) => (regexes, None),
+            Err(err) => (vec![], Some(err))
+        },
+        false => (vec![], None)
+    };
+    let name_list: Vec<String> = match modifiers.name_list_enabled {
+        true => name_list_names(&modifiers.name_list),
+        false => vec![]
+    };
+    let renumber_sequence: HashMap<u64, u64> = match modifiers.renumber_enabled && modifiers.renumber.resequence {
+        true => {
+            // The whole folder's numbers, falling back to this batch's when the selection doesn't cover it.
+            let mut names: Vec<String> = selection.iter()
+                .filter(|item| item.folder == folder_index && item.is_folder == is_folder)
+                .map(|item| split_extension(&item.name, item.is_folder).0)
+                .collect();
+            if selection.len() < offset + file_names.len() {
+                names = file_names.iter().map(|name| split_extension(&name.0, is_folder).0).collect();
+            };
+            renumber_sequence_table(&names, &modifiers.renumber)
+        },
+        false => HashMap::new()
+    };
+    // Sequences among the folder's selected files, and each frame's sequence and place in it.
+    let (sequences, sequence_frames): (Vec<Sequence>, HashMap<String, (usize, usize)>) = match modifiers.sequence_enabled && sequence_active(&modifiers.sequence) && !is_folder {
+        true => {
+            let mut names: Vec<String> = selection.iter()
+                .filter(|item| item.folder == folder_index && !item.is_folder)
+                .map(|item| item.name.to_owned())
+                .collect();
+            if selection.len() < offset + file_names.len() {
+                names = file_names.iter().map(|name| name.0.to_owned()).collect();
+            };
+            let sequences = detect_sequences(&names);
+            let mut frames: HashMap<String, (usize, usize)> = HashMap::new();
+            for (sequence_index, sequence) in sequences.iter().enumerate() {
+                for (rank, (_, name_index)) in sequence.frames.iter().enumerate() {
+                    frames.insert(names[*name_index].to_owned(), (sequence_index, rank));
+                }
+            }
+            (sequences, frames)
+        },
+        false => (vec![], HashMap::new())
+    };
+    let counters: Vec<Vec<(usize, usize)>> = match modifiers.number_enabled {
+        true => modifiers.number.iter().map(|modnumber| number_counters(selection, offset, file_names.len(), modnumber)).collect(),
+        false => vec![]
+    };
     for (index, filename_raw) in file_names.iter().enumerate() {
-        let mut extension_raw = Path::new(&filename_raw.0).extension().unwrap_or_default().to_str().unwrap_or_default();
-        if extension_raw.contains(' ') {
-            // Clear the extension buffer if there's a space in it. False positive.
-            extension_raw = "";
-        };
-        if filename_raw.0.chars().count() >= 2 && &utils::get_utf8_slice(&filename_raw.0, 0, 2, false) == &".." {
-            extension_raw = "";
-        };
         let file_index: usize = filename_raw.1;
-        let file: String;
-        let ext: String;
-        if is_folder == true { // Don't try and find an extension in a folder name
-            extension_raw = "";
-        };
-        if !extension_raw.is_empty() {
-            let (f, e) = utils::split_uft8(&filename_raw.0, filename_raw.0.chars().count() - extension_raw.chars().count() - 1);
-            file = f; ext = e;
-        } else {
-            file = filename_raw.0.clone();
-            ext = String::new();
-        };
-        let mut file: String = file.to_string();
-        let mut ext: String = ext.to_string();
+        let (mut file, mut ext) = split_extension(&filename_raw.0, is_folder);
         let file_hash: String = match &filename_raw.2 {
             Some(hash) => {
                 hash.to_owned()
@@ -62,12 +227,15 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
                 String::new()
             }
         };
+        let file_original: String = file.clone();
+        let ext_original: String = ext.clone();
+        let file_path: String = format!("{}/{}", folder_path, filename_raw.0);
         for (_, modifier) in order.iter().enumerate() {
             match modifier {
                 ModsOrder::Add => {
                     if !modifiers.add_enabled { continue };
                     for mode in modifiers.add.clone() {
-                        let res = add(file.clone(), ext.clone(), mode);
+                        let res = add(file.clone(), ext.clone(), mode, &patterns);
                         file = res.0;
                         ext = res.1;
                     }
@@ -83,7 +251,7 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
                 ModsOrder::Date => {
                     if !modifiers.date_enabled { continue };
                     for mode in modifiers.date.clone() {
-                        let res = date(file.clone(), ext.clone(), mode);
+                        let res = date(file.clone(), ext.clone(), mode, &patterns);
                         file = res.0;
                         ext = res.1;
                     }
@@ -97,14 +265,14 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
                 ModsOrder::Hash => {
                     if !modifiers.hash_enable { continue };
                     if is_folder { continue };
-                    let res = hash(file.clone(), ext.clone(), modifiers.hash.clone(), file_hash.clone());
+                    let res = hash(file.clone(), ext.clone(), modifiers.hash.clone(), file_hash.clone(), &patterns);
                     file = res.0;
                     ext = res.1;
                 },
                 ModsOrder::MoveCopy => {
                     if !modifiers.movecopy_enabled { continue };
                     for mode in modifiers.movecopy.clone() {
-                        let res = movecopy(file.clone(), ext.clone(), mode);
+                        let res = movecopy(file.clone(), ext.clone(), mode, &patterns);
                         file = res.0;
                         ext = res.1;
                     }
@@ -119,24 +287,41 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
                 },
                 ModsOrder::Number => {
                     if !modifiers.number_enabled { continue };
-                    for mode in modifiers.number.clone() {
-                        let res = number(file.clone(), ext.clone(), mode, index);
-                        file = res.0;
-                        ext = res.1;
+                    for (mode_index, mode) in modifiers.number.clone().into_iter().enumerate() {
+                        match number(file.clone(), ext.clone(), mode, counters[mode_index][index], &patterns) {
+                            Ok(res) => {
+                                file = res.0;
+                                ext = res.1;
+                            },
+                            Err(err) => {
+                                invalid_numbers.push((file_index, err));
+                                break;
+                            }
+                        };
                     }
                 },
                 ModsOrder::Regex => {
                     if !modifiers.regex_enabled { continue };
-                    for mode in modifiers.regex.clone() {
-                        let res = regex(file.clone(), ext.clone(), mode);
-                        file = res.0;
-                        ext = res.1;
+                    if let Some(err) = &regex_error {
+                        invalid_regexes.push((file_index, err.to_owned()));
+                        continue;
+                    };
+                    for (mode_index, mode) in modifiers.regex.clone().into_iter().enumerate() {
+                        if let Some(reg) = &regexes[mode_index] {
+                            let res = regex(file.clone(), ext.clone(), mode, reg);
+                            file = res.0;
+                            ext = res.1;
+                        };
                     }
                 },
                 ModsOrder::Remove => {
                     if !modifiers.remove_enabled { continue };
+                    if let Some(err) = &remove_error {
+                        invalid_regexes.push((file_index, err.to_owned()));
+                        continue;
+                    };
                     for mode in modifiers.remove.clone() {
-                        let res = remove(file.clone(), ext.clone(), mode);
+                        let res = remove(file.clone(), ext.clone(), mode, &patterns);
                         file = res.0;
                         ext = res.1;
                     }
@@ -144,9 +329,140 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
                 ModsOrder::Replace => {
                     if !modifiers.replace_enabled { continue };
                     for mode in modifiers.replace.clone() {
-                        let res = replace(file.clone(), ext.clone(), mode);
+                        let res = replace(file.clone(), ext.clone(), mode, &patterns);
+                        file = res.0;
+                        ext = res.1;
+                    }
+                },
+                ModsOrder::Template => {
+                    if !modifiers.template_enabled { continue };
+                    let source = TemplateSource {
+                        name: &file_original,
+                        ext: &ext_original,
+                        path: &file_path,
+                        index: index,
+                        hash: &file_hash,
+                        algorithm: modifiers.hash.algorithm,
+                        is_folder: is_folder
+                    };
+                    for mode in modifiers.template.clone() {
+                        match template(file.clone(), ext.clone(), mode, &source) {
+                            Ok(res) => {
+                                file = res.0;
+                                ext = res.1;
+                            },
+                            Err(error) => {
+                                invalid_templates.push((file_index, error));
+                                break;
+                            }
+                        }
+                    }
+                },
+                ModsOrder::Transliterate => {
+                    if !modifiers.transliterate_enabled { continue };
+                    let res = transliterate(file.clone(), ext.clone(), modifiers.transliterate.clone());
+                    file = res.0;
+                    ext = res.1;
+                },
+                ModsOrder::Normalize => {
+                    if !modifiers.normalize_enabled { continue };
+                    let res = normalize(file.clone(), ext.clone(), modifiers.normalize.clone());
+                    file = res.0;
+                    ext = res.1;
+                },
+                ModsOrder::Dictionary => {
+                    if !modifiers.dictionary_enabled { continue };
+                    let res = dictionary(file.clone(), ext.clone(), &modifiers.dictionary, &dictionary_pairs);
+                    file = res.0;
+                    ext = res.1;
+                },
+                ModsOrder::Mapping => {
+                    if !modifiers.mapping_enabled { continue };
+                    if let Some(err) = &mapping_error {
+                        invalid_mappings.push((file_index, err.to_owned()));
+                        continue;
+                    };
+                    match mapping.get(&filename_raw.0) {
+                        Some(name_new) => {
+                            let res = split_extension(name_new, is_folder);
+                            file = res.0;
+                            ext = res.1;
+                        },
+                        None => {
+                            if !mapping.is_empty() {
+                                unmapped.push(file_index);
+                            };
+                        }
+                    }
+                },
+                ModsOrder::Segment => {
+                    if !modifiers.segment_enabled { continue };
+                    for mode in modifiers.segment.clone() {
+                        let res = segment(file.clone(), ext.clone(), mode);
+                        file = res.0;
+                        ext = res.1;
+                    }
+                },
+                ModsOrder::Sequence => {
+                    if !modifiers.sequence_enabled { continue };
+                    if let Some((sequence_index, rank)) = sequence_frames.get(&filename_raw.0) {
+                        let sequence = &sequences[*sequence_index];
+                        if !sequence.gaps().is_empty() {
+                            sequence_gaps.push((file_index, sequence.gaps_text()));
+                        };
+                        let res = sequence_frame(sequence, *rank, &modifiers.sequence);
                         file = res.0;
                         ext = res.1;
+                    };
+                },
+                ModsOrder::Random => {
+                    if !modifiers.random_enabled { continue };
+                    let res = random_name(file.clone(), ext.clone(), &modifiers.random, &file_path);
+                    file = res.0;
+                    ext = res.1;
+                },
+                ModsOrder::Truncate => {
+                    if !modifiers.truncate_enabled { continue };
+                    let res = truncate(file.clone(), ext.clone(), &modifiers.truncate, &truncate_taken, &truncate_tail);
+                    file = res.0;
+                    ext = res.1;
+                    truncate_taken.insert(format!("{}{}", file, ext));
+                },
+                ModsOrder::Seperators => {
+                    if !modifiers.seperators_enabled { continue };
+                    if let Some(err) = &seperator_error {
+                        invalid_regexes.push((file_index, err.to_owned()));
+                        continue;
+                    };
+                    file = seperators(&file, &modifiers.seperators, &seperator_protect);
+                },
+                ModsOrder::Renumber => {
+                    if !modifiers.renumber_enabled { continue };
+                    match renumber(file.clone(), ext.clone(), &modifiers.renumber, &renumber_sequence) {
+                        Ok(res) => {
+                            file = res.0;
+                            ext = res.1;
+                        },
+                        Err(err) => invalid_numbers.push((file_index, err))
+                    };
+                },
+                ModsOrder::NameList => {
+                    if !modifiers.name_list_enabled || name_list.is_empty() { continue };
+                    match name_list.get(offset + index) {
+                        Some(name_new) => {
+                            let res = split_extension(name_new, is_folder);
+                            if !modifiers.name_list.keep_extension {
+                                file = res.0;
+                                ext = res.1;
+                            } else if !ext.is_empty() && res.1.to_lowercase() == ext.to_lowercase() {
+                                // A line already ending in the item's extension isn't doubled up.
+                                file = res.0;
+                            } else {
+                                // Anything else after a dot is part of the name, like "Live Vol.2".
+                                file = name_new.to_owned();
+                            };
+                        },
+                        None => unlisted.push(file_index)
                     }
                 }
             }
@@ -161,6 +477,32 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
 
         files.push((format!("{}{}", file, ext), file_index, None));
     }
+    // Flag names in a different normalization form, and different names that end up the same once normalized.
+    // Collisions are checked against everything in the folder, not only this batch.
+    if modifiers.normalize_enabled && modifiers.normalize.mode != NormalizeMode::None {
+        let mut normalized: HashMap<String, Vec<&String>> = HashMap::new();
+        for filename_raw in file_names.iter() {
+            let form = normalize_form(&filename_raw.0, modifiers.normalize.mode);
+            if form != filename_raw.0 {
+                normalization_differs.push(filename_raw.1);
+            };
+            normalized.entry(form).or_default().push(&filename_raw.0);
+        }
+        for item in listing.iter() {
+            let names = normalized.entry(normalize_form(&item.name, modifiers.normalize.mode)).or_default();
+            if !names.contains(&&item.name) {
+                names.push(&item.name);
+            };
+        }
+        for filename_raw in file_names.iter() {
+            let form = normalize_form(&filename_raw.0, modifiers.normalize.mode);
+            let collision = normalized[&form].iter().find(|other| ***other != filename_raw.0);
+            if let Some(other) = collision {
+                normalization_collisions.push((filename_raw.1, other.to_string()));
+            };
+        }
+    };
+
     // Check for FS errors..
     for (index, file) in files.clone().iter().enumerate() {
         if cfg!(windows) {
@@ -180,10 +522,14 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
         for (f_index, f) in files.iter().enumerate() {
             if f_index != index {
                 if *file_name == f.0 {
-                    duplicates.push(index);
+                    duplicates.push(file.1);
                 }
             }
         }
+        // Renaming onto an item that stays would overwrite it.
+        if listing.iter().any(|item| !item.selected && item.name == *file_name) && !duplicates.contains(&file.1) {
+            duplicates.push(file.1);
+        };
 
         if file_name.chars().count() >= 256 {
             files_over_length.push((file.1, file_name.chars().count() as u32))
@@ -198,22 +544,85 @@ pub fn process(_index: usize, modifiers: &mut Modifiers, file_names: Vec<(String
     errors.push(ModifierThreadError::LengthLimitFileName(files_over_length));
     errors.push(ModifierThreadError::InvalidChar(invalid_chars));
     errors.push(ModifierThreadError::InvalidFileName(invalid_names));
+    errors.push(ModifierThreadError::InvalidTemplate(invalid_templates));
+    errors.push(ModifierThreadError::NormalizationDiffers(normalization_differs));
+    errors.push(ModifierThreadError::NormalizationCollision(normalization_collisions));
+    errors.push(ModifierThreadError::InvalidNumber(invalid_numbers));
+    errors.push(ModifierThreadError::InvalidRegex(invalid_regexes));
+    errors.push(ModifierThreadError::InvalidMapping(invalid_mappings));
+    errors.push(ModifierThreadError::Unmapped(unmapped));
+    errors.push(ModifierThreadError::ListTooShort(unlisted));
+    errors.push(ModifierThreadError::SequenceGaps(sequence_gaps));
     return (files, errors);
 }
 
-fn add(mut file: String, ext: String, modadd: ModAdd) -> (String, String) {
+/// Splits a name into (name, .ext) the way the modifiers see it.
+pub fn split_extension(filename: &str, is_folder: bool) -> (String, String) {
+    let mut extension_raw = Path::new(filename).extension().unwrap_or_default().to_str().unwrap_or_default();
+    if extension_raw.contains(' ') {
+        // Clear the extension buffer if there's a space in it. False positive.
+        extension_raw = "";
+    };
+    if filename.chars().count() >= 2 && &utils::get_utf8_slice(filename, 0, 2, false) == &".." {
+        extension_raw = "";
+    };
+    if is_folder == true { // Don't try and find an extension in a folder name
+        extension_raw = "";
+    };
+    if !extension_raw.is_empty() {
+        utils::split_uft8(filename, filename.chars().count() - extension_raw.chars().count() - 1)
+    } else {
+        (filename.to_string(), String::new())
+    }
+}
+
+/// Char index to insert at, negative positions count from the end.
+fn insert_position(file: &str, insert_at: i32) -> usize {
+    let count = file.chars().count() as u32;
+    if insert_at.is_negative() {
+        ((count + 1) - u32::try_from(insert_at * -1).unwrap().clamp(1, count + 1)) as usize
+    } else {
+        u32::try_from(insert_at.clamp(0, count as i32)).unwrap() as usize
+    }
+}
+
+/// Char index to insert at, following the anchor when there is one. 
+/// None when the anchor's missing and the fallback is to skip the insert.
+fn insert_index(file: &str, insert_at: i32, anchor: &InsertAnchor, patterns: &Patterns) -> Option<usize> {
+    if anchor.mode == AnchorMode::Position {
+        return Some(insert_position(file, insert_at));
+    };
+    let spans = crop_spans(file, &anchor.pattern, anchor.regex, patterns);
+    let found = match anchor.mode {
+        AnchorMode::BeforeFirst | AnchorMode::AfterFirst => spans.first(),
+        _ => spans.last()
+    };
+    match found {
+        Some((start, end)) => {
+            let at = match anchor.mode {
+                AnchorMode::BeforeFirst | AnchorMode::BeforeLast => *start,
+                _ => *end
+            };
+            Some(file[..at].chars().count())
+        },
+        None => match anchor.fallback {
+            AnchorFallback::Skip => None,
+            AnchorFallback::Position => Some(insert_position(file, insert_at)),
+            AnchorFallback::Start => Some(0),
+            AnchorFallback::End => Some(file.chars().count())
+        }
+    }
+}
+
+fn add(mut file: String, ext: String, modadd: ModAdd, patterns: &Patterns) -> (String, String) {
     if !modadd.prefix.is_empty() {
         file = format!("{}{}", modadd.prefix, file);
     };
     if !modadd.insert.is_empty() {
-        let insert_at: u32;
-        if modadd.insert_at.is_negative() {
-            insert_at = (file.chars().count() as u32 + 1) - u32::try_from(modadd.insert_at * -1).unwrap().clamp(1, file.chars().count() as u32 + 1);
-        } else {
-            insert_at = u32::try_from(modadd.insert_at.clone().clamp(0, file.chars().count() as i32)).unwrap();
-        }
-        let (start, end) = utils::split_uft8(&file, insert_at as usize);
-        file = format!("{}{}{}", start, modadd.insert.to_owned(), end);
+        if let Some(insert_at) = insert_index(&file, modadd.insert_at, &modadd.anchor, patterns) {
+            let (start, end) = utils::split_uft8(&file, insert_at);
+            file = format!("{}{}{}", start, modadd.insert.to_owned(), end);
+        };
     }
     if !modadd.suffix.is_empty() {
         file = format!("{}{}", file, modadd.suffix);
@@ -222,6 +631,7 @@ fn add(mut file: String, ext: String, modadd: ModAdd) -> (String, String) {
 }
 
 fn case(mut file: String, ext: String, modcase: ModCase) -> (String, String) {
+    let protected: Vec<String> = case_word_list(&modcase.protected_words);
     match modcase.mode {
         CaseMode::Lower => {
             match modcase.except_mode {
@@ -241,14 +651,14 @@ fn case(mut file: String, ext: String, modcase: ModCase) -> (String, String) {
                             file = format!(
                                 "{}{}",
                                 keep,
-                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_ascii_lowercase()
+                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_lowercase()
                             )
                         } else if clamped_from != clamped_to {
                             file = format!(
                                 "{}{}{}",
-                                utils::get_utf8_slice(&file, 0, clamped_from as usize, false).to_ascii_lowercase(),
+                                utils::get_utf8_slice(&file, 0, clamped_from as usize, false).to_lowercase(),
                                 keep,
-                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_ascii_lowercase()
+                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_lowercase()
                             );
                         } else {
                             file = file.to_lowercase();
@@ -260,46 +670,40 @@ fn case(mut file: String, ext: String, modcase: ModCase) -> (String, String) {
                 CaseExecptMode::Match => {
                     match file.find(&modcase.except) {
                         Some(index) => {
+                            let index = file[..index].chars().count();
                             let (start, _mid) = utils::split_uft8(&file, index);
                             let (_mid, end) = utils::split_uft8(&file, index + modcase.except.chars().count());
-                            file = format!("{}{}{}", start.to_ascii_lowercase(), modcase.except, end.to_ascii_lowercase());
+                            file = format!("{}{}{}", start.to_lowercase(), modcase.except, end.to_lowercase());
                         },
-                        _ => { file = file.to_ascii_lowercase() } // Do nothing, couldn't find a match.
+                        _ => { file = file.to_lowercase() } // Do nothing, couldn't find a match.
                     }
                 },
                 CaseExecptMode::None => {
-                    file = file.to_ascii_lowercase();
+                    file = file.to_lowercase();
                 }
             }
         },
         CaseMode::Title => {
-            let words_raw: Vec<&str> = file.split(' ').collect();
-            let mut words: Vec<String> = vec![];
+            let small_words = case_small_words(&modcase);
+            let words = case_split_seperated(&file);
+            let last_word = words.iter().rposition(|(word, _)| !word.is_empty());
             let mut file_new: String = String::new();
             let mut first_word = true;
-            for word_index in 0..words_raw.len() {
-                let mut word = words_raw[word_index].to_string();
+            for (index, (mut word, seperator)) in words.into_iter().enumerate() {
                 if word.chars().count() != 0 {
-                    if first_word == false && NONTITLE_WORDS.contains(&word.as_str()) {
-                        word = word.to_ascii_lowercase();
+                    let last = Some(index) == last_word;
+                    if first_word == false && !last && small_words.contains(&word.to_lowercase()) {
+                        word = word.to_lowercase();
                     } else if !word.starts_with(&['{', '[', '(']) && !word.ends_with(&['}', ']', ')']){
                         first_word = false;
-                        if word.ends_with('.') {
+                        if word.ends_with('.') || seperator.contains('.') {
                             first_word = true;
                         }
-                        word = word.to_ascii_lowercase();
-                        let start = word.remove(0);
-                        word.insert(0, start.to_ascii_uppercase());
+                        word = case_capitalize(&word);
                     }
-                    words.push(word);
-                };
-            }
-            for word_index in 0..words.len() {
-                if word_index == 0 {
-                    file_new = words[0].clone();
-                } else {
-                    file_new = format!("{} {}", file_new, words[word_index]);
                 };
+                file_new.push_str(&word);
+                file_new.push_str(&seperator);
             }
             file = file_new;
         },
@@ -320,66 +724,195 @@ fn case(mut file: String, ext: String, modcase: ModCase) -> (String, String) {
                             file = format!(
                                 "{}{}",
                                 keep,
-                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_ascii_uppercase()
+                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_uppercase()
                             )
                         } else if clamped_from != clamped_to {
                             file = format!(
                                 "{}{}{}",
-                                utils::get_utf8_slice(&file, 0, clamped_from as usize, false).to_ascii_uppercase(),
+                                utils::get_utf8_slice(&file, 0, clamped_from as usize, false).to_uppercase(),
                                 keep,
-                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_ascii_uppercase()
+                                utils::get_utf8_slice(&file, clamped_to as usize, file.chars().count(), false).to_uppercase()
                             );
                         } else {
-                            file = file.to_ascii_uppercase();
+                            file = file.to_uppercase();
                         }
                     } else {
-                        file = file.to_ascii_uppercase();
+                        file = file.to_uppercase();
                     };
                 },
                 CaseExecptMode::Match => {
                     match file.find(&modcase.except) {
                         Some(index) => {
+                            let index = file[..index].chars().count();
                             let (start, _mid) = utils::split_uft8(&file, index);
                             let (_mid, end) = utils::split_uft8(&file, index + modcase.except.chars().count());
-                            file = format!("{}{}{}", start.to_ascii_uppercase(), modcase.except, end.to_ascii_uppercase());
+                            file = format!("{}{}{}", start.to_uppercase(), modcase.except, end.to_uppercase());
                         },
-                        _ => { file = file.to_ascii_uppercase() } // Do nothing, couldn't find a match.
+                        _ => { file = file.to_uppercase() } // Do nothing, couldn't find a match.
                     }
                 },
                 CaseExecptMode::None => {
-                    file = file.to_ascii_uppercase();
+                    file = file.to_uppercase();
                 }
             }
         }, 
         CaseMode::UpperFirst => {
-            let words_raw: Vec<&str> = file.split(' ').collect();
-            let mut words: Vec<String> = vec![];
             let mut file_new: String = String::new();
-            if words_raw.len() != 0 {
-                for word_index in 0..words_raw.len() {
-                    let mut word = words_raw[word_index].to_string().to_ascii_lowercase();
-                    if word.chars().count() != 0 {
-                        let start = word.remove(0);
-                        word.insert(0, start.to_ascii_uppercase());
-                        words.push(word)
-                    };
-                }
-                for word_index in 0..words.len() {
-                    if word_index == 0 {
-                        file_new = words[0].clone();
-                    } else {
-                        file_new = format!("{} {}", file_new, words[word_index]);
-                    };
-                }
+            for (word, seperator) in case_split_seperated(&file) {
+                file_new.push_str(&case_capitalize(&word));
+                file_new.push_str(&seperator);
             }
             file = file_new;
         },
+        CaseMode::Camel => {
+            file = case_words(&file, &protected).iter().enumerate().map(|(index, word)| {
+                case_protected(word, &protected).unwrap_or_else(|| {
+                    if index == 0 { word.to_lowercase() } else { case_capitalize(word) }
+                })
+            }).collect();
+        },
+        CaseMode::Pascal => {
+            file = case_words(&file, &protected).iter().map(|word| {
+                case_protected(word, &protected).unwrap_or_else(|| case_capitalize(word))
+            }).collect();
+        },
+        CaseMode::Snake => {
+            file = case_words(&file, &protected).iter().map(|word| {
+                case_protected(word, &protected).unwrap_or_else(|| word.to_lowercase())
+            }).collect::<Vec<String>>().join("_");
+        },
+        CaseMode::Kebab => {
+            file = case_words(&file, &protected).iter().map(|word| {
+                case_protected(word, &protected).unwrap_or_else(|| word.to_lowercase())
+            }).collect::<Vec<String>>().join("-");
+        },
+        CaseMode::Constant => {
+            file = case_words(&file, &protected).iter().map(|word| {
+                case_protected(word, &protected).unwrap_or_else(|| word.to_uppercase())
+            }).collect::<Vec<String>>().join("_");
+        },
+        CaseMode::Sentence => {
+            file = case_words(&file, &protected).iter().enumerate().map(|(index, word)| {
+                case_protected(word, &protected).unwrap_or_else(|| {
+                    if index == 0 { case_capitalize(word) } else { word.to_lowercase() }
+                })
+            }).collect::<Vec<String>>().join(" ");
+        },
         _ => {} // Same, do nothing
     };
+
+    // Put protected words back the way they were written.
+    match modcase.mode {
+        CaseMode::Upper | CaseMode::Lower | CaseMode::Title | CaseMode::UpperFirst if !protected.is_empty() => {
+            let mut file_new: String = String::new();
+            for (word, seperator) in case_split_seperated(&file) {
+                file_new.push_str(&case_protected(&word, &protected).unwrap_or(word));
+                file_new.push_str(&seperator);
+            }
+            file = file_new;
+        },
+        _ => {}
+    };
     (file, ext)
 }
 
-fn date(mut file: String, ext: String, moddate: ModDate) -> (String, String) {
+/// Splits a user entered word list on spaces and commas.
+fn case_word_list(list: &str) -> Vec<String> {
+    list.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_string())
+        .collect()
+}
+
+fn case_small_words(modcase: &ModCase) -> Vec<String> {
+    let language: &[&str] = match modcase.title_language {
+        TitleLanguage::None => &[],
+        TitleLanguage::English => &NONTITLE_WORDS_ENGLISH,
+        TitleLanguage::French => &NONTITLE_WORDS_FRENCH,
+        TitleLanguage::German => &NONTITLE_WORDS_GERMAN,
+        TitleLanguage::Spanish => &NONTITLE_WORDS_SPANISH,
+        TitleLanguage::Italian => &NONTITLE_WORDS_ITALIAN,
+        TitleLanguage::Portuguese => &NONTITLE_WORDS_PORTUGUESE,
+        TitleLanguage::Dutch => &NONTITLE_WORDS_DUTCH
+    };
+    let mut small_words: Vec<String> = language.iter().map(|word| word.to_string()).collect();
+    small_words.extend(case_word_list(&modcase.small_words).iter().map(|word| word.to_lowercase()));
+    small_words
+}
+
+/// Returns the protected spelling of a word if it's on the list, ignoring case.
+fn case_protected(word: &str, protected: &Vec<String>) -> Option<String> {
+    let word = word.to_lowercase();
+    protected.iter().find(|p| p.to_lowercase() == word).cloned()
+}
+
+/// Uppercases the first character of a word and lowercases the rest.
+fn case_capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}", first.to_uppercase(), chars.as_str().to_lowercase()),
+        None => String::new()
+    }
+}
+
+fn case_is_seperator(c: char) -> bool {
+    c.is_whitespace() || CASE_SEPERATORS.contains(&c)
+}
+
+/// Splits on seperators but keeps them, so Title/UpperFirst can put the name back together as it was.
+fn case_split_seperated(file: &str) -> Vec<(String, String)> {
+    let mut words: Vec<(String, String)> = vec![];
+    let mut word: String = String::new();
+    let mut seperator: String = String::new();
+    for c in file.chars() {
+        if case_is_seperator(c) {
+            seperator.push(c);
+        } else {
+            if !seperator.is_empty() {
+                words.push((word.clone(), seperator.clone()));
+                word.clear();
+                seperator.clear();
+            };
+            word.push(c);
+        }
+    }
+    if !word.is_empty() || !seperator.is_empty() {
+        words.push((word, seperator));
+    };
+    words
+}
+
+/// Breaks a name into words on seperators and camel humps, keeping acronyms together ("HTTPServer" -> "HTTP", "Server").
+/// Protected words are never split.
+fn case_words(file: &str, protected: &Vec<String>) -> Vec<String> {
+    let mut words: Vec<String> = vec![];
+    for (chunk, _) in case_split_seperated(file) {
+        if chunk.is_empty() { continue };
+        if case_protected(&chunk, protected).is_some() {
+            words.push(chunk);
+            continue;
+        };
+        let chars: Vec<char> = chunk.chars().collect();
+        let mut word: String = String::new();
+        for (index, c) in chars.iter().enumerate() {
+            if c.is_uppercase() && index != 0 && !word.is_empty() {
+                let previous = chars[index - 1];
+                let next_lower = chars.get(index + 1).map(|n| n.is_lowercase()).unwrap_or(false);
+                if previous.is_lowercase() || previous.is_numeric() || (previous.is_uppercase() && next_lower) {
+                    words.push(word.clone());
+                    word.clear();
+                };
+            };
+            word.push(*c);
+        }
+        if !word.is_empty() {
+            words.push(word);
+        };
+    }
+    words
+}
+
+fn date(mut file: String, ext: String, moddate: ModDate, patterns: &Patterns) -> (String, String) {
     let mut date_string: String = String::new();
     let mut date_year: Vec<String> = vec![];
     let mut date_hour: Vec<String> = vec![];
@@ -507,14 +1040,10 @@ fn date(mut file: String, ext: String, moddate: ModDate) -> (String, String) {
                         file = format!("{}{}", file, date_string);
                     },
                     DateMode::Insert => {
-                        let insert_at: u32;
-                        if moddate.at_pos.is_negative() {
-                            insert_at = (file.chars().count() as u32 + 1) - u32::try_from(moddate.at_pos * -1).unwrap().clamp(1, file.chars().count() as u32 + 1);
-                        } else {
-                            insert_at = u32::try_from(moddate.at_pos.clone().clamp(0, file.chars().count() as i32)).unwrap();
-                        }
-                        let (start, end) = utils::split_uft8(&file, insert_at as usize);
-                        file = format!("{}{}{}", start, date_string, end);
+                        if let Some(insert_at) = insert_index(&file, moddate.at_pos, &moddate.anchor, patterns) {
+                            let (start, end) = utils::split_uft8(&file, insert_at);
+                            file = format!("{}{}{}", start, date_string, end);
+                        };
                     },
                     _ => {}
                 }
@@ -644,18 +1173,12 @@ fn date(mut file: String, ext: String, moddate: ModDate) -> (String, String) {
             file = format!("{} {}", file, date_string);
         },
         DateMode::Insert => {
-            let insert_at: u32;
-            if moddate.at_pos == 0 {
+            if moddate.anchor.mode == AnchorMode::Position && moddate.at_pos == 0 {
                 file = format!("{} {}", date_string, file);
-            } else if moddate.at_pos == file.chars().count() as i32 {
+            } else if moddate.anchor.mode == AnchorMode::Position && moddate.at_pos == file.chars().count() as i32 {
                 file = format!("{} {}", file, date_string);
-            } else {
-                if moddate.at_pos.is_negative() {
-                    insert_at = (file.chars().count() as u32 + 1) - u32::try_from(moddate.at_pos * -1).unwrap().clamp(1, file.chars().count() as u32 + 1);
-                } else {
-                    insert_at = u32::try_from(moddate.at_pos.clone().clamp(0, file.chars().count() as i32)).unwrap();
-                }
-                let (start, end) = utils::split_uft8(&file, insert_at as usize);
+            } else if let Some(insert_at) = insert_index(&file, moddate.at_pos, &moddate.anchor, patterns) {
+                let (start, end) = utils::split_uft8(&file, insert_at);
                 file = format!("{} {} {}", start, date_string, end);
             }
         },
@@ -691,26 +1214,30 @@ fn extension(file: String, mut ext: String, modext: ModExtension) -> (String, St
     (file, ext)
 }
 
-fn hash(mut file: String, ext: String, modhash: ModHashing, file_hash: String) -> (String, String) {
+fn hash_placeholder(algorithm: HashType) -> String {
+    match algorithm {
+        HashType::CRC32 => {
+            String::from("xxCRC32x")
+        },
+        HashType::MD5 => {
+            String::from("xxxxxxxxxxxxxxxMD5xxxxxxxxxxxxxx")
+        },
+        HashType::Sha1 => {
+            String::from("xxxxxxxxxxxxxxxxxxxSHA1xxxxxxxxxxxxxxxxx")
+        },
+        HashType::Sha256 => {
+            String::from("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxSHA256xxxxxxxxxxxxxxxxxxxxxxxxxxxx")
+        }
+    }
+}
+
+fn hash(mut file: String, ext: String, modhash: ModHashing, file_hash: String, patterns: &Patterns) -> (String, String) {
     let mut fake_hash: String = match file_hash.len() != 0 {
         true => {
             file_hash
         },
         false => {
-            match modhash.algorithm {
-                HashType::CRC32 => {
-                    String::from("xxCRC32x")
-                },
-                HashType::MD5 => {
-                    String::from("xxxxxxxxxxxxxxxMD5xxxxxxxxxxxxxx")
-                },
-                HashType::Sha1 => {
-                    String::from("xxxxxxxxxxxxxxxxxxxSHA1xxxxxxxxxxxxxxxxx")
-                },
-                HashType::Sha256 => {
-                    String::from("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxSHA256xxxxxxxxxxxxxxxxxxxxxxxxxxxx")
-                }
-            }
+            hash_placeholder(modhash.algorithm)
         }
     };
 
@@ -758,13 +1285,23 @@ fn hash(mut file: String, ext: String, modhash: ModHashing, file_hash: String) -
         HashMode::Suffix => {
             file = format!("{} {}", file, fake_hash);
         },
+        HashMode::Insert => {
+            if let Some(insert_at) = insert_index(&file, modhash.insert_at, &modhash.anchor, patterns) {
+                let (start, end) = utils::split_uft8(&file, insert_at);
+                file = format!("{}{}{}", start, fake_hash, end);
+            };
+        },
         HashMode::File => {} // Do nothing here.
         HashMode::None => {} // Do nothing here.
     }
     (file, ext)
 }
 
-fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String, String) {
+fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy, patterns: &Patterns) -> (String, String) {
+    let to_match = modmovecopy.mode_to == MoveCopyToMode::BeforeMatch || modmovecopy.mode_to == MoveCopyToMode::AfterMatch;
+    if modmovecopy.unit != MoveCopyUnit::Letters || to_match {
+        return (movecopy_span(file, &modmovecopy, patterns), ext);
+    };
     let clamped_from: u32 = modmovecopy.letters_count.clamp(0, file.chars().count() as u32);
     let clamped_to: u32 = modmovecopy.mode_to_pos.clamp(0, file.chars().count() as u32);
     match modmovecopy.mode_from {
@@ -790,7 +1327,7 @@ fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String,
                         };
                     };
                 }, 
-                MoveCopyToMode::None => {} // Do nothing
+                _ => {} // Do nothing
             };
         },
         MoveCopyFromMode::CopyLastN => {
@@ -813,7 +1350,7 @@ fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String,
                         };
                     };
                 }, 
-                MoveCopyToMode::None => {} // Do nothing
+                _ => {} // Do nothing
             };
 
         },
@@ -841,7 +1378,7 @@ fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String,
                         };
                     };
                 }, 
-                MoveCopyToMode::None => {} // Do nothing
+                _ => {} // Do nothing
             };
 
         },
@@ -867,7 +1404,7 @@ fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String,
                         };
                     };
                 }, 
-                MoveCopyToMode::None => {} // Do nothing
+                _ => {} // Do nothing
             };
         },
         MoveCopyFromMode::None => {} // Do nothing
@@ -875,6 +1412,129 @@ fn movecopy(mut file: String, ext: String, modmovecopy: ModMoveCopy) -> (String,
     (file, ext)
 }
 
+/// Byte span of what the move / copy picks up: N letters, N words or a regex match.
+fn movecopy_source(file: &str, modmovecopy: &ModMoveCopy, patterns: &Patterns) -> Option<(usize, usize)> {
+    let first = modmovecopy.mode_from == MoveCopyFromMode::CopyFirstN || modmovecopy.mode_from == MoveCopyFromMode::MoveFirstN;
+    let count = modmovecopy.letters_count as usize;
+    match modmovecopy.unit {
+        MoveCopyUnit::Letters => {
+            let bounds: Vec<usize> = file.char_indices().map(|(index, _)| index).chain(std::iter::once(file.len())).collect();
+            let count = count.min(bounds.len() - 1);
+            if count == 0 { return None };
+            match first {
+                true => Some((0, bounds[count])),
+                false => Some((bounds[bounds.len() - 1 - count], file.len()))
+            }
+        },
+        MoveCopyUnit::Words => {
+            let mut words: Vec<(usize, usize)> = vec![];
+            let mut start: Option<usize> = None;
+            for (index, char) in file.char_indices() {
+                let is_delimiter = modmovecopy.word_delimiters.contains(char);
+                match (start, is_delimiter) {
+                    (None, false) => start = Some(index),
+                    (Some(word_start), true) => {
+                        words.push((word_start, index));
+                        start = None;
+                    },
+                    _ => {}
+                }
+            }
+            if let Some(word_start) = start {
+                words.push((word_start, file.len()));
+            };
+            let count = count.min(words.len());
+            if count == 0 { return None };
+            match first {
+                true => Some((words[0].0, words[count - 1].1)),
+                false => Some((words[words.len() - count].0, words[words.len() - 1].1))
+            }
+        },
+        MoveCopyUnit::Regex => {
+            if modmovecopy.unit_regex.is_empty() { return None };
+            let reg = patterns.get(&modmovecopy.unit_regex, false)?;
+            let mut found = reg.find_iter(file).filter(|found| !found.is_empty());
+            let found = match first {
+                true => found.next(),
+                false => found.last()
+            }?;
+            Some((found.start(), found.end()))
+        }
+    }
+}
+
+/// Move / copy for the Words and Regex units and the Before / After Match destinations. 
+/// Names without the source or the marker are left alone.
+fn movecopy_span(file: String, modmovecopy: &ModMoveCopy, patterns: &Patterns) -> String {
+    if modmovecopy.mode_from == MoveCopyFromMode::None || modmovecopy.mode_to == MoveCopyToMode::None {
+        return file;
+    };
+    let (start, end) = match movecopy_source(&file, modmovecopy, patterns) {
+        Some(span) => span,
+        None => return file
+    };
+    let is_delimiter = |char: char| modmovecopy.word_delimiters.contains(char);
+    let piece: &str = match modmovecopy.unit {
+        MoveCopyUnit::Letters => &file[start..end],
+        _ => file[start..end].trim_matches(is_delimiter)
+    };
+    let moving = modmovecopy.mode_from == MoveCopyFromMode::MoveFirstN || modmovecopy.mode_from == MoveCopyFromMode::MoveLastN;
+    let rest: String = match moving {
+        true => {
+            // Don't leave the delimiters that surrounded the piece hanging.
+            let before = &file[..start];
+            let after = &file[end..];
+            if before.is_empty() {
+                after.trim_start_matches(is_delimiter).to_string()
+            } else if after.is_empty() {
+                before.trim_end_matches(is_delimiter).to_string()
+            } else if before.ends_with(is_delimiter) {
+                format!("{}{}", before, after.trim_start_matches(is_delimiter))
+            } else {
+                format!("{}{}", before, after)
+            }
+        },
+        false => file.to_owned()
+    };
+    let seperator: String = modmovecopy.seperator.to_string();
+    let joiner: &str = match modmovecopy.seperator_enabled {
+        true => &seperator,
+        false => " "
+    };
+    // Only add the seperator on a side that doesn't already have one.
+    let insert = |left: &str, right: &str| -> String {
+        let seperator_left = match modmovecopy.seperator_enabled && !left.is_empty() && !left.ends_with(modmovecopy.seperator) {
+            true => seperator.as_str(),
+            false => ""
+        };
+        let seperator_right = match modmovecopy.seperator_enabled && !right.is_empty() && !right.starts_with(modmovecopy.seperator) {
+            true => seperator.as_str(),
+            false => ""
+        };
+        format!("{}{}{}{}{}", left, seperator_left, piece, seperator_right, right)
+    };
+    match modmovecopy.mode_to {
+        MoveCopyToMode::ToStart => format!("{}{}{}", piece, joiner, rest),
+        MoveCopyToMode::ToEnd => format!("{}{}{}", rest, joiner, piece),
+        MoveCopyToMode::ToPos => {
+            let (left, right) = utils::split_uft8(&rest, (modmovecopy.mode_to_pos as usize).min(rest.chars().count()));
+            insert(&left, &right)
+        },
+        MoveCopyToMode::BeforeMatch | MoveCopyToMode::AfterMatch => {
+            let marker = match crop_spans(&rest, &modmovecopy.mode_to_match, modmovecopy.mode_to_regex, patterns).first() {
+                Some(span) => *span,
+                None => return file
+            };
+            let at = match modmovecopy.mode_to {
+                MoveCopyToMode::BeforeMatch => marker.0,
+                _ => marker.1
+            };
+            insert(&rest[..at], &rest[at..])
+        },
+        MoveCopyToMode::None => file
+    }
+}
+
 fn name(mut file: String, ext: String, modname: ModName) -> (String, String) {
     match modname.mode {
         NameMode::Remove => {
@@ -916,95 +1576,202 @@ fn name(mut file: String, ext: String, modname: ModName) -> (String, String) {
     (file, ext)
 }
 
-fn number(mut file: String, ext: String, modnumber: ModNumber, file_index: usize) -> (String, String) {
-    let mut num_string: String;
+/// A count in the modifier's number system, without padding. Counts the system can't write are an error.
+fn number_string(value: i64, modnumber: &ModNumber) -> Result<String, String> {
+    // The alpha counters start at 'a' for both 0 and 1.
+    let alpha_index: i64 = match modnumber.starting_num == 0 {
+        true => value,
+        false => value - 1
+    };
+    let sign: &str = match value.is_negative() {
+        true => "-",
+        false => ""
+    };
+    let is_alpha = matches!(modnumber.mode_type, 
+        NumberTypeMode::AlphaLower | NumberTypeMode::AlphaUpper | NumberTypeMode::AlphaLowerToUpper | NumberTypeMode::Custom);
+    if is_alpha && alpha_index < 0 {
+        return Err(format!("{} is before the first letter, letters can't count below it", value));
+    };
+    let alpha_index: usize = alpha_index.max(0) as usize;
     match modnumber.mode_type {
-        NumberTypeMode::AlphaLower => {
-            if modnumber.starting_num == 0 {
-                num_string = alpha_counter::AlphaCounter::lower(modnumber.starting_num as usize + file_index).to_string()
-            } else {
-                num_string = alpha_counter::AlphaCounter::lower(modnumber.starting_num as usize + file_index - 1).to_string()
-            }
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, 'a');
-                };
-            };
-        },
+        NumberTypeMode::AlphaLower => Ok(alpha_counter::AlphaCounter::lower(alpha_index).to_string()),
         NumberTypeMode::AlphaLowerToUpper => {
             let alpha_lower_upper: String = ALPHA_LOWER_UPPER.iter().collect();
-            if modnumber.starting_num == 0 {
-                num_string = alpha_counter::AlphaCounter::custom(modnumber.starting_num as usize + file_index, alpha_lower_upper.as_str()).to_string()
-            } else {
-                num_string = alpha_counter::AlphaCounter::custom(modnumber.starting_num as usize + file_index - 1, alpha_lower_upper.as_str()).to_string()
-            }
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, 'a');
+            Ok(alpha_counter::AlphaCounter::custom(alpha_index, alpha_lower_upper.as_str()).to_string())
+        },
+        NumberTypeMode::AlphaUpper => Ok(alpha_counter::AlphaCounter::upper(alpha_index).to_string()),
+        NumberTypeMode::Custom => {
+            let mut letters: Vec<char> = vec![];
+            for char in modnumber.alphabet.chars() {
+                if letters.contains(&char) {
+                    return Err(format!("'{}' is in the alphabet more than once", char));
                 };
-            };
-        },
-        NumberTypeMode::AlphaUpper => {
-            if modnumber.starting_num == 0 {
-                num_string = alpha_counter::AlphaCounter::upper(modnumber.starting_num as usize + file_index).to_string()
-            } else {
-                num_string = alpha_counter::AlphaCounter::upper(modnumber.starting_num as usize + file_index - 1).to_string()
+                letters.push(char);
+            }
+            match letters.is_empty() {
+                true => Err(String::from("The alphabet is empty")),
+                false => Ok(alpha_counter::AlphaCounter::custom(alpha_index, &modnumber.alphabet).to_string())
             }
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, 'A');
-                };
-            };
-        },
-        NumberTypeMode::BaseTwo => {
-            num_string = format!("{:b}", (modnumber.starting_num + file_index as u32) * modnumber.increment_num);
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, '0');
-                };
-            };
-        },
-        NumberTypeMode::BaseEight => {
-            num_string = format!("{:o}", (modnumber.starting_num + file_index as u32)  * modnumber.increment_num);
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, '0');
-                };
-            };
-        },
-        NumberTypeMode::BaseTen => {
-            num_string = format!("{}", (modnumber.starting_num + file_index as u32) * modnumber.increment_num);
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, '0');
-                };
-            };
-        },
-        NumberTypeMode::BaseSixteen => {
-            num_string = format!("{:x}", (modnumber.starting_num + file_index as u32) * modnumber.increment_num);
-            if num_string.chars().count() < modnumber.padding as usize {
-                let padding: u32 = modnumber.padding - num_string.chars().count() as u32;
-                for _ in 0..padding {
-                    num_string.insert(0, '0');
-                };
-            };
         },
+        NumberTypeMode::BaseTwo => Ok(format!("{}{:b}", sign, value.unsigned_abs())),
+        NumberTypeMode::BaseEight => Ok(format!("{}{:o}", sign, value.unsigned_abs())),
+        NumberTypeMode::BaseTen | NumberTypeMode::Ordinal => Ok(format!("{}{}", sign, value.unsigned_abs())),
+        NumberTypeMode::BaseSixteen => Ok(format!("{}{:x}", sign, value.unsigned_abs())),
+        NumberTypeMode::BaseSixteenUpper => Ok(format!("{}{:X}", sign, value.unsigned_abs())),
+        NumberTypeMode::BaseThirtySix => Ok(format!("{}{}", sign, number_base(value.unsigned_abs(), BASE_DIGITS))),
+        NumberTypeMode::BaseSixtyTwo => Ok(format!("{}{}", sign, number_base(value.unsigned_abs(), BASE_SIXTY_TWO_DIGITS))),
         NumberTypeMode::RomanNumeral => {
-            if modnumber.starting_num != 0 {
-                num_string = format!("{:X}", numerals::roman::Roman::from((modnumber.starting_num + file_index as u32) as i16))
+            if value >= 1 && value <= ROMAN_MAXIMUM {
+                Ok(format!("{:X}", numerals::roman::Roman::from(value as i16)))
             } else {
-                num_string = String::new()
+                Err(format!("{} can't be written in Roman numerals, they go from 1 to {}", value, ROMAN_MAXIMUM))
             }
-        }
-    };
+        },
+        NumberTypeMode::Words => Ok(number_words(value))
+    }
+}
 
-    match modnumber.mode {
+/// `value` written with `digits`, the length of which is the base.
+fn number_base(mut value: u64, digits: &str) -> String {
+    let digits: Vec<char> = digits.chars().collect();
+    let base = digits.len() as u64;
+    let mut written: Vec<char> = vec![];
+    loop {
+        written.push(digits[(value % base) as usize]);
+        value /= base;
+        if value == 0 { break };
+    }
+    written.iter().rev().collect()
+}
+
+/// `value` spelled out in English, "one hundred twenty-one".
+fn number_words(value: i64) -> String {
+    if value == 0 {
+        return WORDS_ONES[0].to_string();
+    };
+    let below_thousand = |value: u64| -> Vec<String> {
+        let mut words: Vec<String> = vec![];
+        if value >= 100 {
+            words.push(format!("{} hundred", WORDS_ONES[(value / 100) as usize]));
+        };
+        match value % 100 {
+            0 => {},
+            rest if rest < 20 => words.push(WORDS_ONES[rest as usize].to_string()),
+            rest if rest % 10 == 0 => words.push(WORDS_TENS[(rest / 10) as usize].to_string()),
+            rest => words.push(format!("{}-{}", WORDS_TENS[(rest / 10) as usize], WORDS_ONES[(rest % 10) as usize]))
+        };
+        words
+    };
+    let mut rest: u64 = value.unsigned_abs();
+    let mut groups: Vec<String> = vec![];
+    for scale in WORDS_SCALES {
+        let group = rest % 1000;
+        if group != 0 {
+            let mut words = below_thousand(group);
+            if !scale.is_empty() {
+                words.push(scale.to_string());
+            };
+            groups.insert(0, words.join(" "));
+        };
+        rest /= 1000;
+        if rest == 0 { break };
+    }
+    match value.is_negative() {
+        true => format!("minus {}", groups.join(" ")),
+        false => groups.join(" ")
+    }
+}
+
+/// The ordinal suffix for a count, "st" for 21.
+fn number_ordinal(value: i64) -> &'static str {
+    let value = value.unsigned_abs();
+    match (value % 10, value % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th"
+    }
+}
+
+/// Pads a count to `width` characters, after the sign.
+fn number_pad(num_string: String, width: usize, modnumber: &ModNumber) -> String {
+    let pad_char: char = match modnumber.mode_type {
+        NumberTypeMode::AlphaLower | NumberTypeMode::AlphaLowerToUpper => 'a',
+        NumberTypeMode::AlphaUpper => 'A',
+        NumberTypeMode::Custom => match modnumber.alphabet.chars().next() {
+            Some(char) => char,
+            None => return num_string
+        },
+        NumberTypeMode::RomanNumeral | NumberTypeMode::Words => return num_string,
+        _ => '0'
+    };
+    let (sign, digits) = match num_string.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", num_string.as_str())
+    };
+    let count = digits.chars().count();
+    if count >= width {
+        return num_string.to_owned();
+    };
+    format!("{}{}{}", sign, pad_char.to_string().repeat(width - count), digits)
+}
+
+/// Where each item sits in its counter's scope and how big that scope is, for the items from `offset` to `offset + count`. 
+/// Falls back to the batch on its own when the selection doesn't cover it.
+fn number_counters(selection: &[SelectionItem], offset: usize, count: usize, modnumber: &ModNumber) -> Vec<(usize, usize)> {
+    if selection.len() < offset + count {
+        return (0..count).map(|index| (index, count)).collect();
+    };
+    let group_regex: Option<Regex> = match modnumber.scope {
+        NumberScope::Group => Regex::new(&modnumber.group_regex).ok(),
+        _ => None
+    };
+    let keys: Vec<String> = selection.iter().map(|item| {
+        match modnumber.scope {
+            NumberScope::Folder => format!("{}-{}", item.folder, item.is_folder),
+            NumberScope::Global => String::new(),
+            NumberScope::Extension => split_extension(&item.name, item.is_folder).1.to_lowercase(),
+            NumberScope::Group => {
+                let captures = group_regex.as_ref().and_then(|reg| reg.captures(&item.name));
+                match captures {
+                    Some(captures) => captures.get(1).or(captures.get(0)).map(|found| found.as_str().to_string()).unwrap_or_default(),
+                    None => String::new()
+                }
+            }
+        }
+    }).collect();
+    let mut totals: HashMap<&str, usize> = HashMap::new();
+    let mut positions: Vec<usize> = vec![];
+    for key in keys.iter() {
+        let total = totals.entry(key.as_str()).or_insert(0);
+        positions.push(*total);
+        *total += 1;
+    };
+    (offset..offset + count).map(|index| (positions[index], totals[keys[index].as_str()])).collect()
+}
+
+/// `counter` is the item's position in its scope and the scope's size.
+fn number(mut file: String, ext: String, modnumber: ModNumber, counter: (usize, usize), patterns: &Patterns) -> Result<(String, String), String> {
+    let (position, total) = counter;
+    let position: usize = match modnumber.reverse {
+        true => total.saturating_sub(position + 1),
+        false => position
+    };
+    let value = |position: usize| modnumber.starting_num as i64 + position as i64 * modnumber.increment_num as i64;
+    let mut width: usize = modnumber.padding as usize;
+    if modnumber.auto_padding && total >= 1 {
+        for end in [value(0), value(total - 1)] {
+            let end_string = number_string(end, &modnumber).unwrap_or_default();
+            width = width.max(end_string.trim_start_matches('-').chars().count());
+        };
+    };
+    let mut num_string: String = number_pad(number_string(value(position), &modnumber)?, width, &modnumber);
+    if modnumber.mode_type == NumberTypeMode::Ordinal {
+        num_string.push_str(number_ordinal(value(position)));
+    };
+
+    match modnumber.mode {
         NumberMode::Prefix => {
             if modnumber.seperator_enabled == true {
                 file = format!("{}{}{}", num_string, modnumber.seperator, file);
@@ -1027,35 +1794,188 @@ fn number(mut file: String, ext: String, modnumber: ModNumber, file_index: usize
             }
         },
         NumberMode::Insert => {
-            let insert_at: u32;
-            if modnumber.insert_at.is_negative() {
-                insert_at = (file.chars().count() as u32 + 1) - u32::try_from(modnumber.insert_at * -1).unwrap().clamp(1, file.chars().count() as u32 + 1);
-            } else {
-                insert_at = u32::try_from(modnumber.insert_at.clone().clamp(0, file.chars().count() as i32)).unwrap();
-            }
-            let (start, end) = utils::split_uft8(&file, insert_at as usize);
-            if modnumber.seperator_enabled == true {
-                file = format!("{}{}{}{}{}", start, modnumber.seperator, num_string, modnumber.seperator, end);
-            } else {
-                file = format!("{}{}{}", start, num_string, end);
-            }
+            if let Some(insert_at) = insert_index(&file, modnumber.insert_at, &modnumber.anchor, patterns) {
+                let (start, end) = utils::split_uft8(&file, insert_at);
+                if modnumber.seperator_enabled == true {
+                    file = format!("{}{}{}{}{}", start, modnumber.seperator, num_string, modnumber.seperator, end);
+                } else {
+                    file = format!("{}{}{}", start, num_string, end);
+                }
+            };
         },
         NumberMode::None => {} // Do nothing
     }
 
-    (file, ext)
+    Ok((file, ext))
 }
 
-fn regex(mut file: String, ext: String, modregex: ModRegex) -> (String, String) {
-    let reg = Regex::new(&modregex.replace_match);
-    if reg.is_ok() {
-        let reg = reg.unwrap();
-        file = reg.replace(&file, modregex.replace_with).to_string();
+/// Compiles a regex modifier's pattern with its flags. An empty pattern gives None, there's nothing to do.
+pub fn build_regex(modregex: &ModRegex) -> Result<Option<Regex>, String> {
+    if modregex.replace_match.is_empty() {
+        return Ok(None);
+    };
+    RegexBuilder::new(&modregex.replace_match)
+        .case_insensitive(modregex.case_insensitive)
+        .multi_line(modregex.multi_line)
+        .unicode(modregex.unicode)
+        .build()
+        .map(|reg| Some(reg))
+        .map_err(|err| err.to_string())
+}
+
+fn regex(mut file: String, mut ext: String, modregex: ModRegex, reg: &Regex) -> (String, String) {
+    match modregex.scope {
+        RegexScope::Name => {
+            file = regex_replace(reg, &file, &modregex);
+        },
+        RegexScope::Extension => {
+            if !ext.is_empty() {
+                let replaced = regex_replace(reg, ext.trim_start_matches('.'), &modregex);
+                ext = match replaced.is_empty() {
+                    true => String::new(),
+                    false => format!(".{}", replaced)
+                };
+            };
+        },
+        RegexScope::Full => {
+            let full = regex_replace(reg, &format!("{}{}", file, ext), &modregex);
+            if ext.is_empty() {
+                file = full;
+            } else {
+                // Whatever follows the last dot is the extension again.
+                match full.rfind('.') {
+                    Some(index) if index != 0 => {
+                        let (f, e) = full.split_at(index);
+                        file = f.to_string();
+                        ext = e.to_string();
+                    },
+                    _ => {
+                        file = full;
+                        ext = String::new();
+                    }
+                }
+            }
+        }
     };
     (file, ext)
 }
 
-fn remove(mut file: String, ext: String, modremove: ModRemove) -> (String, String) {
+fn regex_replace(reg: &Regex, text: &str, modregex: &ModRegex) -> String {
+    match modregex.occurrence {
+        RegexOccurrence::All => {
+            reg.replace_all(text, |caps: &Captures| regex_expand(caps, &modregex.replace_with)).to_string()
+        },
+        RegexOccurrence::Nth => {
+            match reg.captures_iter(text).nth(modregex.nth.max(1) as usize - 1) {
+                Some(caps) => {
+                    let found = caps.get(0).unwrap();
+                    let mut replaced: String = String::from(&text[..found.start()]);
+                    replaced.push_str(&regex_expand(&caps, &modregex.replace_with));
+                    replaced.push_str(&text[found.end()..]);
+                    replaced
+                },
+                None => text.to_string()
+            }
+        }
+    }
+}
+
+/// Expands a replacement string against a match. On top of the regex crate's $1 / ${name} this supports
+/// ${1:03} zero-padding and \U, \L, \T case changes that run until \E or the end.
+fn regex_expand(caps: &Captures, replacement: &str) -> String {
+    let mut expanded: String = String::new();
+    let mut section: String = String::new();
+    let mut mode: Option<char> = None;
+    let chars: Vec<char> = replacement.chars().collect();
+    let mut index: usize = 0;
+    while index < chars.len() {
+        let c = chars[index];
+        if c == '\\' && index + 1 < chars.len() {
+            match chars[index + 1] {
+                'U' | 'L' | 'T' | 'E' => {
+                    expanded.push_str(&regex_case(&section, mode));
+                    section.clear();
+                    mode = match chars[index + 1] {
+                        'E' => None,
+                        other => Some(other)
+                    };
+                },
+                '\\' => section.push('\\'),
+                other => {
+                    section.push('\\');
+                    section.push(other);
+                }
+            };
+            index += 2;
+            continue;
+        };
+        if c == '$' && index + 1 < chars.len() {
+            if chars[index + 1] == '$' {
+                section.push('$');
+                index += 2;
+                continue;
+            };
+            // ${group} or ${group:width}
+            let (group, width, next) = if chars[index + 1] == '{' {
+                match chars[index + 2..].iter().position(|c| *c == '}') {
+                    Some(end) => {
+                        let inner: String = chars[index + 2..index + 2 + end].iter().collect();
+                        let (group, width) = match inner.split_once(':') {
+                            Some((group, width)) => (group.to_string(), width.parse::<usize>().ok()),
+                            None => (inner, None)
+                        };
+                        (group, width, index + 3 + end)
+                    },
+                    None => {
+                        section.push(c);
+                        index += 1;
+                        continue;
+                    }
+                }
+            } else {
+                let group: String = chars[index + 1..].iter()
+                    .take_while(|c| c.is_ascii_alphanumeric() || **c == '_')
+                    .collect();
+                let next = index + 1 + group.chars().count();
+                (group, None, next)
+            };
+            if group.is_empty() {
+                section.push(c);
+                index += 1;
+                continue;
+            };
+            let value: &str = match group.parse::<usize>() {
+                Ok(number) => caps.get(number).map(|m| m.as_str()).unwrap_or_default(),
+                Err(_) => caps.name(&group).map(|m| m.as_str()).unwrap_or_default()
+            };
+            match (width, value.parse::<u64>()) {
+                (Some(width), Ok(number)) => section.push_str(&format!("{:0width$}", number, width = width)),
+                _ => section.push_str(value)
+            };
+            index = next;
+            continue;
+        };
+        section.push(c);
+        index += 1;
+    }
+    expanded.push_str(&regex_case(&section, mode));
+    expanded
+}
+
+fn regex_case(section: &str, mode: Option<char>) -> String {
+    match mode {
+        Some('U') => section.to_uppercase(),
+        Some('L') => section.to_lowercase(),
+        Some('T') => {
+            case_split_seperated(section).iter().map(|(word, seperator)| {
+                format!("{}{}", case_capitalize(word), seperator)
+            }).collect()
+        },
+        _ => section.to_string()
+    }
+}
+
+fn remove(mut file: String, ext: String, modremove: ModRemove, patterns: &Patterns) -> (String, String) {
     let clamped_first = modremove.first_n.clamp(0, file.chars().count() as u32);
         
     // Remove some chars from the beginning
@@ -1105,32 +2025,11 @@ fn remove(mut file: String, ext: String, modremove: ModRemove) -> (String, Strin
         file = file.replacen(word, "", 100);
     };
 
+    // Remove bracketed segments
+    file = remove_brackets(&file, &modremove, patterns);
+
     // Remove Crop
-    match modremove.crop {
-        RemoveCropMode::Before => {
-            if modremove.crop_match.chars().count() >= 1 {
-                let first = file.find(&modremove.crop_match);
-                if first.is_some() {
-                    let index = first.unwrap();
-                    let (_, end) = file.split_at(index);
-                    file = end.to_string();
-                };
-            };
-        },
-        RemoveCropMode::After => {
-            if modremove.crop_match.chars().count() >= 1 {
-                let first = file.find(&modremove.crop_match);
-                if first.is_some() {
-                    let index = first.unwrap();
-                    if index != modremove.crop_match.chars().count() || index != 0 {
-                        let (start, _) = file.split_at(index + 1);
-                        file = start.to_string();
-                    }
-                };
-            }
-        },
-        _ => {}
-    };
+    file = remove_crop(file, &modremove, patterns);
 
     // Remove digits
     if modremove.digits == true {
@@ -1144,9 +2043,7 @@ fn remove(mut file: String, ext: String, modremove: ModRemove) -> (String, Strin
 
     // Replace Accented Chars with non-accented chars
     if modremove.accented_chars == true {
-        for (index, c) in ACCENTED_CHARS.iter().enumerate() {
-            file = file.replacen(*c, &ACCENTED_CHARS_REPLACEMENT[index].to_string(), 255);
-        };
+        file = strip_accents(&file);
     };
 
     // Remove Symbols (Special characters)
@@ -1171,17 +2068,1023 @@ fn remove(mut file: String, ext: String, modremove: ModRemove) -> (String, Strin
     (file, ext)
 }
 
-fn replace(mut file: String, ext: String, modreplace: ModReplace) -> (String, String) {
-    if modreplace.first_occurance && modreplace.replace_match.len() != 0 {
-        file = file.replacen(&modreplace.replace_match, &modreplace.replace_with, 1);
-    } else if modreplace.replace_match.len() != 0 {
-        file = file.replacen(&modreplace.replace_match, &modreplace.replace_with, 255);
+/// Patterns compiled once per process call, instead of once per name.
+#[derive(Default)]
+struct Patterns {
+    compiled: RefCell<HashMap<(String, bool), Option<Regex>>>
+}
+impl Patterns {
+    /// None when the pattern doesn't compile.
+    fn get(&self, pattern: &str, case_insensitive: bool) -> Option<Regex> {
+        self.compiled.borrow_mut()
+            .entry((pattern.to_string(), case_insensitive))
+            .or_insert_with(|| RegexBuilder::new(pattern).case_insensitive(case_insensitive).build().ok())
+            .to_owned()
     }
+}
+
+/// Byte spans of every match of a crop marker. An empty marker or invalid regex matches nothing.
+fn crop_spans(file: &str, marker: &str, is_regex: bool, patterns: &Patterns) -> Vec<(usize, usize)> {
+    if marker.is_empty() {
+        return vec![];
+    };
+    if is_regex {
+        match patterns.get(marker, false) {
+            Some(reg) => reg.find_iter(file).filter(|found| !found.is_empty()).map(|found| (found.start(), found.end())).collect(),
+            None => vec![]
+        }
+    } else {
+        file.match_indices(marker).map(|(start, found)| (start, start + found.len())).collect()
+    }
+}
+
+/// Every slice is taken at match boundaries, so multi-byte names are cut cleanly.
+fn remove_crop(file: String, modremove: &ModRemove, patterns: &Patterns) -> String {
+    if modremove.crop == RemoveCropMode::None {
+        return file;
+    };
+    let spans = crop_spans(&file, &modremove.crop_match, modremove.crop_regex, patterns);
+    let first = match modremove.crop_occurrence {
+        RemoveCropOccurrence::First => spans.first(),
+        RemoveCropOccurrence::Last => spans.last(),
+        RemoveCropOccurrence::Nth => spans.get(modremove.crop_nth.max(1) as usize - 1)
+    };
+    let (start, end) = match first {
+        Some(span) => *span,
+        None => return file
+    };
+    match modremove.crop {
+        RemoveCropMode::Before => file[start..].to_string(),
+        RemoveCropMode::After => file[..end].to_string(),
+        RemoveCropMode::KeepBetween | RemoveCropMode::RemoveBetween => {
+            let (end_start, end_end) = match crop_spans(&file[end..], &modremove.crop_match_end, modremove.crop_regex, patterns).first() {
+                Some(span) => (span.0 + end, span.1 + end),
+                None => return file
+            };
+            match (&modremove.crop, modremove.crop_keep_markers) {
+                (RemoveCropMode::KeepBetween, false) => file[end..end_start].to_string(),
+                (RemoveCropMode::KeepBetween, true) => file[start..end_end].to_string(),
+                (_, false) => format!("{}{}", &file[..start], &file[end_end..]),
+                (_, true) => format!("{}{}", &file[..end], &file[end_start..])
+            }
+        },
+        RemoveCropMode::None => file
+    }
+}
+
+/// Splits the name on the segment modifier's delimiter. Returns the segments and the first delimiter found.
+pub fn segment_split(file: &str, modsegment: &ModSegment) -> Result<(Vec<String>, String), String> {
+    let mut segments: Vec<String> = vec![];
+    let mut delimiter_found: String = String::new();
+    if modsegment.delimiter.is_empty() {
+        return Ok((vec![file.to_string()], delimiter_found));
+    };
+    let spans: Vec<(usize, usize)> = match modsegment.delimiter_regex {
+        true => {
+            let reg = Regex::new(&modsegment.delimiter).map_err(|err| err.to_string())?;
+            reg.find_iter(file).filter(|found| !found.is_empty()).map(|found| (found.start(), found.end())).collect()
+        },
+        false => file.match_indices(&modsegment.delimiter).map(|(start, found)| (start, start + found.len())).collect()
+    };
+    let mut last: usize = 0;
+    for (start, end) in spans {
+        if delimiter_found.is_empty() {
+            delimiter_found = file[start..end].to_string();
+        };
+        segments.push(file[last..start].to_string());
+        last = end;
+    }
+    segments.push(file[last..].to_string());
+    if modsegment.trim {
+        segments = segments.iter().map(|segment| segment.trim().to_string()).collect();
+    };
+    Ok((segments, delimiter_found))
+}
+
+/// Reads an order like `2,0,1`.
+pub fn segment_order(order: &str) -> Result<Vec<usize>, String> {
+    order.split(',')
+        .map(|index| index.trim())
+        .filter(|index| !index.is_empty())
+        .map(|index| index.parse::<usize>().map_err(|_| format!("{} isn't a segment number.", index)))
+        .collect()
+}
+
+/// Names missing a segment the order asks for are left alone.
+fn segment(file: String, ext: String, modsegment: ModSegment) -> (String, String) {
+    let order = match segment_order(&modsegment.order) {
+        Ok(order) if !order.is_empty() => order,
+        _ => return (file, ext)
+    };
+    let (segments, delimiter_found) = match segment_split(&file, &modsegment) {
+        Ok(split) => split,
+        Err(_) => return (file, ext)
+    };
+    if order.iter().any(|index| *index >= segments.len()) {
+        return (file, ext);
+    };
+    let joiner = match modsegment.joiner.is_empty() {
+        true => delimiter_found,
+        false => modsegment.joiner.to_owned()
+    };
+    let rebuilt: Vec<&str> = order.iter().map(|index| segments[*index].as_str()).collect();
+    (rebuilt.join(&joiner), ext)
+}
+
+/// The generator is keyed by the item's path, so an item keeps its value when others are selected or moved.
+fn random_name(file: String, ext: String, modrandom: &ModRandom, file_path: &str) -> (String, String) {
+    if modrandom.mode == RandomMode::None {
+        return (file, ext);
+    };
+    let mut generator = random::Generator::new(modrandom.seed, file_path);
+    let modified_ms = || -> u64 {
+        std::fs::metadata(file_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+            .map(|time| time.as_millis() as u64)
+            .unwrap_or_default()
+    };
+    let length = modrandom.length as usize;
+    let value: String = match modrandom.kind {
+        RandomKind::Alphanumeric => random::alphanumeric(&mut generator, length),
+        RandomKind::Hex => random::hex(&mut generator, length),
+        RandomKind::NanoId => random::nanoid(&mut generator, length),
+        RandomKind::UuidV4 => random::uuid_v4(&mut generator),
+        RandomKind::UuidV7 => random::uuid_v7(&mut generator, modified_ms()),
+        RandomKind::Ulid => random::ulid(&mut generator, modified_ms())
+    };
+    let seperator: String = match modrandom.seperator_enabled {
+        true => modrandom.seperator.to_string(),
+        false => String::new()
+    };
+    let file: String = match modrandom.mode {
+        RandomMode::Replace => value,
+        RandomMode::Prefix => format!("{}{}{}", value, seperator, file),
+        RandomMode::Suffix => format!("{}{}{}", file, seperator, value),
+        RandomMode::None => file
+    };
+    (file, ext)
+}
+
+/// Cuts the name down to the limit, keeping the extension and, when protected, a trailing counter or hash.
+/// `tail` is TRUNCATE_TAIL, compiled once per batch.
+fn truncate(file: String, ext: String, modtruncate: &ModTruncate, taken: &HashSet<String>, tail: &Regex) -> (String, String) {
+    if modtruncate.limit == 0 {
+        return (file, ext);
+    };
+    let measure = |text: &str| -> usize {
+        match modtruncate.unit {
+            TruncateUnit::Characters => text.chars().count(),
+            TruncateUnit::Bytes => text.len()
+        }
+    };
+    let ext_length: usize = match modtruncate.count_extension {
+        true => measure(&ext),
+        false => 0
+    };
+    let limit = modtruncate.limit as usize;
+    if measure(&file) + ext_length <= limit {
+        return (file, ext);
+    };
+    let tail_start: usize = match modtruncate.protect_tail {
+        true => tail.find(&file).map(|found| found.start()).unwrap_or(file.len()),
+        false => file.len()
+    };
+    let (head, tail) = file.split_at(tail_start);
+    let marker: &str = match modtruncate.marker_enabled {
+        true => &modtruncate.marker,
+        false => ""
+    };
+    let budget = limit.saturating_sub(ext_length + measure(tail) + measure(marker));
+    let mut truncated = format!("{}{}{}", truncate_head(head, budget, modtruncate, &measure), marker, tail);
+    if modtruncate.dedupe && taken.contains(&format!("{}{}", truncated, ext)) {
+        let short_hash = format!("~{:06x}", crc32fast::hash(format!("{}{}", file, ext).as_bytes()) & 0xFFFFFF);
+        let budget = budget.saturating_sub(measure(&short_hash));
+        truncated = format!("{}{}{}{}", truncate_head(head, budget, modtruncate, &measure), marker, short_hash, tail);
+    };
+    (truncated, ext)
+}
+
+/// The start of `head` that fits in `budget`, backed up to a word boundary when asked.
+fn truncate_head(head: &str, budget: usize, modtruncate: &ModTruncate, measure: &dyn Fn(&str) -> usize) -> String {
+    let mut end: usize = 0;
+    for (index, char) in head.char_indices() {
+        let next = index + char.len_utf8();
+        if measure(&head[..next]) > budget { break };
+        end = next;
+    }
+    let mut kept: &str = &head[..end];
+    let mid_word = head[end..].chars().next().is_some_and(|char| !TRUNCATE_SEPERATORS.contains(&char))
+        && kept.chars().last().is_some_and(|char| !TRUNCATE_SEPERATORS.contains(&char));
+    if modtruncate.word_boundary && mid_word {
+        if let Some(boundary) = kept.rfind(TRUNCATE_SEPERATORS).filter(|boundary| *boundary >= 1) {
+            kept = &kept[..boundary];
+        };
+    };
+    kept.trim_end_matches(TRUNCATE_SEPERATORS).to_string()
+}
+
+/// One regex a line, blank lines are skipped.
+pub fn build_seperator_protect(protect: &str) -> Result<Vec<Regex>, String> {
+    protect.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| Regex::new(line).map_err(|err| err.to_string()))
+        .collect()
+}
+
+/// Turns the chosen seperators into the target one. Only the name is touched, the extension never is.
+/// `protect` is the compiled protect lines, left out when protection is off.
+fn seperators(file: &str, modseperators: &ModSeperators, protect: &[Regex]) -> String {
+    if modseperators.from.is_empty() && !modseperators.split_camel {
+        return file.to_string();
+    };
+    let mut protected: Vec<(usize, usize)> = vec![];
+    let protect: &[Regex] = match modseperators.protect_enabled {
+        true => protect,
+        false => &[]
+    };
+    for reg in protect {
+        for captures in reg.captures_iter(file) {
+            if let Some(found) = captures.get(1).or(captures.get(0)) {
+                protected.push((found.start(), found.end()));
+            };
+        }
+    }
+    let is_protected = |at: usize| protected.iter().any(|(start, end)| at >= *start && at < *end);
+    let to = modseperators.to;
+    let chars: Vec<(usize, char)> = file.char_indices().collect();
+    let mut result = String::new();
+    for (position, (at, char)) in chars.iter().enumerate() {
+        if is_protected(*at) {
+            result.push(*char);
+            continue;
+        };
+        if *char == to || modseperators.from.contains(*char) {
+            if modseperators.collapse && (result.is_empty() || result.ends_with(to)) { continue };
+            result.push(to);
+            continue;
+        };
+        if modseperators.split_camel && position >= 1 {
+            // "fileName" and the end of an acronym, "HTTPServer".
+            let previous = chars[position - 1].1;
+            let next_lower = chars.get(position + 1).is_some_and(|next| next.1.is_lowercase());
+            let hump = char.is_uppercase() && (previous.is_lowercase() || (previous.is_uppercase() && next_lower));
+            if hump && !result.is_empty() && !result.ends_with(to) {
+                result.push(to);
+            };
+        };
+        result.push(*char);
+    }
+    match modseperators.collapse {
+        true => result.trim_end_matches(to).to_string(),
+        false => result
+    }
+}
+
+/// Whether the sequence modifier changes anything, it's left out otherwise.
+fn sequence_active(modsequence: &ModSequence) -> bool {
+    modsequence.renumber || modsequence.padding >= 1 || modsequence.prefix_enabled
+}
+
+/// Rebuilds a frame's name from its sequence, so the changes made before this modifier are replaced.
+fn sequence_frame(sequence: &Sequence, rank: usize, modsequence: &ModSequence) -> (String, String) {
+    let (frame, _) = sequence.frames[rank];
+    let first = sequence.frames[0].0;
+    let frame: u64 = match (modsequence.renumber, modsequence.close_gaps) {
+        (true, true) => modsequence.start as u64 + rank as u64,
+        (true, false) => modsequence.start as u64 + (frame - first),
+        (false, _) => frame
+    };
+    let width: usize = match modsequence.padding {
+        0 => sequence.padding,
+        padding => padding as usize
+    };
+    let prefix: &str = match modsequence.prefix_enabled {
+        true => &modsequence.prefix,
+        false => &sequence.prefix
+    };
+    split_extension(&format!("{}{:0width$}{}", prefix, frame, sequence.suffix, width = width), false)
+}
+
+/// Byte span of the number the renumber modifier works on.
+fn renumber_span(file: &str, modrenumber: &ModRenumber) -> Option<(usize, usize)> {
+    let mut spans: Vec<(usize, usize)> = vec![];
+    let mut start: Option<usize> = None;
+    for (index, char) in file.char_indices() {
+        match (start, char.is_ascii_digit()) {
+            (None, true) => start = Some(index),
+            (Some(number_start), false) => {
+                spans.push((number_start, index));
+                start = None;
+            },
+            _ => {}
+        }
+    }
+    if let Some(number_start) = start {
+        spans.push((number_start, file.len()));
+    };
+    match modrenumber.occurrence {
+        RenumberOccurrence::First => spans.first().copied(),
+        RenumberOccurrence::Last => spans.last().copied(),
+        RenumberOccurrence::Nth => spans.get((modrenumber.nth as usize).max(1) - 1).copied()
+    }
+}
+
+/// Old number to its resequenced number, from every name in the folder. Repeated numbers share one.
+fn renumber_sequence_table(names: &[String], modrenumber: &ModRenumber) -> HashMap<u64, u64> {
+    let mut numbers: Vec<u64> = names.iter()
+        .filter_map(|name| renumber_span(name, modrenumber).and_then(|(start, end)| name[start..end].parse::<u64>().ok()))
+        .collect();
+    numbers.sort();
+    numbers.dedup();
+    let lowest = numbers.first().copied().unwrap_or_default();
+    numbers.iter().enumerate().map(|(rank, number)| {
+        let resequenced = match modrenumber.keep_gaps {
+            true => modrenumber.start as u64 + (number - lowest),
+            false => modrenumber.start as u64 + rank as u64
+        };
+        (*number, resequenced)
+    }).collect()
+}
+
+/// Pads, offsets or resequences the chosen number in place. Names without it are left alone.
+/// The sequence is built from the original names, a number an earlier modifier made isn't in it and errors.
+fn renumber(file: String, ext: String, modrenumber: &ModRenumber, sequence: &HashMap<u64, u64>) -> Result<(String, String), String> {
+    let (start, end) = match renumber_span(&file, modrenumber) {
+        Some(span) => span,
+        None => return Ok((file, ext))
+    };
+    let digits = &file[start..end];
+    let mut number: u64 = match digits.parse::<u64>() {
+        Ok(number) => number,
+        Err(_) => return Ok((file, ext))
+    };
+    if modrenumber.resequence {
+        number = match sequence.get(&number) {
+            Some(resequenced) => *resequenced,
+            None => return Err(format!("{} isn't one of the folder's numbers, a modifier before renumber changed it.", number))
+        };
+    };
+    let number: u64 = (number as i128 + modrenumber.offset as i128).clamp(0, u64::MAX as i128) as u64;
+    // Without a padding keep zero padded numbers at their width.
+    let width: usize = match modrenumber.padding {
+        0 if digits.len() >= 2 && digits.starts_with('0') => digits.len(),
+        padding => padding as usize
+    };
+    Ok((format!("{}{:0width$}{}", &file[..start], number, &file[end..], width = width), ext))
+}
+
+/// The open / close pairs picked in the remove modifier.
+fn remove_bracket_pairs(modremove: &ModRemove) -> Vec<(char, char)> {
+    let mut pairs: Vec<(char, char)> = vec![];
+    if modremove.brackets_round { pairs.push(('(', ')')) };
+    if modremove.brackets_square { pairs.push(('[', ']')) };
+    if modremove.brackets_curly { pairs.push(('{', '}')) };
+    if modremove.brackets_angle { pairs.push(('<', '>')) };
+    for custom in modremove.brackets_custom.split(',') {
+        let chars: Vec<char> = custom.trim().chars().collect();
+        if chars.len() == 2 {
+            pairs.push((chars[0], chars[1]));
+        };
+    }
+    pairs
+}
+
+/// Char ranges (start, end exclusive) of the outermost bracketed segments, delimiters included. 
+/// Nested pairs are part of their outer segment and unclosed openers are left alone.
+fn bracket_segments(chars: &[char], pairs: &[(char, char)]) -> Vec<(usize, usize)> {
+    let mut closed: Vec<(usize, usize)> = vec![];
+    let mut open: Vec<(char, usize)> = vec![]; // (Closer expected, Start)
+    for (index, char) in chars.iter().enumerate() {
+        // Openers above the one this closes were never closed, they're dropped.
+        if let Some(depth) = open.iter().rposition(|(closer, _)| closer == char) {
+            closed.push((open[depth].1, index + 1));
+            open.truncate(depth);
+            continue;
+        };
+        if let Some((_, closer)) = pairs.iter().find(|(opener, _)| opener == char) {
+            open.push((*closer, index));
+        };
+    }
+    let mut segments: Vec<(usize, usize)> = closed.iter()
+        .filter(|(start, end)| !closed.iter().any(|(outer_start, outer_end)| outer_start < start && end <= outer_end))
+        .copied()
+        .collect();
+    segments.sort();
+    segments
+}
+
+fn remove_brackets(file: &str, modremove: &ModRemove, patterns: &Patterns) -> String {
+    let pairs = remove_bracket_pairs(modremove);
+    if pairs.is_empty() {
+        return file.to_string();
+    };
+    let keep: Option<Regex> = match modremove.brackets_keep.is_empty() {
+        true => None,
+        false => patterns.get(&modremove.brackets_keep, false)
+    };
+    let chars: Vec<char> = file.chars().collect();
+    let mut segments: Vec<(usize, usize)> = bracket_segments(&chars, &pairs).into_iter()
+        .filter(|(start, end)| {
+            let inner: String = chars[start + 1..end - 1].iter().collect();
+            !keep.as_ref().map(|keep| keep.is_match(&inner)).unwrap_or(false)
+        })
+        .collect();
+    match modremove.brackets_which {
+        RemoveBracketsMode::All => {},
+        RemoveBracketsMode::First => segments.truncate(1),
+        RemoveBracketsMode::Last => segments = segments.pop().into_iter().collect()
+    };
+    chars.iter().enumerate()
+        .filter(|(index, _)| !segments.iter().any(|(start, end)| index >= start && index < end))
+        .map(|(_, char)| char)
+        .collect()
+}
+
+fn replace(mut file: String, ext: String, modreplace: ModReplace, patterns: &Patterns) -> (String, String) {
+    if modreplace.replace_match.is_empty() {
+        return (file, ext);
+    };
+    let spans = replace_spans(&file, &modreplace, patterns);
+    let chosen: Vec<(usize, usize)> = match modreplace.occurrence {
+        ReplaceOccurrence::All => spans,
+        ReplaceOccurrence::First => spans.first().copied().into_iter().collect(),
+        ReplaceOccurrence::Last => spans.last().copied().into_iter().collect(),
+        ReplaceOccurrence::Nth => spans.get(modreplace.nth.max(1) as usize - 1).copied().into_iter().collect()
+    };
+    let mut file_new: String = String::new();
+    let mut last: usize = 0;
+    for (start, end) in chosen {
+        file_new.push_str(&file[last..start]);
+        if modreplace.preserve_case {
+            file_new.push_str(&replace_preserve_case(&file[start..end], &modreplace.replace_with));
+        } else {
+            file_new.push_str(&modreplace.replace_with);
+        };
+        last = end;
+    }
+    file_new.push_str(&file[last..]);
+    file = file_new;
     (file, ext)
 }
 
+/// Byte ranges of every match of the replace modifier, honouring case-insensitive and whole-word.
+fn replace_spans(file: &str, modreplace: &ModReplace, patterns: &Patterns) -> Vec<(usize, usize)> {
+    let reg = match patterns.get(&regex::escape(&modreplace.replace_match), modreplace.case_insensitive) {
+        Some(reg) => reg,
+        None => return vec![] // An escaped literal always compiles, unless it's absurdly long.
+    };
+    reg.find_iter(file)
+        .filter(|found| {
+            if !modreplace.whole_word { return true };
+            let before = file[..found.start()].chars().next_back();
+            let after = file[found.end()..].chars().next();
+            !before.map(|c| c.is_alphanumeric()).unwrap_or(false) && !after.map(|c| c.is_alphanumeric()).unwrap_or(false)
+        })
+        .map(|found| (found.start(), found.end()))
+        .collect()
+}
+
+/// Reads `find,replace` pairs, one per line. Tab separated lines are split on the first tab, 
+/// anything else as CSV with optional double quotes. Blank lines and lines starting with # are skipped.
+/// Returns the pairs and the line numbers that couldn't be read.
+pub fn parse_pairs(text: &str) -> (Vec<(String, String)>, Vec<usize>) {
+    let mut pairs: Vec<(String, String)> = vec![];
+    let mut malformed: Vec<usize> = vec![];
+    for (line_index, line) in text.lines().enumerate() {
+        let line = line.trim_end_matches('\r');
+        if line.trim().is_empty() || line.starts_with('#') { continue };
+        let fields: Vec<String> = match line.split_once('\t') {
+            Some((find, with)) => vec![find.to_string(), with.to_string()],
+            None => parse_csv_line(line)
+        };
+        if fields.len() < 2 || fields[0].is_empty() {
+            malformed.push(line_index + 1);
+            continue;
+        };
+        pairs.push((fields[0].to_owned(), fields[1].to_owned()));
+    }
+    (pairs, malformed)
+}
+
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields: Vec<String> = vec![];
+    let mut field: String = String::new();
+    let mut quoted: bool = false;
+    let mut chars = line.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(char)
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+/// The usable names from a names list, in order.
+pub fn name_list_names(modnamelist: &ModNameList) -> Vec<String> {
+    let numbering = Regex::new(r"^\s*[#\[(]?\d+[\])]?(?:\s*[.):_-]\s*|\s+)").unwrap();
+    let mut names: Vec<String> = vec![];
+    for line in modnamelist.table.lines() {
+        let mut name: String = line.trim_end_matches('\r').to_string();
+        if modnamelist.strip_numbering {
+            name = numbering.replace(&name, "").to_string();
+        };
+        if modnamelist.trim_whitespace {
+            name = name.split_whitespace().collect::<Vec<&str>>().join(" ");
+        };
+        if name.trim().is_empty() { continue };
+        names.push(name);
+    }
+    names
+}
+
+/// Reads an old → new mapping, either JSON or `old,new` rows like parse_pairs. 
+/// A leading `old_name,new_name` header row is skipped. Unlike the dictionary, anything unreadable is an error.
+pub fn parse_mapping(text: &str) -> Result<Vec<(String, String)>, String> {
+    let mut pairs: Vec<(String, String)> = vec![];
+    let trimmed = text.trim_start();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        let json: serde_json::Value = serde_json::from_str(text).map_err(|err| format!("Invalid JSON: {}", err))?;
+        match json {
+            serde_json::Value::Object(object) => {
+                for (old, new) in object {
+                    match new.as_str() {
+                        Some(new) => pairs.push((old, new.to_string())),
+                        None => return Err(format!("The new name for {} isn't a string.", old))
+                    }
+                }
+            },
+            serde_json::Value::Array(rows) => {
+                for (row_index, row) in rows.iter().enumerate() {
+                    let pair = match row {
+                        serde_json::Value::Array(fields) => (fields.get(0).and_then(|f| f.as_str()), fields.get(1).and_then(|f| f.as_str())),
+                        serde_json::Value::Object(fields) => (
+                            fields.get("old").or(fields.get("old_name")).and_then(|f| f.as_str()),
+                            fields.get("new").or(fields.get("new_name")).and_then(|f| f.as_str())
+                        ),
+                        _ => (None, None)
+                    };
+                    match pair {
+                        (Some(old), Some(new)) => pairs.push((old.to_string(), new.to_string())),
+                        _ => return Err(format!("Row {} needs an old and a new name.", row_index + 1))
+                    }
+                }
+            },
+            _ => return Err(String::from("Expected a JSON object or array."))
+        }
+    } else {
+        let (rows, malformed) = parse_pairs(text);
+        if let Some(line) = malformed.first() {
+            return Err(format!("Line {} needs an old and a new name.", line));
+        };
+        pairs = rows;
+        if let Some((old, new)) = pairs.first() {
+            if ["old", "old_name"].contains(&old.to_lowercase().as_str()) && ["new", "new_name"].contains(&new.to_lowercase().as_str()) {
+                pairs.remove(0);
+            };
+        };
+    };
+    let mut seen: HashMap<&String, ()> = HashMap::new();
+    for (old, _) in pairs.iter() {
+        if seen.insert(old, ()).is_some() {
+            return Err(format!("{} is mapped more than once.", old));
+        };
+    }
+    Ok(pairs)
+}
+
+/// Compiles the dictionary into anchored finds, in the order they should be tried.
+pub fn build_dictionary(moddictionary: &ModDictionary) -> Vec<(Regex, String)> {
+    let (mut pairs, _) = parse_pairs(&moddictionary.table);
+    if moddictionary.order == DictionaryOrder::Longest {
+        pairs.sort_by(|a, b| b.0.chars().count().cmp(&a.0.chars().count())); // Stable, so equal lengths keep the listed order.
+    };
+    pairs.into_iter().filter_map(|(find, with)| {
+        RegexBuilder::new(&format!("^{}", regex::escape(&find)))
+            .case_insensitive(moddictionary.case_insensitive)
+            .build()
+            .ok()
+            .map(|reg| (reg, with))
+    }).collect()
+}
+
+/// Walks the name once, replacing the first pair that matches at each position. 
+/// Replaced text isn't looked at again, so pairs can't feed into each other.
+fn dictionary(file: String, ext: String, moddictionary: &ModDictionary, pairs: &Vec<(Regex, String)>) -> (String, String) {
+    if pairs.is_empty() {
+        return (file, ext);
+    };
+    let mut file_new: String = String::new();
+    let mut position: usize = 0;
+    while position < file.len() {
+        let rest = &file[position..];
+        let found = pairs.iter().find_map(|(reg, with)| {
+            let found = reg.find(rest)?;
+            if found.end() == 0 { return None };
+            if moddictionary.whole_word {
+                let before = file[..position].chars().next_back();
+                let after = rest[found.end()..].chars().next();
+                if before.map(|c| c.is_alphanumeric()).unwrap_or(false) || after.map(|c| c.is_alphanumeric()).unwrap_or(false) {
+                    return None;
+                };
+            };
+            Some((found.end(), with))
+        });
+        match found {
+            Some((length, with)) => {
+                if moddictionary.preserve_case {
+                    file_new.push_str(&replace_preserve_case(&rest[..length], with));
+                } else {
+                    file_new.push_str(with);
+                };
+                position += length;
+            },
+            None => {
+                let char = rest.chars().next().unwrap();
+                file_new.push(char);
+                position += char.len_utf8();
+            }
+        }
+    }
+    (file_new, ext)
+}
+
+/// Gives the replacement the same case as the text it replaces: UPPER, lower or Title.
+fn replace_preserve_case(matched: &str, replacement: &str) -> String {
+    let letters: Vec<char> = matched.chars().filter(|c| c.is_alphabetic()).collect();
+    if letters.is_empty() {
+        return replacement.to_string();
+    };
+    if letters.iter().all(|c| c.is_uppercase()) && letters.len() > 1 {
+        replacement.to_uppercase()
+    } else if letters.iter().all(|c| c.is_lowercase()) {
+        replacement.to_lowercase()
+    } else if letters[0].is_uppercase() && letters[1..].iter().all(|c| c.is_lowercase()) {
+        case_capitalize(replacement)
+    } else {
+        replacement.to_string()
+    }
+}
+
+/// What the modifier panel's tester shows for a single name.
+#[derive(Clone)]
+pub struct MatchPreview {
+    /// The part of the name the pattern runs against.
+    pub text: String,
+    /// Byte ranges of every match in text.
+    pub spans: Vec<(usize, usize)>,
+    /// (Group, Value) of the first match.
+    pub captures: Vec<(String, String)>,
+    /// The whole name after this one modifier.
+    pub result: String,
+    /// How many of the tested names matched at least once.
+    pub names_matched: usize
+}
+
+fn regex_scoped_text(modregex: &ModRegex, file: &str, ext: &str) -> String {
+    match modregex.scope {
+        RegexScope::Name => file.to_string(),
+        RegexScope::Extension => ext.trim_start_matches('.').to_string(),
+        RegexScope::Full => format!("{}{}", file, ext)
+    }
+}
+
+/// Runs a regex modifier against the first of (Name, Is Folder) for the tester, and counts how many of them match.
+/// Err if the pattern doesn't compile.
+pub fn preview_regex(modregex: &ModRegex, names: &Vec<(String, bool)>) -> Result<Option<MatchPreview>, String> {
+    let reg = match build_regex(modregex)? {
+        Some(reg) => reg,
+        None => return Ok(None)
+    };
+    let (filename, is_folder) = match names.first() {
+        Some(name) => name,
+        None => return Ok(None)
+    };
+    let names_matched = names.iter().filter(|(name, is_folder)| {
+        let (file, ext) = split_extension(name, *is_folder);
+        reg.is_match(&regex_scoped_text(modregex, &file, &ext))
+    }).count();
+    let (file, ext) = split_extension(filename, *is_folder);
+    let text: String = regex_scoped_text(modregex, &file, &ext);
+    let spans: Vec<(usize, usize)> = reg.find_iter(&text).map(|m| (m.start(), m.end())).collect();
+    let mut captures: Vec<(String, String)> = vec![];
+    if let Some(caps) = reg.captures(&text) {
+        for (group, name) in reg.capture_names().enumerate().skip(1) {
+            let label = match name {
+                Some(name) => format!("{} ({})", group, name),
+                None => group.to_string()
+            };
+            captures.push((label, caps.get(group).map(|m| m.as_str().to_string()).unwrap_or_default()));
+        }
+    };
+    let (file, ext) = regex(file, ext, modregex.clone(), &reg);
+    Ok(Some(MatchPreview {
+        text: text,
+        spans: spans,
+        captures: captures,
+        result: format!("{}{}", file, ext),
+        names_matched: names_matched
+    }))
+}
+
+/// Runs a replace modifier against the first of (Name, Is Folder) for the tester, and counts how many of them match.
+pub fn preview_replace(modreplace: &ModReplace, names: &Vec<(String, bool)>) -> Option<MatchPreview> {
+    if modreplace.replace_match.is_empty() {
+        return None;
+    };
+    let (filename, is_folder) = names.first()?;
+    let patterns = Patterns::default();
+    let names_matched = names.iter().filter(|(name, is_folder)| {
+        !replace_spans(&split_extension(name, *is_folder).0, modreplace, &patterns).is_empty()
+    }).count();
+    let (file, ext) = split_extension(filename, *is_folder);
+    let spans: Vec<(usize, usize)> = replace_spans(&file, modreplace, &patterns);
+    let (result, ext) = replace(file.clone(), ext, modreplace.clone(), &patterns);
+    Some(MatchPreview {
+        text: file,
+        spans: spans,
+        captures: vec![],
+        result: format!("{}{}", result, ext),
+        names_matched: names_matched
+    })
+}
+
+/// Decomposes to NFD, drops the combining marks and recomposes, so "Crème Brûlée" becomes "Creme Brulee".
+fn strip_accents(file: &str) -> String {
+    let mut stripped: String = String::new();
+    for c in file.nfd().filter(|c| !is_combining_mark(*c)) {
+        match ACCENTED_CHARS.iter().find(|(accented, _)| *accented == c) {
+            Some((_, replacement)) => stripped.push_str(replacement),
+            None => stripped.push(c)
+        }
+    }
+    stripped.nfc().collect()
+}
+
+/// Latin letters (Basic, Latin-1, Extended A/B, IPA and Extended Additional), left alone by NonLatin.
+fn is_latin(c: char) -> bool {
+    c.is_ascii() || ('\u{00C0}'..='\u{024F}').contains(&c) || ('\u{0250}'..='\u{02AF}').contains(&c) || ('\u{1E00}'..='\u{1EFF}').contains(&c)
+}
+
+fn transliterate(mut file: String, ext: String, modtransliterate: ModTransliterate) -> (String, String) {
+    match modtransliterate.mode {
+        TransliterateMode::Ascii => {
+            file = deunicode::deunicode_with_tofu(&strip_accents(&file), &modtransliterate.unknown);
+        },
+        TransliterateMode::NonLatin => {
+            // Transliterate runs of non-latin characters together, so the words keep their spacing.
+            let mut file_new: String = String::new();
+            let mut run: String = String::new();
+            for c in file.chars() {
+                if is_latin(c) || is_combining_mark(c) || c.is_whitespace() {
+                    if !run.is_empty() {
+                        file_new.push_str(&deunicode::deunicode_with_tofu(&run, &modtransliterate.unknown));
+                        run.clear();
+                    };
+                    file_new.push(c);
+                } else {
+                    run.push(c);
+                }
+            }
+            if !run.is_empty() {
+                file_new.push_str(&deunicode::deunicode_with_tofu(&run, &modtransliterate.unknown));
+            };
+            file = file_new;
+        },
+        _ => {} // None, do nothing.
+    };
+    (file, ext)
+}
+
+fn normalize_form(file: &str, mode: NormalizeMode) -> String {
+    match mode {
+        NormalizeMode::NFC => file.nfc().collect(),
+        NormalizeMode::NFD => file.nfd().collect(),
+        NormalizeMode::NFKC => file.nfkc().collect(),
+        NormalizeMode::NFKD => file.nfkd().collect(),
+        NormalizeMode::None => file.to_string()
+    }
+}
+
+fn normalize(file: String, ext: String, modnormalize: ModNormalize) -> (String, String) {
+    (normalize_form(&file, modnormalize.mode), normalize_form(&ext, modnormalize.mode))
+}
+
+/// Everything the template modifier can pull from, besides the name being edited.
+struct TemplateSource<'a> {
+    name: &'a str,
+    ext: &'a str,
+    path: &'a str,
+    index: usize,
+    hash: &'a str,
+    algorithm: HashType,
+    is_folder: bool
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum TemplateToken {
+    Text(String),
+    /// (Token, Argument after the first ':')
+    Variable(String, Option<String>)
+}
+
+/// Checks a template for unknown tokens and bad arguments, used by the modifier panel.
+pub fn validate_template(template: &str) -> Result<(), String> {
+    template_tokens(template).map(|_| ())
+}
+
+fn template_tokens(template: &str) -> Result<Vec<TemplateToken>, String> {
+    let mut tokens: Vec<TemplateToken> = vec![];
+    let mut text: String = String::new();
+    let mut chars = template.chars().peekable();
+    while let Some(c) = chars.next() {
+        let next = chars.peek().copied();
+        match c {
+            '{' if next == Some('{') => {
+                chars.next();
+                text.push('{');
+            },
+            '}' if next == Some('}') => {
+                chars.next();
+                text.push('}');
+            },
+            '{' => {
+                let mut inner: String = String::new();
+                let mut closed: bool = false;
+                while let Some(c) = chars.next() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    };
+                    inner.push(c);
+                }
+                if !closed {
+                    return Err(format!("Unclosed token {{{}", inner));
+                };
+                if !text.is_empty() {
+                    tokens.push(TemplateToken::Text(text.clone()));
+                    text.clear();
+                };
+                let (name, arg) = match inner.split_once(':') {
+                    Some((name, arg)) => (name.trim().to_string(), Some(arg.to_string())),
+                    None => (inner.trim().to_string(), None)
+                };
+                template_check_token(&name, &arg)?;
+                tokens.push(TemplateToken::Variable(name, arg));
+            },
+            '}' => {
+                return Err(String::from("Unmatched } (use }} for a literal brace)"));
+            },
+            _ => { text.push(c) }
+        }
+    }
+    if !text.is_empty() {
+        tokens.push(TemplateToken::Text(text));
+    };
+    Ok(tokens)
+}
+
+fn template_check_token(name: &str, arg: &Option<String>) -> Result<(), String> {
+    if !TEMPLATE_TOKENS.contains(&name) {
+        return Err(format!("Unknown token {{{}}}", name));
+    };
+    let arg = match arg {
+        Some(arg) => arg,
+        None => return Ok(())
+    };
+    match name {
+        "counter" | "parent" => {
+            if arg.parse::<usize>().is_err() {
+                return Err(format!("{{{}:{}}} expects a number", name, arg));
+            };
+        },
+        "date" | "mtime" | "ctime" | "atime" => {
+            if chrono::format::strftime::StrftimeItems::new(arg).any(|i| matches!(i, chrono::format::Item::Error)) {
+                return Err(format!("{{{}:{}}} has an invalid date format", name, arg));
+            };
+        },
+        "size" => {
+            if !["b", "kb", "mb", "gb"].contains(&arg.to_ascii_lowercase().as_str()) {
+                return Err(format!("{{size:{}}} expects b, kb, mb or gb", arg));
+            };
+        },
+        _ => {
+            return Err(format!("{{{}}} doesn't take an argument", name));
+        }
+    };
+    Ok(())
+}
+
+fn template(file: String, mut ext: String, modtemplate: ModTemplate, source: &TemplateSource) -> Result<(String, String), String> {
+    if modtemplate.template.is_empty() {
+        return Ok((file, ext));
+    };
+    let mut tokens = template_tokens(&modtemplate.template)?;
+
+    // A trailing {ext} leaves the extension where the other modifiers expect it.
+    if tokens.last() == Some(&TemplateToken::Variable(String::from("ext"), None)) {
+        tokens.pop();
+        ext = source.ext.to_string();
+    } else if tokens.iter().any(|token| matches!(token, TemplateToken::Variable(name, _) if name == "ext")) {
+        ext = String::new();
+    };
+
+    let mut file_new: String = String::new();
+    for token in tokens {
+        match token {
+            TemplateToken::Text(text) => {
+                file_new.push_str(&text);
+            },
+            TemplateToken::Variable(name, arg) => {
+                file_new.push_str(&template_variable(&name, arg, &modtemplate, source)?);
+            }
+        }
+    }
+    Ok((file_new, ext))
+}
+
+fn template_variable(name: &str, arg: Option<String>, modtemplate: &ModTemplate, source: &TemplateSource) -> Result<String, String> {
+    match name {
+        "name" => Ok(source.name.to_string()),
+        "ext" => Ok(source.ext.to_string()),
+        "counter" => {
+            let counter = modtemplate.counter_start as u64 + (source.index as u64 * modtemplate.counter_step as u64);
+            let width = arg.unwrap_or_default().parse::<usize>().unwrap_or(0);
+            Ok(format!("{:0width$}", counter, width = width))
+        },
+        "date" => {
+            Ok(chrono::Local::now().format(&arg.unwrap_or(String::from(TEMPLATE_DATE_FORMAT))).to_string())
+        },
+        "mtime" | "ctime" | "atime" => {
+            let metadata = std::fs::metadata(source.path).map_err(|err| err.to_string())?;
+            let time = match name {
+                "mtime" => metadata.modified(),
+                "ctime" => metadata.created(),
+                _ => metadata.accessed()
+            }.map_err(|err| err.to_string())?;
+            let time: chrono::DateTime<chrono::Local> = time.into();
+            Ok(time.format(&arg.unwrap_or(String::from(TEMPLATE_DATE_FORMAT))).to_string())
+        },
+        "hash" => {
+            if source.is_folder {
+                Ok(String::new())
+            } else if source.hash.is_empty() {
+                Ok(hash_placeholder(source.algorithm))
+            } else {
+                Ok(source.hash.to_string())
+            }
+        },
+        "size" => {
+            let size = std::fs::metadata(source.path).map_err(|err| err.to_string())?.len();
+            match arg.unwrap_or_default().to_ascii_lowercase().as_str() {
+                "kb" => Ok((size / 1024).to_string()),
+                "mb" => Ok((size / (1024 * 1024)).to_string()),
+                "gb" => Ok((size / (1024 * 1024 * 1024)).to_string()),
+                _ => Ok(size.to_string())
+            }
+        },
+        "parent" => {
+            let depth = arg.unwrap_or(String::from("1")).parse::<usize>().unwrap_or(1);
+            let parent = Path::new(source.path).ancestors().nth(depth)
+                .and_then(|path| path.file_name())
+                .and_then(|name| name.to_str())
+                .unwrap_or_default();
+            Ok(parent.to_string())
+        },
+        _ => Err(format!("Unknown token {{{}}}", name))
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    fn names(names: &[&str]) -> Vec<(String, usize, Option<String>)> {
+        names.iter().enumerate().map(|(index, name)| (name.to_string(), index, None)).collect()
+    }
+
+    /// Runs one modifier over files in a single folder, returning the new names.
+    fn process_files(modifiers: &mut super::Modifiers, files: &[&str], order: super::ModsOrder) -> (Vec<String>, Vec<super::ModifierThreadError>) {
+        let (renamed, errors) = super::process(super::Batch { folder_index: 0, offset: 0, selection: &[], listing: &[] }, String::from("/scratch"), modifiers, names(files), vec![order], false);
+        (renamed.into_iter().map(|file| file.0).collect(), errors)
+    }
+
+    /// Like `process_files`, with `unselected` sitting in the same folder but not being renamed.
+    fn process_listed(modifiers: &mut super::Modifiers, files: &[&str], unselected: &[&str], order: super::ModsOrder) -> (Vec<String>, Vec<super::ModifierThreadError>) {
+        let listing: Vec<super::ListedItem> = files.iter().map(|name| (name, true))
+            .chain(unselected.iter().map(|name| (name, false)))
+            .map(|(name, selected)| super::ListedItem { name: name.to_string(), is_folder: false, selected: selected })
+            .collect();
+        let (renamed, errors) = super::process(super::Batch { folder_index: 0, offset: 0, selection: &[], listing: &listing }, String::from("/scratch"), modifiers, names(files), vec![order], false);
+        (renamed.into_iter().map(|file| file.0).collect(), errors)
+    }
+
     #[test]
     fn test_add() {
         let (prefix, _) = super::add(String::from("Hello world"), 
@@ -1190,33 +3093,660 @@ mod tests {
                 prefix: String::from("Test - "), 
                 insert: String::from(""), 
                 insert_at: 0, 
+                anchor: super::InsertAnchor::default(), 
                 suffix: String::from(""), 
                 seperator: ' ', 
                 seperator_enabled: false 
-            });
+            }, &super::Patterns::default());
         let (suffix, _) = super::add(String::from("Hello world"), 
             String::from(".ext"), 
             super::ModAdd { 
                 prefix: String::from(""), 
                 insert: String::from(""), 
                 insert_at: 0, 
+                anchor: super::InsertAnchor::default(), 
                 suffix: String::from(" - Test"), 
                 seperator: ' ', 
                 seperator_enabled: false 
-            });
+            }, &super::Patterns::default());
         let (insert, _) = super::add(String::from("Hello world"), 
             String::from(".ext"), 
             super::ModAdd { 
                 prefix: String::from(""), 
                 insert: String::from("Test"), 
                 insert_at: 5, 
+                anchor: super::InsertAnchor::default(), 
                 suffix: String::from(""), 
                 seperator: ' ', 
                 seperator_enabled: false 
-            });
+            }, &super::Patterns::default());
         assert_eq!(prefix, String::from("Test - Hello world"));
         assert_eq!(suffix, String::from("Hello world - Test"));
         assert_eq!(insert, String::from("HelloTest world"));
+
+        let anchored = |file: &str, mode: super::AnchorMode, pattern: &str, regex: bool, fallback: super::AnchorFallback| {
+            super::add(String::from(file), String::new(), super::ModAdd { 
+                insert: String::from("#"), 
+                insert_at: 2, 
+                anchor: super::InsertAnchor { mode, pattern: String::from(pattern), regex, fallback, ..Default::default() },
+                ..Default::default() 
+            }, &super::Patterns::default()).0
+        };
+        use super::{AnchorMode, AnchorFallback};
+        assert_eq!(anchored("ab12cd34", AnchorMode::BeforeFirst, r"\d", true, AnchorFallback::Skip), String::from("ab#12cd34"));
+        assert_eq!(anchored("a-b-c", AnchorMode::AfterFirst, "-", false, AnchorFallback::Skip), String::from("a-#b-c"));
+        assert_eq!(anchored("a-b-c", AnchorMode::AfterLast, "-", false, AnchorFallback::Skip), String::from("a-b-#c"));
+        assert_eq!(anchored("one two three", AnchorMode::BeforeLast, r"\w+", true, AnchorFallback::Skip), String::from("one two #three"));
+        assert_eq!(anchored("ćžabc", AnchorMode::BeforeFirst, "a", false, AnchorFallback::Skip), String::from("ćž#abc"));
+        assert_eq!(anchored("abc", AnchorMode::BeforeFirst, "x", false, AnchorFallback::Skip), String::from("abc"));
+        assert_eq!(anchored("abc", AnchorMode::BeforeFirst, "x", false, AnchorFallback::Position), String::from("ab#c"));
+        assert_eq!(anchored("abc", AnchorMode::BeforeFirst, "x", false, AnchorFallback::End), String::from("abc#"));
+    }
+
+    #[test]
+    fn test_case() {
+        let case = |file: &str, mode: super::CaseMode| {
+            super::case(String::from(file), String::new(), super::ModCase { mode: mode, ..Default::default() }).0
+        };
+        assert_eq!(case("straße été", super::CaseMode::Upper), String::from("STRASSE ÉTÉ"));
+        assert_eq!(case("ΑΘΗΝΑ Москва", super::CaseMode::Lower), String::from("αθηνα москва"));
+        assert_eq!(case("the lord_of the-rings", super::CaseMode::Title), String::from("The Lord_of the-Rings"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Camel), String::from("myHttpServerV2FinalCut"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Pascal), String::from("MyHttpServerV2FinalCut"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Snake), String::from("my_http_server_v2_final_cut"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Kebab), String::from("my-http-server-v2-final-cut"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Constant), String::from("MY_HTTP_SERVER_V2_FINAL_CUT"));
+        assert_eq!(case("myHTTPServer_v2 final-cut", super::CaseMode::Sentence), String::from("My http server v2 final cut"));
+
+        let title = |file: &str, language: super::TitleLanguage, protected: &str| {
+            super::case(String::from(file), String::new(), super::ModCase {
+                mode: super::CaseMode::Title,
+                title_language: language,
+                protected_words: String::from(protected),
+                ..Default::default()
+            }).0
+        };
+        assert_eq!(title("the usb guide to nasa", super::TitleLanguage::English, "USB, NASA"), String::from("The USB Guide to NASA"));
+        assert_eq!(title("what it is made of", super::TitleLanguage::English, ""), String::from("What It Is Made Of"));
+        assert_eq!(title("la casa de papel", super::TitleLanguage::Spanish, ""), String::from("La Casa de Papel"));
+        let snake = super::case(String::from("my iphone backup"), String::new(), super::ModCase {
+            mode: super::CaseMode::Snake,
+            protected_words: String::from("iPhone"),
+            ..Default::default()
+        }).0;
+        assert_eq!(snake, String::from("my_iPhone_backup"));
+    }
+
+    #[test]
+    fn test_transliterate() {
+        assert_eq!(super::strip_accents("Ærøskøbing Łódź Đà Nẵng ÉTÉ straße"), String::from("AEroskobing Lodz Da Nang ETE strasse"));
+        let transliterate = |file: &str, mode: super::TransliterateMode| {
+            super::transliterate(String::from(file), String::new(), super::ModTransliterate { mode: mode, ..Default::default() }).0
+        };
+        assert_eq!(transliterate("Москва Αθήνα", super::TransliterateMode::Ascii), String::from("Moskva Athena"));
+        assert_eq!(transliterate("café Москва", super::TransliterateMode::NonLatin), String::from("café Moskva"));
+        assert_eq!(transliterate("café Москва", super::TransliterateMode::None), String::from("café Москва"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let decomposed = String::from("Cafe\u{301}");
+        let composed = String::from("Caf\u{e9}");
+        let modnormalize = |mode: super::NormalizeMode| super::ModNormalize { mode: mode, ..Default::default() };
+        assert_eq!(super::normalize(decomposed.clone(), String::new(), modnormalize(super::NormalizeMode::NFC)).0, composed);
+        assert_eq!(super::normalize(composed.clone(), String::new(), modnormalize(super::NormalizeMode::NFD)).0, decomposed);
+        assert_eq!(super::normalize(String::from("\u{fb01}le\u{b2}"), String::new(), modnormalize(super::NormalizeMode::NFKC)).0, String::from("file2"));
+
+        // The composed name isn't selected, the decomposed one still collides with it.
+        let mut modifiers = super::Modifiers { normalize: modnormalize(super::NormalizeMode::NFC), normalize_enabled: true, ..Default::default() };
+        let listing = vec![
+            super::ListedItem { name: composed.clone(), is_folder: true, selected: false },
+            super::ListedItem { name: decomposed.clone(), is_folder: false, selected: true }
+        ];
+        let (_, errors) = super::process(super::Batch { folder_index: 0, offset: 0, selection: &[], listing: &listing }, String::from("/scratch"), &mut modifiers, names(&[&decomposed]), vec![super::ModsOrder::Normalize], false);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::NormalizationCollision(collisions) if collisions[..] == [(0, composed.clone())])));
+        let (_, errors) = process_files(&mut modifiers, &[&decomposed], super::ModsOrder::Normalize);
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::NormalizationCollision(collisions) if !collisions.is_empty())));
+    }
+
+    #[test]
+    fn test_regex() {
+        let regex = |file: &str, ext: &str, modregex: super::ModRegex| {
+            let reg = super::build_regex(&modregex).unwrap().unwrap();
+            super::regex(String::from(file), String::from(ext), modregex, &reg)
+        };
+        let modregex = |pattern: &str, with: &str| super::ModRegex {
+            replace_match: String::from(pattern),
+            replace_with: String::from(with),
+            ..Default::default()
+        };
+        assert_eq!(regex("a1 b2 c3", "", modregex("[0-9]", "#")).0, String::from("a# b2 c3"));
+        assert_eq!(regex("a1 b2 c3", "", super::ModRegex { nth: 2, ..modregex("([a-z])([0-9])", "$2$1") }).0, String::from("a1 2b c3"));
+        assert_eq!(regex("a1 b2 c3", "", super::ModRegex { occurrence: super::RegexOccurrence::All, ..modregex("[0-9]", "") }).0, String::from("a b c"));
+        assert_eq!(regex("Photo PHOTO", "", super::ModRegex { case_insensitive: true, occurrence: super::RegexOccurrence::All, ..modregex("photo", "img") }).0, String::from("img img"));
+        assert_eq!(regex("photo", ".jpeg", super::ModRegex { scope: super::RegexScope::Extension, ..modregex("^jpeg$", "jpg") }), (String::from("photo"), String::from(".jpg")));
+        assert_eq!(regex("photo", ".tar", super::ModRegex { scope: super::RegexScope::Full, ..modregex("\\.tar$", ".tar.gz") }), (String::from("photo.tar"), String::from(".gz")));
+        assert_eq!(regex("the.office.s02e3", "", modregex(r"^(.+)\.s(\d+)e(\d+)$", r"\T$1\E - S${2:02}E${3:02}")).0, String::from("The.Office - S02E03"));
+        assert_eq!(regex("artist - song", "", modregex(r"^(?P<artist>\w+) - (?P<title>\w+)$", r"\U$artist\E - \T${title}")).0, String::from("ARTIST - Song"));
+        assert_eq!(regex("abc", "", modregex("b", r"$$\\")).0, String::from("a$\\c"));
+        assert!(super::build_regex(&modregex("(unclosed", "")).is_err());
+        assert!(super::build_regex(&modregex("", "")).unwrap().is_none());
+
+        let names = vec![(String::from("track 7.mp3"), false), (String::from("cover.jpg"), false)];
+        let preview = super::preview_regex(&modregex(r"(\d+)", "${1:02}"), &names).unwrap().unwrap();
+        assert_eq!(preview.spans, vec![(6, 7)]);
+        assert_eq!(preview.captures, vec![(String::from("1"), String::from("7"))]);
+        assert_eq!(preview.result, String::from("track 07.mp3"));
+        assert_eq!(preview.names_matched, 1);
+    }
+
+    #[test]
+    fn test_replace() {
+        let replace = |file: &str, modreplace: super::ModReplace| {
+            super::replace(String::from(file), String::new(), modreplace, &super::Patterns::default()).0
+        };
+        let modreplace = |find: &str, with: &str| super::ModReplace {
+            replace_match: String::from(find),
+            replace_with: String::from(with),
+            ..Default::default()
+        };
+        assert_eq!(replace("a-b-c-d", modreplace("-", " ")), String::from("a b c d"));
+        assert_eq!(replace("a-b-c-d", super::ModReplace { occurrence: super::ReplaceOccurrence::First, ..modreplace("-", " ") }), String::from("a b-c-d"));
+        assert_eq!(replace("a-b-c-d", super::ModReplace { occurrence: super::ReplaceOccurrence::Last, ..modreplace("-", " ") }), String::from("a-b-c d"));
+        assert_eq!(replace("a-b-c-d", super::ModReplace { occurrence: super::ReplaceOccurrence::Nth, nth: 2, ..modreplace("-", " ") }), String::from("a-b c-d"));
+        assert_eq!(replace("a-b", super::ModReplace { occurrence: super::ReplaceOccurrence::Nth, nth: 5, ..modreplace("-", " ") }), String::from("a-b"));
+        assert_eq!(replace("x".repeat(300).as_str(), modreplace("x", "y")), "y".repeat(300));
+        assert_eq!(replace("Cat cat CAT", super::ModReplace { case_insensitive: true, ..modreplace("cat", "dog") }), String::from("dog dog dog"));
+        assert_eq!(replace("Cat cat CAT", super::ModReplace { case_insensitive: true, preserve_case: true, ..modreplace("cat", "dog") }), String::from("Dog dog DOG"));
+        assert_eq!(replace("cat catalog bobcat cat", super::ModReplace { whole_word: true, ..modreplace("cat", "dog") }), String::from("dog catalog bobcat dog"));
+        assert_eq!(replace("the_cat", super::ModReplace { whole_word: true, ..modreplace("cat", "dog") }), String::from("the_dog"));
+        // Compiled once, then reused for every name.
+        let patterns = super::Patterns::default();
+        for file in ["a-b", "c-d"] {
+            super::replace(String::from(file), String::new(), modreplace("-", " "), &patterns);
+        }
+        assert_eq!(patterns.compiled.borrow().len(), 1);
+
+        let mut modifiers = super::Modifiers::default();
+        modifiers.replace = vec![super::ModReplace { first_occurance: true, ..modreplace("-", " ") }];
+        modifiers.migrate();
+        assert_eq!(modifiers.replace[0].occurrence, super::ReplaceOccurrence::First);
+    }
+
+    #[test]
+    fn test_dictionary() {
+        let dictionary = |file: &str, moddictionary: super::ModDictionary| {
+            let pairs = super::build_dictionary(&moddictionary);
+            super::dictionary(String::from(file), String::new(), &moddictionary, &pairs).0
+        };
+        let moddictionary = |table: &str| super::ModDictionary { table: String::from(table), ..Default::default() };
+        assert_eq!(dictionary("teh recieve", moddictionary("teh,the\nrecieve,receive")), String::from("the receive"));
+        assert_eq!(dictionary("new york", moddictionary("new,NEW\nnew york,NYC")), String::from("NYC"));
+        assert_eq!(dictionary("new york", super::ModDictionary { order: super::DictionaryOrder::Listed, ..moddictionary("new,NEW\nnew york,NYC") }), String::from("NEW york"));
+        assert_eq!(dictionary("a b", moddictionary("a,b\nb,c")), String::from("b c"));
+        assert_eq!(dictionary("Ep EP ep", super::ModDictionary { case_insensitive: true, preserve_case: true, ..moddictionary("ep\tepisode") }), String::from("Episode EPISODE episode"));
+        assert_eq!(dictionary("cat catalog", super::ModDictionary { whole_word: true, ..moddictionary("cat,dog") }), String::from("dog catalog"));
+        assert_eq!(dictionary("a,b", moddictionary("\"a,b\",\"say \"\"hi\"\"\"")), String::from("say \"hi\""));
+
+        let (pairs, malformed) = super::parse_pairs("# comment\n\nfind,replace\r\nbroken\n,empty\n");
+        assert_eq!(pairs, vec![(String::from("find"), String::from("replace"))]);
+        assert_eq!(malformed, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_mapping() {
+        let pairs = |old: &str, new: &str| vec![(String::from(old), String::from(new))];
+        assert_eq!(super::parse_mapping("old_name,new_name\nIMG_1.jpg,Beach.jpg").unwrap(), pairs("IMG_1.jpg", "Beach.jpg"));
+        assert_eq!(super::parse_mapping("IMG_1.jpg\tBeach.jpg").unwrap(), pairs("IMG_1.jpg", "Beach.jpg"));
+        assert_eq!(super::parse_mapping("{\"IMG_1.jpg\": \"Beach.jpg\"}").unwrap(), pairs("IMG_1.jpg", "Beach.jpg"));
+        assert_eq!(super::parse_mapping("[[\"IMG_1.jpg\", \"Beach.jpg\"]]").unwrap(), pairs("IMG_1.jpg", "Beach.jpg"));
+        assert_eq!(super::parse_mapping("[{\"old\": \"IMG_1.jpg\", \"new\": \"Beach.jpg\"}]").unwrap(), pairs("IMG_1.jpg", "Beach.jpg"));
+        assert!(super::parse_mapping("a.jpg,b.jpg\nbroken").is_err());
+        assert!(super::parse_mapping("a.jpg,b.jpg\na.jpg,c.jpg").is_err());
+        assert!(super::parse_mapping("[\"a.jpg\"]").is_err());
+        // Chains and swaps are saved through temporary names.
+        assert!(super::parse_mapping("a.jpg,Beach.jpeg\nb.jpg,a.jpg").is_ok());
+        assert!(super::parse_mapping("a.jpg,a.jpg\nb.jpg,c.jpg").is_ok());
+        let mut modifiers = super::Modifiers { 
+            mapping: super::ModMapping { table: String::from("a.jpg,b.jpg\nb.jpg,a.jpg"), ..Default::default() }, 
+            ..Default::default() 
+        };
+        let (renamed, errors) = process_files(&mut modifiers, &["a.jpg", "b.jpg"], super::ModsOrder::Mapping);
+        assert_eq!(renamed, vec!["b.jpg", "a.jpg"]);
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if !duplicates.is_empty())));
+
+        let mut modifiers = super::Modifiers { 
+            mapping: super::ModMapping { table: String::from("a.jpg,Beach.jpeg\nb.jpg,c.jpg"), ..Default::default() }, 
+            ..Default::default() 
+        };
+        let (renamed, errors) = process_files(&mut modifiers, &["a.jpg", "b.jpg", "d.jpg"], super::ModsOrder::Mapping);
+        assert_eq!(renamed, vec!["Beach.jpeg", "c.jpg", "d.jpg"]);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::Unmapped(unmapped) if *unmapped == vec![2])));
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if !duplicates.is_empty())));
+        // c.jpg is in the folder but not selected.
+        let (_, errors) = process_listed(&mut modifiers, &["a.jpg", "b.jpg", "d.jpg"], &["c.jpg"], super::ModsOrder::Mapping);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if *duplicates == vec![1])));
+    }
+
+    #[test]
+    fn test_name_list() {
+        let modnamelist = |table: &str| super::ModNameList { table: String::from(table), ..Default::default() };
+        assert_eq!(super::name_list_names(&modnamelist("  One   Two \r\n\nThree")), vec!["One Two", "Three"]);
+        assert_eq!(super::name_list_names(&super::ModNameList { strip_numbering: true, ..modnamelist("01. Intro\n2 - Song\n[03] Outro\n1999") }), 
+            vec!["Intro", "Song", "Outro", "1999"]);
+
+        let mut modifiers = super::Modifiers { name_list: modnamelist("Intro\nSong.flac"), ..Default::default() };
+        let (renamed, _) = process_files(&mut modifiers, &["track01.mp3", "track02.mp3"], super::ModsOrder::NameList);
+        assert_eq!(renamed, vec!["Intro.mp3", "Song.flac.mp3"]);
+        let mut dotted = super::Modifiers { name_list: modnamelist("Live Vol.2\nMr.Brightside\nOutro.MP3"), ..Default::default() };
+        let (renamed, _) = process_files(&mut dotted, &["track01.mp3", "track02.mp3", "track03.mp3"], super::ModsOrder::NameList);
+        assert_eq!(renamed, vec!["Live Vol.2.mp3", "Mr.Brightside.mp3", "Outro.mp3"]);
+        modifiers.name_list.keep_extension = false;
+        let (renamed, errors) = super::process(super::Batch { folder_index: 0, offset: 1, selection: &[], listing: &[] }, String::from("/scratch"), &mut modifiers, names(&["track01.mp3", "track02.mp3"]), vec![super::ModsOrder::NameList], false);
+        assert_eq!(renamed.iter().map(|file| file.0.as_str()).collect::<Vec<&str>>(), vec!["Song.flac", "track02.mp3"]);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::ListTooShort(unlisted) if *unlisted == vec![1])));
+    }
+
+    #[test]
+    fn test_remove_brackets() {
+        let modremove = super::ModRemove { brackets_round: true, brackets_square: true, brackets_curly: true, ..Default::default() };
+        let remove = |file: &str, modremove: &super::ModRemove| super::remove_brackets(file, modremove, &super::Patterns::default());
+        assert_eq!(remove("[Group] Show (1080p) {tag}", &modremove), String::from(" Show  "));
+        assert_eq!(remove("Show [a (b) [c]] end", &modremove), String::from("Show  end"));
+        assert_eq!(remove("Show (unclosed", &modremove), String::from("Show (unclosed"));
+        assert_eq!(remove("Song (feat. X [Remix]", &modremove), String::from("Song (feat. X "));
+        assert_eq!(remove("Song (feat. [X) end]", &modremove), String::from("Song  end]"));
+        assert_eq!(remove("[A] Show [B]", &super::ModRemove { brackets_which: super::RemoveBracketsMode::First, ..modremove.clone() }), String::from(" Show [B]"));
+        assert_eq!(remove("[A] Show [B]", &super::ModRemove { brackets_which: super::RemoveBracketsMode::Last, ..modremove.clone() }), String::from("[A] Show "));
+        assert_eq!(remove("Film (2009) (1080p)", &super::ModRemove { brackets_keep: String::from(r"^\d{4}$"), ..modremove.clone() }), String::from("Film (2009) "));
+        assert_eq!(remove("Ünï «tag» ok", &super::ModRemove { brackets_custom: String::from("«»"), ..Default::default() }), String::from("Ünï  ok"));
+        // An invalid keep pattern leaves the name alone rather than removing every bracket.
+        let mut modifiers = super::Modifiers { 
+            remove: vec![super::ModRemove { brackets_keep: String::from("(unclosed"), ..modremove.clone() }], 
+            remove_enabled: true, 
+            ..Default::default() 
+        };
+        let (renamed, errors) = process_files(&mut modifiers, &["Film (2009).mkv"], super::ModsOrder::Remove);
+        assert_eq!(renamed, vec!["Film (2009).mkv"]);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::InvalidRegex(invalid) if invalid.len() == 1)));
+    }
+
+    #[test]
+    fn test_remove_crop() {
+        let patterns = super::Patterns::default();
+        let crop = |file: &str, mode: super::RemoveCropMode, a: &str, b: &str| super::remove_crop(String::from(file), &super::ModRemove {
+            crop: mode,
+            crop_match: String::from(a),
+            crop_match_end: String::from(b),
+            ..Default::default()
+        }, &patterns);
+        assert_eq!(crop("intro - song", super::RemoveCropMode::Before, " - ", ""), String::from(" - song"));
+        assert_eq!(crop("intro - song", super::RemoveCropMode::After, " - ", ""), String::from("intro - "));
+        assert_eq!(crop("日本語 – 歌", super::RemoveCropMode::After, "–", ""), String::from("日本語 –"));
+        assert_eq!(crop("a [keep] b", super::RemoveCropMode::KeepBetween, "[", "]"), String::from("keep"));
+        assert_eq!(crop("a [drop] b", super::RemoveCropMode::RemoveBetween, "[", "]"), String::from("a  b"));
+        assert_eq!(crop("a [drop", super::RemoveCropMode::RemoveBetween, "[", "]"), String::from("a [drop"));
+        assert_eq!(super::remove_crop(String::from("a [x] b"), &super::ModRemove {
+            crop: super::RemoveCropMode::RemoveBetween, crop_keep_markers: true, crop_match: String::from("["), crop_match_end: String::from("]"), ..Default::default()
+        }, &patterns), String::from("a [] b"));
+        assert_eq!(super::remove_crop(String::from("a.b.c.d"), &super::ModRemove {
+            crop: super::RemoveCropMode::After, crop_occurrence: super::RemoveCropOccurrence::Last, crop_match: String::from("."), ..Default::default()
+        }, &patterns), String::from("a.b.c."));
+        assert_eq!(super::remove_crop(String::from("a.b.c.d"), &super::ModRemove {
+            crop: super::RemoveCropMode::Before, crop_occurrence: super::RemoveCropOccurrence::Nth, crop_nth: 2, crop_match: String::from("."), ..Default::default()
+        }, &patterns), String::from(".c.d"));
+        assert_eq!(super::remove_crop(String::from("ep12 title s01"), &super::ModRemove {
+            crop: super::RemoveCropMode::KeepBetween, crop_regex: true, crop_match: String::from(r"ep\d+ "), crop_match_end: String::from(r" s\d+"), ..Default::default()
+        }, &patterns), String::from("title"));
+    }
+
+    #[test]
+    fn test_segment() {
+        let segment = |file: &str, modsegment: super::ModSegment| super::segment(String::from(file), String::new(), modsegment).0;
+        let modsegment = |delimiter: &str, order: &str| super::ModSegment { 
+            delimiter: String::from(delimiter), 
+            order: String::from(order), 
+            ..Default::default() 
+        };
+        assert_eq!(segment("Title - Artist", modsegment(" - ", "1,0")), String::from("Artist - Title"));
+        assert_eq!(segment("31-12-2019", super::ModSegment { joiner: String::from("."), ..modsegment("-", "2,1,0") }), String::from("2019.12.31"));
+        assert_eq!(segment("a _ b", super::ModSegment { joiner: String::from("+"), ..modsegment("_", "1,0") }), String::from("b+a"));
+        assert_eq!(segment("a _ b", super::ModSegment { joiner: String::from("+"), trim: false, ..modsegment("_", "1,0") }), String::from(" b+a "));
+        assert_eq!(segment("Title", modsegment(" - ", "1,0")), String::from("Title"));
+        assert_eq!(segment("a1b22c", super::ModSegment { delimiter_regex: true, ..modsegment(r"\d+", "2,1,0") }), String::from("c1b1a"));
+        assert!(super::segment_order("2,x").is_err());
+    }
+
+    #[test]
+    fn test_movecopy() {
+        let movecopy = |file: &str, modmovecopy: super::ModMoveCopy| super::movecopy(String::from(file), String::new(), modmovecopy, &super::Patterns::default()).0;
+        let modmovecopy = |unit: super::MoveCopyUnit, mode_from: super::MoveCopyFromMode, mode_to: super::MoveCopyToMode| super::ModMoveCopy { 
+            unit,
+            mode_from,
+            mode_to,
+            letters_count: 1,
+            ..Default::default() 
+        };
+        use super::{MoveCopyUnit as Unit, MoveCopyFromMode as From, MoveCopyToMode as To};
+        assert_eq!(movecopy("The Song Name", modmovecopy(Unit::Words, From::MoveFirstN, To::ToEnd)), String::from("Song Name The"));
+        assert_eq!(movecopy("Song_Name_2019", modmovecopy(Unit::Words, From::MoveLastN, To::ToStart)), String::from("2019 Song_Name"));
+        assert_eq!(
+            movecopy("Song_Name_2019", super::ModMoveCopy { seperator: '_', seperator_enabled: true, ..modmovecopy(Unit::Words, From::MoveLastN, To::ToStart) }), 
+            String::from("2019_Song_Name")
+        );
+        assert_eq!(
+            movecopy("a b c d", super::ModMoveCopy { letters_count: 2, ..modmovecopy(Unit::Words, From::CopyFirstN, To::ToEnd) }), 
+            String::from("a b c d a b")
+        );
+        assert_eq!(
+            movecopy("Title - 2019", super::ModMoveCopy { unit_regex: String::from(r"\d{4}"), ..modmovecopy(Unit::Regex, From::MoveLastN, To::ToStart) }), 
+            String::from("2019 Title")
+        );
+        assert_eq!(
+            movecopy("Live Song (2019)", super::ModMoveCopy { 
+                mode_to_match: String::from("("), 
+                seperator_enabled: true, 
+                ..modmovecopy(Unit::Words, From::MoveFirstN, To::BeforeMatch) 
+            }), 
+            String::from("Song Live (2019)")
+        );
+        assert_eq!(
+            movecopy("ab12cd", super::ModMoveCopy { 
+                letters_count: 2,
+                mode_to_match: String::from(r"\d+"), 
+                mode_to_regex: true, 
+                ..modmovecopy(Unit::Letters, From::MoveFirstN, To::AfterMatch) 
+            }), 
+            String::from("12abcd")
+        );
+        assert_eq!(movecopy("Title", super::ModMoveCopy { mode_to_match: String::from("x"), ..modmovecopy(Unit::Words, From::MoveFirstN, To::AfterMatch) }), String::from("Title"));
+        assert_eq!(movecopy("abc", super::ModMoveCopy { letters_count: 2, ..modmovecopy(Unit::Letters, From::MoveFirstN, To::ToEnd) }), String::from("c ab"));
+    }
+
+    #[test]
+    fn test_number() {
+        let proto_files = vec![
+            (String::from("/scratch/a"), vec![], names(&["x.jpg", "y.png", "z.jpg"])),
+            (String::from("/scratch/b"), vec![], names(&["a_1.jpg", "b_1.jpg", "a_2.png"]))
+        ];
+        let selection = super::selection_items(&proto_files);
+        let numbered = |modnumber: super::ModNumber| -> Vec<String> {
+            let mut modifiers = super::Modifiers { number: vec![modnumber], number_enabled: true, ..Default::default() };
+            let mut renamed: Vec<String> = vec![];
+            let mut offset: usize = 0;
+            for (index, (path, _, files)) in proto_files.iter().enumerate() {
+                let (files_renamed, _) = super::process(super::Batch { folder_index: index, offset, selection: &selection, listing: &[] }, path.to_owned(), &mut modifiers, files.to_owned(), vec![super::ModsOrder::Number], false);
+                offset += files.len();
+                renamed.extend(files_renamed.into_iter().map(|file| file.0));
+            };
+            renamed
+        };
+        let modnumber = |scope: super::NumberScope| super::ModNumber { 
+            mode: super::NumberMode::Prefix, 
+            seperator_enabled: true, 
+            scope: scope, 
+            ..Default::default() 
+        };
+        assert_eq!(numbered(modnumber(super::NumberScope::Folder)), vec!["1 x.jpg", "2 y.png", "3 z.jpg", "1 a_1.jpg", "2 b_1.jpg", "3 a_2.png"]);
+        assert_eq!(numbered(modnumber(super::NumberScope::Global)), vec!["1 x.jpg", "2 y.png", "3 z.jpg", "4 a_1.jpg", "5 b_1.jpg", "6 a_2.png"]);
+        assert_eq!(numbered(modnumber(super::NumberScope::Extension)), vec!["1 x.jpg", "1 y.png", "2 z.jpg", "3 a_1.jpg", "4 b_1.jpg", "2 a_2.png"]);
+        assert_eq!(
+            numbered(super::ModNumber { group_regex: String::from(r"^([a-z])_"), ..modnumber(super::NumberScope::Group) }), 
+            vec!["1 x.jpg", "2 y.png", "3 z.jpg", "1 a_1.jpg", "1 b_1.jpg", "2 a_2.png"]
+        );
+        assert_eq!(
+            numbered(super::ModNumber { reverse: true, auto_padding: true, starting_num: 5, ..modnumber(super::NumberScope::Global) }), 
+            vec!["10 x.jpg", "09 y.png", "08 z.jpg", "07 a_1.jpg", "06 b_1.jpg", "05 a_2.png"]
+        );
+        // Subfolders and files of a folder count apart.
+        let mut modifiers = super::Modifiers { number: vec![modnumber(super::NumberScope::Folder)], number_enabled: true, ..Default::default() };
+        let selection = super::selection_items(&vec![(String::from("/scratch/a"), names(&["Raw", "Edits"]), names(&["x.jpg", "y.jpg"]))]);
+        let (folders, _) = super::process(super::Batch { folder_index: 0, offset: 0, selection: &selection, listing: &[] }, String::from("/scratch/a"), &mut modifiers, names(&["Raw", "Edits"]), vec![super::ModsOrder::Number], true);
+        let (files, _) = super::process(super::Batch { folder_index: 0, offset: 2, selection: &selection, listing: &[] }, String::from("/scratch/a"), &mut modifiers, names(&["x.jpg", "y.jpg"]), vec![super::ModsOrder::Number], false);
+        assert_eq!(folders.into_iter().chain(files).map(|item| item.0).collect::<Vec<String>>(), vec!["1 Raw", "2 Edits", "1 x.jpg", "2 y.jpg"]);
+        assert_eq!(
+            numbered(super::ModNumber { increment_num: -2, starting_num: 3, ..modnumber(super::NumberScope::Folder) }), 
+            vec!["3 x.jpg", "1 y.png", "-1 z.jpg", "3 a_1.jpg", "1 b_1.jpg", "-1 a_2.png"]
+        );
+
+        let count = |value: i64, mode_type: super::NumberTypeMode| super::number_string(value, &super::ModNumber { mode_type: mode_type, ..Default::default() });
+        assert_eq!(count(255, super::NumberTypeMode::BaseSixteenUpper), Ok(String::from("FF")));
+        assert_eq!(count(71, super::NumberTypeMode::BaseThirtySix), Ok(String::from("1z")));
+        assert_eq!(count(-61, super::NumberTypeMode::BaseSixtyTwo), Ok(String::from("-z")));
+        assert_eq!(count(1994, super::NumberTypeMode::RomanNumeral), Ok(String::from("MCMXCIV")));
+        assert!(count(0, super::NumberTypeMode::RomanNumeral).is_err());
+        assert!(count(40000, super::NumberTypeMode::RomanNumeral).is_err());
+        assert!(count(0, super::NumberTypeMode::AlphaLower).is_err());
+        assert!(super::number_string(1, &super::ModNumber { mode_type: super::NumberTypeMode::Custom, alphabet: String::from("abca"), ..Default::default() }).is_err());
+        assert_eq!(count(0, super::NumberTypeMode::Words), Ok(String::from("zero")));
+        assert_eq!(count(121, super::NumberTypeMode::Words), Ok(String::from("one hundred twenty-one")));
+        assert_eq!(count(-2_000_013, super::NumberTypeMode::Words), Ok(String::from("minus two million thirteen")));
+        assert_eq!(
+            numbered(super::ModNumber { mode_type: super::NumberTypeMode::Ordinal, starting_num: 11, padding: 3, ..modnumber(super::NumberScope::Global) }), 
+            vec!["011th x.jpg", "012th y.png", "013th z.jpg", "014th a_1.jpg", "015th b_1.jpg", "016th a_2.png"]
+        );
+        assert_eq!(
+            numbered(super::ModNumber { mode_type: super::NumberTypeMode::Ordinal, starting_num: 21, ..modnumber(super::NumberScope::Folder) })[0..3], 
+            ["21st x.jpg", "22nd y.png", "23rd z.jpg"]
+        );
+        // A count that can't be written keeps the name and errors.
+        let mut modifiers = super::Modifiers { 
+            number: vec![super::ModNumber { mode_type: super::NumberTypeMode::RomanNumeral, starting_num: 0, ..modnumber(super::NumberScope::Folder) }], 
+            number_enabled: true, 
+            ..Default::default() 
+        };
+        let (renamed, errors) = process_files(&mut modifiers, &["x.jpg", "y.jpg"], super::ModsOrder::Number);
+        assert_eq!(renamed, vec!["x.jpg", "I y.jpg"]);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::InvalidNumber(invalid) if invalid.len() == 1 && invalid[0].0 == 0)));
+        assert_eq!(super::number_pad(String::from("-7"), 3, &super::ModNumber::default()), String::from("-007"));
+    }
+
+    #[test]
+    fn test_renumber() {
+        let renumber = |file: &str, modrenumber: super::ModRenumber| {
+            super::renumber(String::from(file), String::new(), &modrenumber, &std::collections::HashMap::new()).unwrap().0
+        };
+        assert_eq!(renumber("ep 2", super::ModRenumber { padding: 3, ..Default::default() }), String::from("ep 002"));
+        assert_eq!(renumber("ep 010", super::ModRenumber { offset: -1, ..Default::default() }), String::from("ep 009"));
+        assert_eq!(renumber("Track 7 of 12", super::ModRenumber { padding: 2, ..Default::default() }), String::from("Track 07 of 12"));
+        assert_eq!(
+            renumber("Track 7 of 12", super::ModRenumber { occurrence: super::RenumberOccurrence::Last, offset: 1, ..Default::default() }), 
+            String::from("Track 7 of 13")
+        );
+        assert_eq!(
+            renumber("s1 e5 v2", super::ModRenumber { occurrence: super::RenumberOccurrence::Nth, nth: 2, padding: 2, ..Default::default() }), 
+            String::from("s1 e05 v2")
+        );
+        assert_eq!(renumber("no number", super::ModRenumber { padding: 3, ..Default::default() }), String::from("no number"));
+
+        let resequenced = |modrenumber: super::ModRenumber| -> Vec<String> {
+            let mut modifiers = super::Modifiers { renumber: modrenumber, ..Default::default() };
+            process_files(&mut modifiers, &["ep 2.mkv", "ep 10.mkv", "ep 5.mkv", "extra.mkv"], super::ModsOrder::Renumber).0
+        };
+        assert_eq!(resequenced(super::ModRenumber { resequence: true, ..Default::default() }), vec!["ep 1.mkv", "ep 3.mkv", "ep 2.mkv", "extra.mkv"]);
+        assert_eq!(
+            resequenced(super::ModRenumber { resequence: true, keep_gaps: true, start: 1, padding: 2, ..Default::default() }), 
+            vec!["ep 01.mkv", "ep 09.mkv", "ep 04.mkv", "extra.mkv"]
+        );
+        // A numbered subfolder selected alongside is resequenced on its own.
+        let selection: Vec<super::SelectionItem> = [("Season 1", true), ("ep 2.mkv", false), ("ep 5.mkv", false), ("ep 10.mkv", false)].iter()
+            .map(|(name, is_folder)| super::SelectionItem { folder: 0, name: name.to_string(), is_folder: *is_folder })
+            .collect();
+        let mut modifiers = super::Modifiers { renumber: super::ModRenumber { resequence: true, ..Default::default() }, ..Default::default() };
+        let (renamed, _) = super::process(super::Batch { folder_index: 0, offset: 1, selection: &selection, listing: &[] }, String::from("/scratch"), &mut modifiers, 
+            names(&["ep 2.mkv", "ep 5.mkv", "ep 10.mkv"]), vec![super::ModsOrder::Renumber], false);
+        assert_eq!(renamed.into_iter().map(|file| file.0).collect::<Vec<String>>(), vec!["ep 1.mkv", "ep 2.mkv", "ep 3.mkv"]);
+        // A number added before renumber isn't in the table, it errors instead of mixing in the raw number.
+        let mut modifiers = super::Modifiers { 
+            add: vec![super::ModAdd { prefix: String::from("2024 "), ..Default::default() }], 
+            renumber: super::ModRenumber { resequence: true, ..Default::default() }, 
+            ..Default::default() 
+        };
+        let (renamed, errors) = super::process(super::Batch { folder_index: 0, offset: 0, selection: &[], listing: &[] }, String::from("/scratch"), &mut modifiers, names(&["ep 2.mkv", "ep 5.mkv"]), 
+            vec![super::ModsOrder::Add, super::ModsOrder::Renumber], false);
+        assert_eq!(renamed[0].0, "2024 ep 2.mkv");
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::InvalidNumber(invalid) if invalid.len() == 2)));
+
+        // Shifting up onto numbers that are also being renamed is fine, onto one that stays isn't.
+        let mut modifiers = super::Modifiers { renumber: super::ModRenumber { offset: 1, ..Default::default() }, ..Default::default() };
+        let (renamed, errors) = process_files(&mut modifiers, &["ep 1.mkv", "ep 2.mkv"], super::ModsOrder::Renumber);
+        assert_eq!(renamed, vec!["ep 2.mkv", "ep 3.mkv"]);
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if !duplicates.is_empty())));
+        let (_, errors) = process_listed(&mut modifiers, &["ep 1.mkv", "ep 2.mkv"], &["ep 3.mkv"], super::ModsOrder::Renumber);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if *duplicates == vec![1])));
+    }
+
+    #[test]
+    fn test_sequence() {
+        let sequenced = |modsequence: super::ModSequence| -> (Vec<String>, Vec<super::ModifierThreadError>) {
+            let mut modifiers = super::Modifiers { sequence: modsequence, ..Default::default() };
+            process_files(&mut modifiers, &["shot_0012.exr", "shot_0010.exr", "shot_0011.exr", "shot_0015.exr", "notes.txt"], super::ModsOrder::Sequence)
+        };
+        assert_eq!(
+            sequenced(super::ModSequence { renumber: true, start: 1001, ..Default::default() }).0, 
+            vec!["shot_1003.exr", "shot_1001.exr", "shot_1002.exr", "shot_1006.exr", "notes.txt"]
+        );
+        assert_eq!(
+            sequenced(super::ModSequence { renumber: true, close_gaps: true, padding: 3, ..Default::default() }).0, 
+            vec!["shot_003.exr", "shot_001.exr", "shot_002.exr", "shot_004.exr", "notes.txt"]
+        );
+        let (renamed, errors) = sequenced(super::ModSequence { prefix_enabled: true, prefix: String::from("plate."), ..Default::default() });
+        assert_eq!(renamed, vec!["plate.0012.exr", "plate.0010.exr", "plate.0011.exr", "plate.0015.exr", "notes.txt"]);
+        let gaps: Vec<(usize, String)> = errors.into_iter().filter_map(|error| match error {
+            super::ModifierThreadError::SequenceGaps(gaps) => Some(gaps),
+            _ => None
+        }).flatten().collect();
+        assert_eq!(gaps.len(), 4);
+        assert_eq!(gaps[0].1, "13-14");
+        // Shifting up overlaps the old frames, those are saved through temporary names, a frame left unselected isn't.
+        let mut modifiers = super::Modifiers { sequence: super::ModSequence { renumber: true, start: 2, ..Default::default() }, ..Default::default() };
+        let frames = ["shot_0001.exr", "shot_0002.exr", "shot_0003.exr"];
+        let (renamed, errors) = process_files(&mut modifiers, &frames, super::ModsOrder::Sequence);
+        assert_eq!(renamed, vec!["shot_0002.exr", "shot_0003.exr", "shot_0004.exr"]);
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if !duplicates.is_empty())));
+        let (_, errors) = process_listed(&mut modifiers, &frames, &["shot_0004.exr"], super::ModsOrder::Sequence);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::DuplicateFileName(duplicates) if *duplicates == vec![2])));
+        // Unchanged modifier, nothing is renamed or warned about.
+        let (renamed, errors) = sequenced(super::ModSequence::default());
+        assert_eq!(renamed[0], "shot_0012.exr");
+        assert!(errors.iter().all(|error| !matches!(error, super::ModifierThreadError::SequenceGaps(gaps) if !gaps.is_empty())));
+    }
+
+    #[test]
+    fn test_random() {
+        let randomized = |modrandom: super::ModRandom| -> Vec<String> {
+            let mut modifiers = super::Modifiers { random: modrandom, ..Default::default() };
+            process_files(&mut modifiers, &["a.jpg", "b.jpg"], super::ModsOrder::Random).0
+        };
+        let modrandom = |kind: super::RandomKind| super::ModRandom { mode: super::RandomMode::Replace, kind: kind, seed: 7, ..Default::default() };
+        let hex = randomized(modrandom(super::RandomKind::Hex));
+        assert_eq!(hex, randomized(modrandom(super::RandomKind::Hex)));
+        assert_ne!(hex[0], hex[1]);
+        // New seeds fit the float the seed is edited through.
+        let seed = super::ModRandom::default().seed;
+        assert!(seed <= super::random::SEED_MAXIMUM && seed as f64 as u64 == seed);
+        assert_ne!(hex, randomized(super::ModRandom { seed: 8, ..modrandom(super::RandomKind::Hex) }));
+        assert!(hex[0].len() == 12 && hex[0].ends_with(".jpg") && hex[0][..8].chars().all(|char| char.is_ascii_hexdigit()));
+
+        let uuid = randomized(modrandom(super::RandomKind::UuidV4));
+        let uuid_regex = super::Regex::new(r"^[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}\.jpg$").unwrap();
+        assert!(uuid_regex.is_match(&uuid[0]));
+        // Missing files have no modified date, their time part is the epoch.
+        assert!(randomized(modrandom(super::RandomKind::UuidV7))[0].starts_with("00000000-0000-7"));
+        let ulid = randomized(modrandom(super::RandomKind::Ulid));
+        assert_eq!(ulid[0].len(), 30);
+        assert!(ulid[0].starts_with("0000000000"));
+        assert_eq!(randomized(super::ModRandom { kind: super::RandomKind::NanoId, length: 21, ..modrandom(super::RandomKind::Hex) })[0].len(), 25);
+        assert_eq!(
+            randomized(super::ModRandom { mode: super::RandomMode::Prefix, seperator_enabled: true, seperator: '_', ..modrandom(super::RandomKind::Hex) })[0], 
+            format!("{}_a.jpg", &hex[0][..8])
+        );
+        assert_eq!(randomized(super::ModRandom { mode: super::RandomMode::None, ..modrandom(super::RandomKind::Hex) })[0], "a.jpg");
+    }
+
+    #[test]
+    fn test_truncate() {
+        let taken = std::collections::HashSet::new();
+        let tail = super::Regex::new(super::TRUNCATE_TAIL).unwrap();
+        let truncate = |file: &str, ext: &str, modtruncate: &super::ModTruncate| -> String {
+            let (file, ext) = super::truncate(String::from(file), String::from(ext), modtruncate, &taken, &tail);
+            format!("{}{}", file, ext)
+        };
+        let modtruncate = super::ModTruncate { limit: 20, ..Default::default() };
+        assert_eq!(truncate("short", ".txt", &modtruncate), "short.txt");
+        assert_eq!(truncate("The quick brown fox jumps", ".txt", &modtruncate), "The quick brown.txt");
+        assert_eq!(truncate("The quick brown fox jumps 042", ".txt", &modtruncate), "The quick 042.txt");
+        assert_eq!(truncate("The quick brown fox [1A2B3C4D]", ".mkv", &modtruncate), "The [1A2B3C4D].mkv");
+        assert_eq!(
+            truncate("The quick brown fox jumps", ".txt", &super::ModTruncate { word_boundary: false, marker_enabled: true, ..modtruncate.clone() }), 
+            "The quick brown….txt"
+        );
+        assert_eq!(truncate("The quick brown fox jumps", ".txt", &super::ModTruncate { count_extension: false, ..modtruncate.clone() }), "The quick brown fox.txt");
+        // Two bytes a letter, a limit of 9 bytes fits 4.
+        assert_eq!(truncate("ééééééé", "", &super::ModTruncate { limit: 9, unit: super::TruncateUnit::Bytes, ..Default::default() }), "éééé");
+
+        let mut modifiers = super::Modifiers { truncate: super::ModTruncate { limit: 24, ..Default::default() }, ..Default::default() };
+        let (renamed, _) = process_files(&mut modifiers, &["Holiday photos from the beach.jpg", "Holiday photos from the mountains.jpg"], super::ModsOrder::Truncate);
+        assert_eq!(renamed[0], "Holiday photos from.jpg");
+        assert!(renamed[1].starts_with("Holiday~") && renamed[1].ends_with(".jpg"));
+        assert!(renamed[1].chars().count() <= 24);
+        // A cut that lands on a name staying in the folder is deduped too.
+        let (renamed, _) = process_listed(&mut modifiers, &["Holiday photos from the beach.jpg"], &["Holiday photos from.jpg"], super::ModsOrder::Truncate);
+        assert!(renamed[0].starts_with("Holiday~"));
+    }
+
+    #[test]
+    fn test_seperators() {
+        let modseperators = super::ModSeperators { from: String::from("._-"), ..Default::default() };
+        let protect = super::build_seperator_protect(&modseperators.protect).unwrap();
+        let seperators = |file: &str, modseperators: &super::ModSeperators| super::seperators(file, modseperators, &protect);
+        assert_eq!(seperators("The.Movie.Name.2019.1080p", &modseperators), "The Movie Name 2019 1080p");
+        assert_eq!(seperators("some_file-name", &modseperators), "some file name");
+        assert_eq!(seperators("__some..file__", &modseperators), "some file");
+        assert_eq!(seperators("app.v1.2.3.final", &modseperators), "app v1.2.3 final");
+        assert_eq!(seperators("Concert.Audio.5.1.Mix", &modseperators), "Concert Audio 5.1 Mix");
+        assert_eq!(seperators("lib-2.10.4-beta", &modseperators), "lib 2.10.4 beta");
+        assert_eq!(seperators("a..b", &super::ModSeperators { collapse: false, ..modseperators.clone() }), "a  b");
+        assert_eq!(seperators("v1.2", &modseperators), "v1.2");
+        assert_eq!(seperators("v1.2", &super::ModSeperators { protect_enabled: false, ..modseperators.clone() }), "v1 2");
+        assert_eq!(
+            seperators("myHTTPServer_config", &super::ModSeperators { split_camel: true, to: '_', ..modseperators.clone() }), 
+            "my_HTTP_Server_config"
+        );
+        assert_eq!(seperators("unchanged.name", &super::ModSeperators::default()), "unchanged.name");
+
+        let mut modifiers = super::Modifiers { seperators: modseperators.clone(), ..Default::default() };
+        let (renamed, _) = process_files(&mut modifiers, &["some_file.tar.gz"], super::ModsOrder::Seperators);
+        assert_eq!(renamed, vec!["some file tar.gz"]);
+        modifiers.seperators.protect = String::from("(unclosed");
+        let (_, errors) = process_files(&mut modifiers, &["some_file.tar.gz"], super::ModsOrder::Seperators);
+        assert!(errors.iter().any(|error| matches!(error, super::ModifierThreadError::InvalidRegex(invalid) if invalid.len() == 1)));
+    }
+
+    #[test]
+    fn test_template() {
+        let source = super::TemplateSource {
+            name: "photo",
+            ext: ".jpg",
+            path: "/scratch/holiday/photo.jpg",
+            index: 4,
+            hash: "",
+            algorithm: super::HashType::CRC32,
+            is_folder: false
+        };
+        let modtemplate = |template: &str| super::ModTemplate {
+            template: String::from(template),
+            ..Default::default()
+        };
+        let (file, ext) = super::template(String::from("photo"), String::from(".jpg"),
+            modtemplate("{parent}_{counter:03}_{name}{ext}"), &source).unwrap();
+        let (inner, inner_ext) = super::template(String::from("photo"), String::from(".jpg"),
+            modtemplate("{name}{ext} {{copy}}"), &source).unwrap();
+        assert_eq!((file, ext), (String::from("holiday_005_photo"), String::from(".jpg")));
+        assert_eq!((inner, inner_ext), (String::from("photo.jpg {copy}"), String::new()));
+        assert!(super::validate_template("{name}_{nope}").is_err());
+        assert!(super::validate_template("{counter:abc}").is_err());
+        assert!(super::validate_template("{name").is_err());
     }
 
     fn test_date() {
@@ -0,0 +1,113 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const ALPHANUMERIC: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const HEX: &str = "0123456789abcdef";
+const NANOID: &str = "useandom-26T198340PX75pxJACKVERYMINDBUSHWOLF_GQZbfghjklqvwyzrict";
+// Crockford's base 32, no I, L, O or U.
+const ULID: &str = "0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+/// Seeds are edited through a float, 53 bits is all it holds exactly.
+pub const SEED_MAXIMUM: u64 = (1 << 53) - 1;
+
+/// Seeded generator, the same seed and key always give the same values.
+/// Nothing here is fit for secrets, it's for names.
+pub struct Generator {
+    state: u64
+}
+
+impl Generator {
+    pub fn new(seed: u64, key: &str) -> Self {
+        // FNV-1a over the key, so every item gets its own stream.
+        let mut state: u64 = 0xcbf29ce484222325 ^ seed;
+        for byte in key.bytes() {
+            state ^= byte as u64;
+            state = state.wrapping_mul(0x100000001b3);
+        }
+        Self { state }
+    }
+
+    /// SplitMix64.
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        value ^ (value >> 31)
+    }
+
+    /// Evenly spread over 0 to `bound`, without `bound`.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let value = self.next_u64();
+            if value < limit {
+                return value % bound;
+            };
+        }
+    }
+
+    pub fn bytes(&mut self, count: usize) -> Vec<u8> {
+        let mut bytes: Vec<u8> = vec![];
+        while bytes.len() < count {
+            bytes.extend_from_slice(&self.next_u64().to_be_bytes());
+        }
+        bytes.truncate(count);
+        bytes
+    }
+
+    fn pick(&mut self, alphabet: &str, length: usize) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        (0..length).map(|_| chars[self.below(chars.len() as u64) as usize]).collect()
+    }
+}
+
+/// A new seed from the clock.
+pub fn new_seed() -> u64 {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or_default();
+    Generator::new(nanos as u64, "seed").next_u64() & SEED_MAXIMUM
+}
+
+pub fn alphanumeric(generator: &mut Generator, length: usize) -> String {
+    generator.pick(ALPHANUMERIC, length)
+}
+
+pub fn hex(generator: &mut Generator, length: usize) -> String {
+    generator.pick(HEX, length)
+}
+
+/// NanoID's URL safe alphabet.
+pub fn nanoid(generator: &mut Generator, length: usize) -> String {
+    generator.pick(NANOID, length)
+}
+
+pub fn uuid_v4(generator: &mut Generator) -> String {
+    let mut bytes = generator.bytes(16);
+    bytes[6] = (bytes[6] & 0x0F) | 0x40;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    uuid_string(&bytes)
+}
+
+/// Time ordered, `time_ms` is milliseconds since the Unix epoch.
+pub fn uuid_v7(generator: &mut Generator, time_ms: u64) -> String {
+    let mut bytes = generator.bytes(16);
+    bytes[0..6].copy_from_slice(&time_ms.to_be_bytes()[2..8]);
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    uuid_string(&bytes)
+}
+
+/// Time ordered, `time_ms` is milliseconds since the Unix epoch.
+pub fn ulid(generator: &mut Generator, time_ms: u64) -> String {
+    let random = generator.bytes(10);
+    let mut value: u128 = (time_ms as u128 & 0xFFFF_FFFF_FFFF) << 80;
+    for (index, byte) in random.iter().enumerate() {
+        value |= (*byte as u128) << (72 - index * 8);
+    }
+    let chars: Vec<char> = ULID.chars().collect();
+    // 26 characters of 5 bits, the first only holds 3.
+    (0..26).rev().map(|place| chars[((value >> (place * 5)) & 0x1F) as usize]).collect()
+}
+
+fn uuid_string(bytes: &[u8]) -> String {
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32])
+}
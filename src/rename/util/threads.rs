@@ -18,6 +18,38 @@ fn rename_file(original_path: String, renamed_path: String) -> Result<(), io::Er
     fs::rename(original_path, renamed_path)
 }
 
+/// Orders the renames so none lands on an item that hasn't moved yet.
+/// Moves onto another move's source (chains, swaps, shifted numbers) go through a temporary name first.
+fn staged_moves(moves: Vec<(String, String)>) -> Vec<(String, String)> {
+    let sources: Vec<&String> = moves.iter().filter(|(from, to)| from != to).map(|(from, _)| from).collect();
+    let mut staged: Vec<(String, String)> = vec![];
+    let mut finish: Vec<(String, String)> = vec![];
+    let mut direct: Vec<(String, String)> = vec![];
+    for (index, (from, to)) in moves.iter().enumerate() {
+        if from != to && sources.contains(&to) {
+            let temporary = format!("{}.kita-{}-{}", from, std::process::id(), index);
+            staged.push((from.to_owned(), temporary.to_owned()));
+            finish.push((temporary, to.to_owned()));
+        } else {
+            direct.push((from.to_owned(), to.to_owned()));
+        };
+    }
+    staged.extend(direct);
+    staged.extend(finish);
+    staged
+}
+
+/// Reads the folders' sort keys off the GUI thread, the selector sorts each folder once its keys are in.
+pub fn read_sort_keys(folders: Vec<dir::Folder>, mode: dir::SortMode, read: Arc<Mutex<Vec<(dir::SortMode, dir::Folder)>>>, ctx: egui::Context) -> JoinHandle<()> {
+    spawn(move || {
+        for mut folder in folders {
+            dir::read_sort_keys(&mut folder, &mode);
+            read.lock().unwrap().push((mode.to_owned(), folder));
+            ctx.request_repaint();
+        }
+    })
+}
+
 pub fn thread(gui: &mut app::WindowMain, func: ThreadFunction) -> JoinHandle<()> {
     let progress = Arc::clone(&gui.thread_storage.progress);
     let hashes = Arc::clone(&gui.thread_storage.hashes);
@@ -148,62 +180,31 @@ pub fn thread(gui: &mut app::WindowMain, func: ThreadFunction) -> JoinHandle<()>
         
         ThreadFunction::SaveUndoRedo(edit, save_type, time_out) => {
             spawn(move || {
-                let progress_slice: f32 = (1.0 / (edit.items.len() as f32 - 1.0) as f32) * 100.0;
+                let moves: Vec<(String, String)> = edit.items.iter().map(|item| match save_type {
+                    SaveType::Save | SaveType::Redo => (item.path_original.to_owned(), item.path_edited.to_owned()),
+                    SaveType::Undo => (item.path_edited.to_owned(), item.path_original.to_owned())
+                }).collect();
+                let moves = staged_moves(moves);
+                let progress_slice: f32 = (1.0 / (moves.len() as f32 - 1.0) as f32) * 100.0;
     
                 // Update Internal State
                 *state.lock().unwrap() = ThreadState::Saving;
                 let mut errored: bool = false;
                 let mut errs: Vec<String> = vec![];
                 // Commit Changes
-                match save_type {
-                    SaveType::Save => {
-                        for item in &edit.items {
-                            match rename_file(item.path_original.to_owned(), item.path_edited.to_owned()) {
-                                Ok(_) => {
-                                    *progress.lock().unwrap() += progress_slice;
-                                },
-                                Err(err) => {
-                                    errored = true;
-                                    println!("{}", err.to_string());
-                                    errs.push(err.to_string());
-                                    *state.lock().unwrap() = ThreadState::Errored;
-                                }
-                            }
-                            timeout(Duration::from_millis(time_out as u64));
-                        }
-                    },
-                    SaveType::Undo => {
-                        for item in &edit.items {
-                            match rename_file(item.path_edited.to_owned(), item.path_original.to_owned()) {
-                                Ok(_) => {
-                                    *progress.lock().unwrap() += progress_slice;
-                                },
-                                Err(err) => {
-                                    errored = true;
-                                    println!("{}", err.to_string());
-                                    errs.push(err.to_string());
-                                    *state.lock().unwrap() = ThreadState::Errored;
-                                }
-                            }
-                            timeout(Duration::from_millis(time_out as u64));
-                        }
-                    }, 
-                    SaveType::Redo => {
-                        for item in &edit.items {
-                            match rename_file(item.path_original.to_owned(), item.path_edited.to_owned()) {
-                                Ok(_) => {
-                                    *progress.lock().unwrap() += progress_slice;
-                                },
-                                Err(err) => {
-                                    errored = true;
-                                    println!("{}", err.to_string());
-                                    errs.push(err.to_string());
-                                    *state.lock().unwrap() = ThreadState::Errored;
-                                }
-                            }
-                            timeout(Duration::from_millis(time_out as u64));
+                for (from, to) in moves {
+                    match rename_file(from, to) {
+                        Ok(_) => {
+                            *progress.lock().unwrap() += progress_slice;
+                        },
+                        Err(err) => {
+                            errored = true;
+                            println!("{}", err.to_string());
+                            errs.push(err.to_string());
+                            *state.lock().unwrap() = ThreadState::Errored;
                         }
                     }
+                    timeout(Duration::from_millis(time_out as u64));
                 }
                 if errored {
                     *errors.lock().unwrap() = errs.to_owned();
@@ -219,6 +220,7 @@ pub fn thread(gui: &mut app::WindowMain, func: ThreadFunction) -> JoinHandle<()>
             let modifier_order = Arc::clone(&gui.modifier_thread_storage.modifier_order);
             let eddited_files = Arc::clone(&gui.modifier_thread_storage.eddited_files);
             let raw_files = Arc::clone(&gui.modifier_thread_storage.raw_files);
+            let listings = Arc::clone(&gui.modifier_thread_storage.listings);
             let errors = Arc::clone(&gui.modifier_thread_storage.errors);
             let state = Arc::clone(&gui.modifier_thread_storage.state);
             let frame_time = Arc::clone(&gui.modifier_thread_storage.thread_calc_time);
@@ -252,13 +254,21 @@ pub fn thread(gui: &mut app::WindowMain, func: ThreadFunction) -> JoinHandle<()>
                     let proto_files = files.take().unwrap();
                     let mut mods = mods.take().unwrap();
                     let mod_order = mod_order.take().unwrap();
+                    // Set before the files, so it's there whenever they are.
+                    let listings = listings.lock().unwrap().take().unwrap_or_default();
 
                     // Edit all the proto-files with the modifiers
                     let mut completed_edits: Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)> = vec![];
                     let mut completed_errors: Vec<(Vec<ModifierThreadError>, Vec<ModifierThreadError>)> = vec![];
-                    for (index, (folders, files)) in proto_files.iter().enumerate() {
-                        let folders_edits = file_processing::process(index, &mut mods, folders.to_owned(), mod_order.clone(), true);
-                        let files_edits = file_processing::process(index, &mut mods, files.to_owned(), mod_order.clone(), false);
+                    let mut offset: usize = 0;
+                    let selection = file_processing::selection_items(&proto_files);
+                    for (index, (path, folders, files)) in proto_files.iter().enumerate() {
+                        let listing: &[file_processing::ListedItem] = listings.get(index).map(|listing| listing.as_slice()).unwrap_or_default();
+                        let batch = |offset: usize| file_processing::Batch { folder_index: index, offset, selection: &selection, listing };
+                        let folders_edits = file_processing::process(batch(offset), path.to_owned(), &mut mods, folders.to_owned(), mod_order.clone(), true);
+                        offset += folders.len();
+                        let files_edits = file_processing::process(batch(offset), path.to_owned(), &mut mods, files.to_owned(), mod_order.clone(), false);
+                        offset += files.len();
                         completed_edits.push((folders_edits.0, files_edits.0));
                         completed_errors.push((folders_edits.1, files_edits.1));
                     };
@@ -286,7 +296,9 @@ pub struct ModifierThreadStorage {
     pub modifiers: Arc<Mutex<Option<mods::Modifiers>>>,
     pub modifier_order: Arc<Mutex<Option<Vec<mods::ModsOrder>>>>,
     pub eddited_files: Arc<Mutex<Option<Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)>>>>,
-    pub raw_files: Arc<Mutex<Option<Vec<(Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)>>>>,
+    pub raw_files: Arc<Mutex<Option<Vec<(String, Vec<(String, usize, Option<String>)>, Vec<(String, usize, Option<String>)>)>>>>,
+    /// Everything in each folder of `raw_files`, selected or not.
+    pub listings: Arc<Mutex<Option<Vec<Vec<file_processing::ListedItem>>>>>,
     pub errors: Arc<Mutex<Option<Vec<(Vec<ModifierThreadError>, Vec<ModifierThreadError>)>>>>,
     pub state: Arc<Mutex<ThreadState>>,
     pub thread_calc_time: Arc<Mutex<u32>>
@@ -317,6 +329,7 @@ pub enum HashMode {
     None,
     Prefix,
     Suffix,
+    Insert,
     File
 }
 
@@ -360,7 +373,25 @@ pub enum ModifierThreadError {
     /// Vec<(File Index, Char Index, Invalid Char)>
     InvalidChar(Vec<(usize, char)>),
     /// Vec<(File Indexx, Invalid String)>
-    InvalidFileName(Vec<(usize, String)>)
+    InvalidFileName(Vec<(usize, String)>),
+    /// Vec<(File Index, Error Message)>
+    InvalidTemplate(Vec<(usize, String)>),
+    /// Vec<File Index>, names that aren't in the chosen normalization form. Only a warning.
+    NormalizationDiffers(Vec<usize>),
+    /// Vec<(File Index, Colliding Name)>
+    NormalizationCollision(Vec<(usize, String)>),
+    /// Vec<(File Index, Error Message)>, counts the number system can't write.
+    InvalidNumber(Vec<(usize, String)>),
+    /// Vec<(File Index, Error Message)>
+    InvalidRegex(Vec<(usize, String)>),
+    /// Vec<(File Index, Error Message)>
+    InvalidMapping(Vec<(usize, String)>),
+    /// Vec<File Index>, names the mapping doesn't list. Only a warning, they keep their name.
+    Unmapped(Vec<usize>),
+    /// Vec<File Index>, items past the end of the names list. Only a warning, they keep their name.
+    ListTooShort(Vec<usize>),
+    /// Vec<(File Index, Missing Frames)>, frames of a sequence with gaps. Only a warning.
+    SequenceGaps(Vec<(usize, String)>)
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -368,4 +399,30 @@ pub enum SaveType {
     Save,
     Redo,
     Undo
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_staged_moves() {
+        let folder = std::env::temp_dir().join(format!("kita_test_staged_{}", std::process::id()));
+        std::fs::create_dir_all(&folder).unwrap();
+        let path = |name: &str| folder.join(name).to_str().unwrap().to_string();
+        for name in ["ep 1", "ep 2", "a", "b"] {
+            std::fs::write(path(name), name).unwrap();
+        }
+        // Shift up by one, and a swap.
+        let moves = vec![
+            (path("ep 1"), path("ep 2")), (path("ep 2"), path("ep 3")), 
+            (path("a"), path("b")), (path("b"), path("a"))
+        ];
+        for (from, to) in super::staged_moves(moves) {
+            super::rename_file(from, to).unwrap();
+        }
+        let read = |name: &str| std::fs::read_to_string(path(name)).unwrap();
+        assert_eq!((read("ep 2"), read("ep 3")), (String::from("ep 1"), String::from("ep 2")));
+        assert_eq!((read("a"), read("b")), (String::from("b"), String::from("a")));
+        assert!(!std::path::Path::new(&path("ep 1")).exists());
+        assert_eq!(std::fs::read_dir(&folder).unwrap().count(), 4);
+        std::fs::remove_dir_all(folder).unwrap();
+    }
+}